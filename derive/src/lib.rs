@@ -0,0 +1,373 @@
+//! This crate provides the derive macros for the [`FromStrFront`][front] and
+//! [`FromStrBack`][back] traits of `strtools`, it should not be used directly, enable the `derive`
+//! feature of `strtools` instead.
+//!
+//! [front]: https://docs.rs/strtools/latest/strtools/parse/trait.FromStrFront.html
+//! [back]: https://docs.rs/strtools/latest/strtools/parse/trait.FromStrBack.html
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident, LitStr};
+
+/// Derives `FromStrFront` for structs and enums.
+///
+/// Struct fields are parsed in order, enum variants are tried in order and the first one that
+/// parses successfully is returned. Generic types get bounds on all of their field types.
+/// Containers, variants and fields accept `#[strtools(before = "...", after = "...")]` to require
+/// literals before or after them, unit variants without literals match their own name. The error
+/// type is `strtools::parse::DeriveError`, [`FromStr`][0] is not derived, see
+/// `strtools::parse::forward` for forwarding it.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{forward, FromStrFront};
+///
+/// #[derive(Debug, PartialEq, FromStrFront)]
+/// #[strtools(before = "(", after = ")")]
+/// struct Point {
+///     x: i32,
+///     #[strtools(before = ",")]
+///     y: i32,
+/// }
+///
+/// #[derive(Debug, PartialEq, FromStrFront)]
+/// enum Shape {
+///     #[strtools(before = "circle ")]
+///     Circle(Point, #[strtools(before = " ")] u32),
+///     Empty,
+/// }
+///
+/// forward!(front for Point);
+/// forward!(front for Shape);
+///
+/// assert_eq!(
+///     Shape::from_str_front("circle (1,-2) 5!")?,
+///     (Shape::Circle(Point { x: 1, y: -2 }, 5), "!")
+/// );
+/// assert_eq!(Shape::from_str_front("Empty")?, (Shape::Empty, ""));
+/// # Ok::<_, strtools::parse::DeriveError>(())
+/// ```
+///
+/// [0]: std::str::FromStr
+#[proc_macro_derive(FromStrFront, attributes(strtools))]
+pub fn derive_from_str_front(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Direction::Front)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `FromStrBack` for structs and enums.
+///
+/// Behaves like the `FromStrFront` derive, but struct fields are parsed in reverse order from the
+/// back of the input.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{forward, FromStrBack};
+///
+/// #[derive(Debug, PartialEq, FromStrBack)]
+/// struct Range(u32, #[strtools(before = "..")] u32);
+///
+/// forward!(back for Range);
+///
+/// assert_eq!(Range::from_str_back("from 1..5")?, (Range(1, 5), "from "));
+/// # Ok::<_, strtools::parse::DeriveError>(())
+/// ```
+#[proc_macro_derive(FromStrBack, attributes(strtools))]
+pub fn derive_from_str_back(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Direction::Back)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Front,
+    Back,
+}
+
+/// The literals given by `#[strtools(before = "...", after = "...")]`.
+#[derive(Default)]
+struct Literals {
+    before: Option<LitStr>,
+    after: Option<LitStr>,
+}
+
+impl Literals {
+    fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut literals = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("strtools")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("before") {
+                    literals.before = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("after") {
+                    literals.after = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown strtools attribute, expected `before` or `after`"))
+                }
+            })?;
+        }
+
+        Ok(literals)
+    }
+}
+
+/// How the generated code bails out of a failed parse.
+#[derive(Clone)]
+enum Bail {
+    /// Return the error, used for structs.
+    Return,
+
+    /// Break out of the labeled block of the variant, used for enums.
+    Break(syn::Lifetime),
+}
+
+struct Codegen {
+    direction: Direction,
+    bail: Bail,
+}
+
+impl Codegen {
+    fn literal(&self, literal: &Option<LitStr>) -> TokenStream2 {
+        let Some(literal) = literal else {
+            return quote!();
+        };
+
+        let yield_literal = match self.direction {
+            Direction::Front => quote!(::strtools::parse::yield_literal_front),
+            Direction::Back => quote!(::strtools::parse::yield_literal_back),
+        };
+
+        let bail = match &self.bail {
+            Bail::Return => quote! {
+                return ::std::result::Result::Err(
+                    ::strtools::parse::DeriveError::Literal(#literal)
+                )
+            },
+            Bail::Break(label) => quote!(break #label),
+        };
+
+        quote! {
+            if !#yield_literal(&mut rest, #literal) {
+                #bail;
+            }
+        }
+    }
+
+    fn field(&self, var: &Ident, name: &str, ty: &syn::Type) -> TokenStream2 {
+        let yield_field = match self.direction {
+            Direction::Front => quote!(<#ty as ::strtools::parse::FromStrFront>::yield_front),
+            Direction::Back => quote!(<#ty as ::strtools::parse::FromStrBack>::yield_back),
+        };
+
+        let bail = match &self.bail {
+            Bail::Return => quote! {
+                return ::std::result::Result::Err(
+                    ::strtools::parse::DeriveError::field(#name, err)
+                )
+            },
+            Bail::Break(label) => quote!(break #label),
+        };
+
+        quote! {
+            let #var = match #yield_field(&mut rest) {
+                ::std::result::Result::Ok(value) => value,
+                ::std::result::Result::Err(err) => {
+                    #bail;
+                }
+            };
+        }
+    }
+
+    /// Generates the parsing code for the given fields and returns it together with the
+    /// constructor arguments.
+    fn fields(
+        &self,
+        container: &Literals,
+        fields: &Fields,
+    ) -> syn::Result<(TokenStream2, TokenStream2)> {
+        let mut steps = vec![];
+        let mut vars = vec![];
+
+        for (idx, field) in fields.iter().enumerate() {
+            let literals = Literals::from_attrs(&field.attrs)?;
+            let var = format_ident!("__strtools_field_{}", idx);
+            let name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| idx.to_string(), Ident::to_string);
+
+            let (first, last) = match self.direction {
+                Direction::Front => (&literals.before, &literals.after),
+                Direction::Back => (&literals.after, &literals.before),
+            };
+
+            steps.push(vec![
+                self.literal(first),
+                self.field(&var, &name, &field.ty),
+                self.literal(last),
+            ]);
+            vars.push((field.ident.clone(), var));
+        }
+
+        let (first, last) = match self.direction {
+            Direction::Front => (&container.before, &container.after),
+            Direction::Back => {
+                steps.reverse();
+                (&container.after, &container.before)
+            }
+        };
+
+        let first = self.literal(first);
+        let last = self.literal(last);
+        let steps = steps.into_iter().flatten();
+        let parse = quote! {
+            #first
+            #(#steps)*
+            #last
+        };
+
+        let ctor = match fields {
+            Fields::Named(_) => {
+                let vars = vars.iter().map(|(ident, var)| quote!(#ident: #var));
+                quote!({ #(#vars),* })
+            }
+            Fields::Unnamed(_) => {
+                let vars = vars.iter().map(|(_, var)| var);
+                quote!((#(#vars),*))
+            }
+            Fields::Unit => quote!(),
+        };
+
+        Ok((parse, ctor))
+    }
+}
+
+/// Adds bounds for all field types if the input is generic.
+fn bound_fields(input: &DeriveInput, trait_name: &TokenStream2) -> syn::Generics {
+    let mut generics = input.generics.clone();
+    if generics.type_params().next().is_none() {
+        return generics;
+    }
+
+    let fields: Vec<_> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|v| &v.fields).collect(),
+        Data::Union(_) => vec![],
+    };
+
+    let where_clause = generics.make_where_clause();
+    for field in fields {
+        let ty = &field.ty;
+        where_clause.predicates.push(syn::parse_quote! {
+            #ty: ::strtools::parse::#trait_name
+        });
+        where_clause.predicates.push(syn::parse_quote! {
+            <#ty as ::strtools::parse::#trait_name>::Error:
+                ::std::error::Error + ::std::marker::Send + ::std::marker::Sync + 'static
+        });
+    }
+
+    generics
+}
+
+fn expand(input: &DeriveInput, direction: Direction) -> syn::Result<TokenStream2> {
+    let container = Literals::from_attrs(&input.attrs)?;
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let codegen = Codegen {
+                direction,
+                bail: Bail::Return,
+            };
+            let (parse, ctor) = codegen.fields(&container, &data.fields)?;
+
+            quote! {
+                let mut rest = input;
+                #parse
+                ::std::result::Result::Ok((Self #ctor, rest))
+            }
+        }
+        Data::Enum(data) => {
+            if container.before.is_some() || container.after.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "literals are not supported on enums, add them to the variants instead",
+                ));
+            }
+
+            let mut variants = vec![];
+            for (idx, variant) in data.variants.iter().enumerate() {
+                let mut literals = Literals::from_attrs(&variant.attrs)?;
+                if matches!(variant.fields, Fields::Unit)
+                    && literals.before.is_none()
+                    && literals.after.is_none()
+                {
+                    literals.before = Some(LitStr::new(
+                        &variant.ident.to_string(),
+                        variant.ident.span(),
+                    ));
+                }
+
+                let label = syn::Lifetime::new(
+                    &format!("'__strtools_variant_{}", idx),
+                    proc_macro2::Span::call_site(),
+                );
+                let codegen = Codegen {
+                    direction,
+                    bail: Bail::Break(label.clone()),
+                };
+                let (parse, ctor) = codegen.fields(&literals, &variant.fields)?;
+                let ident = &variant.ident;
+
+                variants.push(quote! {
+                    #label: {
+                        let mut rest = input;
+                        #parse
+                        return ::std::result::Result::Ok((Self::#ident #ctor, rest));
+                    }
+                });
+            }
+
+            quote! {
+                #(#variants)*
+                ::std::result::Result::Err(::strtools::parse::DeriveError::NoVariant)
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "unions are not supported",
+            ))
+        }
+    };
+
+    let (trait_name, method) = match direction {
+        Direction::Front => (quote!(FromStrFront), quote!(from_str_front)),
+        Direction::Back => (quote!(FromStrBack), quote!(from_str_back)),
+    };
+
+    let ident = &input.ident;
+    let generics = bound_fields(input, &trait_name);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::strtools::parse::#trait_name for #ident #ty_generics #where_clause {
+            type Error = ::strtools::parse::DeriveError;
+
+            #[allow(unreachable_code, unused_labels, unused_mut)]
+            fn #method(
+                input: &str,
+            ) -> ::std::result::Result<(Self, &str), ::strtools::parse::DeriveError> {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,107 @@
+use std::str::FromStr;
+use strtools::parse::{forward, DeriveError, FromStrBack, FromStrFront};
+
+#[derive(Debug, PartialEq, FromStrFront, FromStrBack)]
+#[strtools(before = "<", after = ">")]
+struct Named {
+    a: u8,
+    #[strtools(before = "|")]
+    b: bool,
+}
+
+#[derive(Debug, PartialEq, FromStrFront, FromStrBack)]
+enum Token {
+    #[strtools(before = "#")]
+    Number(u16),
+    Yes,
+    #[strtools(before = "no")]
+    No,
+    Pair(#[strtools(after = "x")] u8, u8),
+}
+
+#[derive(Debug, PartialEq, FromStrFront)]
+struct Generic<T>(#[strtools(before = "=")] T);
+
+forward!(front for Named);
+forward!(front for Token);
+
+impl<T> FromStr for Generic<T>
+where
+    T: FromStrFront,
+    T::Error: std::error::Error + Send + Sync + 'static,
+{
+    type Err = DeriveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::from_str_front(s)? {
+            (value, "") => Ok(value),
+            _ => Err(DeriveError::Incomplete),
+        }
+    }
+}
+
+#[test]
+fn struct_front() {
+    assert_eq!(
+        Named::from_str_front("<3|true>rest").unwrap(),
+        (Named { a: 3, b: true }, "rest")
+    );
+    assert!(matches!(
+        Named::from_str_front("3|true>"),
+        Err(DeriveError::Literal("<"))
+    ));
+    assert!(matches!(
+        Named::from_str_front("<3|yes>"),
+        Err(DeriveError::Field { field: "b", .. })
+    ));
+}
+
+#[test]
+fn struct_back() {
+    assert_eq!(
+        Named::from_str_back("rest<3|false>").unwrap(),
+        (Named { a: 3, b: false }, "rest")
+    );
+    assert!(matches!(
+        Named::from_str_back("<3|false"),
+        Err(DeriveError::Literal(">"))
+    ));
+}
+
+#[test]
+fn enum_front() {
+    assert_eq!(
+        Token::from_str_front("#12 ").unwrap(),
+        (Token::Number(12), " ")
+    );
+    assert_eq!(Token::from_str_front("Yes").unwrap(), (Token::Yes, ""));
+    assert_eq!(Token::from_str_front("no!").unwrap(), (Token::No, "!"));
+    assert_eq!(
+        Token::from_str_front("1x2").unwrap(),
+        (Token::Pair(1, 2), "")
+    );
+    assert!(matches!(
+        Token::from_str_front("maybe"),
+        Err(DeriveError::NoVariant)
+    ));
+}
+
+#[test]
+fn enum_back() {
+    assert_eq!(
+        Token::from_str_back(" #12").unwrap(),
+        (Token::Number(12), " ")
+    );
+    assert_eq!(
+        Token::from_str_back("1x2").unwrap(),
+        (Token::Pair(1, 2), "")
+    );
+}
+
+#[test]
+fn generic() {
+    assert_eq!(
+        Generic::<u8>::from_str_front("=5").unwrap(),
+        (Generic(5), "")
+    );
+}
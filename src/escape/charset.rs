@@ -1,4 +1,7 @@
-use crate::{split, util::SortedSlice};
+use crate::{
+    split,
+    util::{CharMembership, SortedSlice},
+};
 use std::borrow::Cow;
 
 /// Escapes all chars in `charset` and the `escape` itself inside `input`. The `charset` parameter
@@ -23,10 +26,38 @@ use std::borrow::Cow;
 /// # }
 /// ```
 pub fn charset<'s>(input: &'s str, escape: char, charset: &SortedSlice<char>) -> Cow<'s, str> {
+    charset_with(input, escape, charset)
+}
+
+/// Behaves like [`charset`], but `charset` can be any [`CharMembership`], like a [`CharSet`][0]
+/// or a single char.
+///
+/// # Complexity
+/// This algorithm requires `O(n * c)` time where `n` is the length of the input string and `c` is
+/// the cost of a membership check, a [`CharSet`][0] requires `O(1)` time for ASCII chars.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::{escape, util::CharSet};
+///
+/// let charset: CharSet = ['"', '→'].into_iter().collect();
+/// assert_eq!(escape::charset_with(r#"a"b→c"#, '\\', &charset), r#"a\"b\→c"#);
+/// assert_eq!(escape::charset_with("a'b", '\\', &'\''), r"a\'b");
+/// ```
+///
+/// [0]: crate::util::CharSet
+pub fn charset_with<'s, C: CharMembership + ?Sized>(
+    input: &'s str,
+    escape: char,
+    charset: &C,
+) -> Cow<'s, str> {
     let mut rest = input;
     let mut result = Cow::Borrowed("");
 
-    while let Some(idx) = rest.find(|ch| ch == escape || charset.binary_search(&ch).is_ok()) {
+    while let Some(idx) = rest.find(|ch| ch == escape || charset.contains_char(ch)) {
         // SAFETY: str::find on rest must give a valid byte offset to a char in rest
         let (head, ch, tail) = unsafe { split::char_boundary_unchecked(rest, idx) };
         let mutate = result.to_mut();
@@ -48,6 +79,7 @@ pub fn charset<'s>(input: &'s str, escape: char, charset: &SortedSlice<char>) ->
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::CharSet;
 
     #[test]
     fn quotes() {
@@ -67,4 +99,14 @@ mod tests {
             r"bypass escaping\\\'"
         );
     }
+
+    #[test]
+    fn with_char_set() {
+        let set: CharSet = ['\'', 'ä'].into_iter().collect();
+        assert_eq!(charset_with(r"ä'\", '\\', &set), r"\ä\'\\");
+        assert!(matches!(
+            charset_with("abc", '\\', &set),
+            Cow::Borrowed("abc")
+        ));
+    }
 }
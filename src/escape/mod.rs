@@ -1,4 +1,8 @@
-//! This module contains functions with the primary purpose of escaping characters in [str]s.
+//! This module contains functions with the primary purpose of escaping and unescaping characters
+//! in [str]s.
 
 mod charset;
-pub use charset::charset;
+pub use charset::{charset, charset_with};
+
+mod unescape;
+pub use unescape::{unescape, UnescapeError};
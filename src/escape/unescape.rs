@@ -0,0 +1,121 @@
+use crate::util::CharMembership;
+use std::borrow::Cow;
+
+/// An [Error][0] for [`unescape`], see its documentation for more info.
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum UnescapeError {
+    /// Indicates that the input ended with an escape at the given byte offset that did not escape
+    /// anything.
+    #[error("the escape at {0} was not followed by any char")]
+    Dangling(usize),
+
+    /// Indicates that the escape at the given byte offset was followed by a char that is neither
+    /// in the charset nor the escape itself.
+    #[error("the escape at {0} was followed by {1:?}, which cannot be escaped")]
+    Invalid(usize, char),
+}
+
+/// Removes the escapes before all chars in `charset` and the `escape` itself inside `input`, this
+/// reverses [`charset`][0]. The `charset` parameter can be any [`CharMembership`], like a
+/// [`SortedSlice`][1] or a [`CharSet`][2] of chars.
+///
+/// # Errors
+/// Returns an error if:
+/// - `input` ends with an escape that is not itself escaped
+/// - an escape is followed by a char that is neither in `charset` nor `escape`
+///
+/// # Complexity
+/// This algorithm requires `O(n * c)` time where `n` is the length of the input string and `c` is
+/// the cost of a membership check, a [`CharSet`][2] requires `O(1)` time for ASCII chars.
+///
+/// # Allocation
+/// If `input` contains no escapes, no allocations are done and the input is borrowed, otherwise a
+/// [`String`] is allocated and all but the escapes are copied over.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{escape::{self, UnescapeError}, util::sorted};
+///
+/// assert_eq!(escape::unescape(r"it\'s \\ fine", '\\', &sorted!['\''])?, r"it's \ fine");
+///
+/// assert_eq!(escape::unescape(r"\n", '\\', &sorted!['\'']), Err(UnescapeError::Invalid(0, 'n')));
+/// assert_eq!(escape::unescape(r"a\", '\\', &sorted!['\'']), Err(UnescapeError::Dangling(1)));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [0]: super::charset
+/// [1]: crate::util::SortedSlice
+/// [2]: crate::util::CharSet
+pub fn unescape<'s, C: CharMembership + ?Sized>(
+    input: &'s str,
+    escape: char,
+    charset: &C,
+) -> Result<Cow<'s, str>, UnescapeError> {
+    let Some(first) = input.find(escape) else {
+        return Ok(Cow::Borrowed(input));
+    };
+
+    let mut result = String::with_capacity(input.len());
+    result.push_str(&input[..first]);
+
+    let mut iter = input[first..].char_indices();
+    while let Some((idx, ch)) = iter.next() {
+        if ch != escape {
+            result.push(ch);
+            continue;
+        }
+
+        match iter.next() {
+            Some((_, escaped)) if escaped == escape || charset.contains_char(escaped) => {
+                result.push(escaped);
+            }
+            Some((_, escaped)) => return Err(UnescapeError::Invalid(first + idx, escaped)),
+            None => return Err(UnescapeError::Dangling(first + idx)),
+        }
+    }
+
+    Ok(Cow::Owned(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{escape::charset_with, util::Sorted};
+
+    #[test]
+    fn borrowed() {
+        assert!(matches!(
+            unescape("no escapes", '\\', &Sorted::new(['\'']).unwrap()),
+            Ok(Cow::Borrowed("no escapes"))
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let set = Sorted::new(['"', '\'']).unwrap();
+        let input = r#"a "quoted" \'string\\'"#;
+
+        assert_eq!(
+            unescape(&charset_with(input, '\\', &set), '\\', &set),
+            Ok(input.into())
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let set = Sorted::new(['\'']).unwrap();
+
+        assert_eq!(
+            unescape(r"äö\x", '\\', &set),
+            Err(UnescapeError::Invalid(4, 'x'))
+        );
+        assert_eq!(
+            unescape(r"\\\", '\\', &set),
+            Err(UnescapeError::Dangling(2))
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use super::eq_ignore_case;
+
+/// Returns the byte length of the longest common prefix of `a` and `b` where chars are compared
+/// with `eq`, the length is always on a char boundary of `a`.
+fn prefix_len_by(a: &str, b: &str, mut eq: impl FnMut(char, char) -> bool) -> usize {
+    let mut b = b.chars();
+    for (idx, a) in a.char_indices() {
+        match b.next() {
+            Some(b) if eq(a, b) => {}
+            _ => return idx,
+        }
+    }
+    a.len()
+}
+
+/// Returns the byte length of the longest common prefix of `a` and `b`, the length is always on a
+/// char boundary of both strings.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the shorter string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::common_prefix_len("[INFO] started", "[INFO] stopped"), 9);
+///
+/// // 'ä' and 'ö' share their first byte, but not their first char
+/// assert_eq!(find::common_prefix_len("ä", "ö"), 0);
+/// ```
+pub fn common_prefix_len(a: &str, b: &str) -> usize {
+    prefix_len_by(a, b, |a, b| a == b)
+}
+
+/// Returns the longest common prefix of `a` and `b` as a slice of `a`, see [`common_prefix_len`].
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::common_prefix("[INFO] started", "[INFO] stopped"), "[INFO] st");
+/// assert_eq!(find::common_prefix("abc", "xyz"), "");
+/// ```
+pub fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    &a[..common_prefix_len(a, b)]
+}
+
+/// Behaves like [`common_prefix`], but compares chars ignoring case. The returned prefix is a
+/// slice of `a`, so it has the case of `a`.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::common_prefix_ignore_case("Hello World", "HELLO WORLD!"), "Hello World");
+/// assert_eq!(find::common_prefix_ignore_case("Ärger", "äRGERLICH"), "Ärger");
+/// ```
+pub fn common_prefix_ignore_case<'a>(a: &'a str, b: &str) -> &'a str {
+    &a[..prefix_len_by(a, b, eq_ignore_case)]
+}
+
+/// Returns the byte length of the longest common suffix of `a` and `b`, the length is always on a
+/// char boundary of both strings.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the shorter string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::common_suffix_len("image.png", "icon.png"), 4);
+///
+/// // 'ä' and 'Ĥ' share their last byte, but not their last char
+/// assert_eq!(find::common_suffix_len("ä", "Ĥ"), 0);
+/// ```
+pub fn common_suffix_len(a: &str, b: &str) -> usize {
+    let mut b = b.chars();
+    for (idx, a_ch) in a.char_indices().rev() {
+        match b.next_back() {
+            Some(b_ch) if a_ch == b_ch => {}
+            _ => return a.len() - idx - a_ch.len_utf8(),
+        }
+    }
+    a.len()
+}
+
+/// Returns the longest common suffix of `a` and `b` as a slice of `a`, see [`common_suffix_len`].
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::common_suffix("src/main.rs", "tests/main.rs"), "/main.rs");
+/// assert_eq!(find::common_suffix("abc", "xyz"), "");
+/// ```
+pub fn common_suffix<'a>(a: &'a str, b: &str) -> &'a str {
+    &a[a.len() - common_suffix_len(a, b)..]
+}
+
+/// Returns the byte length of the longest suffix of `a` that is also a prefix of `b`. The length
+/// is always on a char boundary of both strings.
+///
+/// # Complexity
+/// This algorithm requires `O(n + m)` time where `n` and `m` are the lengths of the input strings.
+///
+/// # Allocation
+/// The failure function of `b` is collected into a [Vec] of `m` lengths.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// // concatenate streamed chunks without duplicating their overlap
+/// let (a, b) = ("the quick br", "brown fox");
+/// let joined = format!("{a}{}", &b[find::overlap(a, b)..]);
+/// assert_eq!(joined, "the quick brown fox");
+///
+/// assert_eq!(find::overlap("abc", "xyz"), 0);
+/// ```
+pub fn overlap(a: &str, b: &str) -> usize {
+    // only the last `b.len()` bytes of `a` can overlap
+    let a = &a.as_bytes()[a.len().saturating_sub(b.len())..];
+    let b = b.as_bytes();
+
+    if b.is_empty() {
+        return 0;
+    }
+
+    // the length of the longest proper prefix of `b[..=idx]` that is also its suffix
+    let mut failure = vec![0; b.len()];
+    let mut len = 0;
+    for idx in 1..b.len() {
+        while len > 0 && b[idx] != b[len] {
+            len = failure[len - 1];
+        }
+        if b[idx] == b[len] {
+            len += 1;
+        }
+        failure[idx] = len;
+    }
+
+    // the length of the longest prefix of `b` that is a suffix of the bytes of `a` seen so far,
+    // valid utf-8 can only match on char boundaries
+    let mut len = 0;
+    for &byte in a {
+        while len > 0 && (len == b.len() || byte != b[len]) {
+            len = failure[len - 1];
+        }
+        if byte == b[len] {
+            len += 1;
+        }
+    }
+
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefix() {
+        assert_eq!(common_prefix("", "abc"), "");
+        assert_eq!(common_prefix("abc", ""), "");
+        assert_eq!(common_prefix("abc", "abc"), "abc");
+        assert_eq!(common_prefix("abc", "abcdef"), "abc");
+        assert_eq!(common_prefix("abcdef", "abc"), "abc");
+        assert_eq!(common_prefix("äöü", "äöx"), "äö");
+        assert_eq!(common_prefix("äöü", "äö"), "äö");
+    }
+
+    #[test]
+    fn prefix_len() {
+        assert_eq!(common_prefix_len("äa", "äb"), 2);
+        assert_eq!(common_prefix_len("ä", "ö"), 0);
+    }
+
+    #[test]
+    fn prefix_ignore_case() {
+        assert_eq!(common_prefix_ignore_case("ABC", "abd"), "AB");
+        assert_eq!(common_prefix_ignore_case("abc", "ABC"), "abc");
+        assert_eq!(common_prefix_ignore_case("ÄÖ", "äöü"), "ÄÖ");
+        assert_eq!(common_prefix_ignore_case("a", "b"), "");
+    }
+
+    #[test]
+    fn suffix() {
+        assert_eq!(common_suffix("", "abc"), "");
+        assert_eq!(common_suffix("abc", ""), "");
+        assert_eq!(common_suffix("abc", "abc"), "abc");
+        assert_eq!(common_suffix("abc", "xyzabc"), "abc");
+        assert_eq!(common_suffix("xyzabc", "abc"), "abc");
+        assert_eq!(common_suffix("üöä", "xöä"), "öä");
+        assert_eq!(common_suffix("öä", "üöä"), "öä");
+    }
+
+    #[test]
+    fn suffix_len() {
+        assert_eq!(common_suffix_len("aä", "bä"), 2);
+        assert_eq!(common_suffix_len("ä", "Ĥ"), 0);
+    }
+
+    #[test]
+    fn overlaps() {
+        assert_eq!(overlap("", ""), 0);
+        assert_eq!(overlap("abc", ""), 0);
+        assert_eq!(overlap("", "abc"), 0);
+        assert_eq!(overlap("abc", "abc"), 3);
+        assert_eq!(overlap("xabc", "abcx"), 3);
+        assert_eq!(overlap("abc", "bcd"), 2);
+        assert_eq!(overlap("aaa", "aaaa"), 3);
+        assert_eq!(overlap("abab", "ababx"), 4);
+        assert_eq!(overlap("abcab", "abx"), 2);
+        assert_eq!(overlap("xöä", "öäy"), 4);
+        // 'ä' and 'Ĥ' share their last byte
+        assert_eq!(overlap("Ĥ", "ä"), 0);
+    }
+
+    #[test]
+    fn overlap_brute_force() {
+        let inputs = ["", "a", "ab", "aab", "abab", "baba", "aaa", "abba", "bab"];
+        for a in inputs {
+            for b in inputs {
+                let expected = (0..=a.len().min(b.len()))
+                    .rev()
+                    .find(|&len| a.ends_with(&b[..len]))
+                    .unwrap();
+                assert_eq!(overlap(a, b), expected, "{a} {b}");
+            }
+        }
+    }
+}
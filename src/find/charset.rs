@@ -0,0 +1,200 @@
+use crate::util::Sorted;
+use std::ops::Range;
+
+/// Returns the byte index and the first char of `input` that is not in `set`, or [`None`] if all
+/// chars are in `set`.
+///
+/// # Complexity
+/// This algorithm requires `O(n * log m)` time where `n` is the length of the input string and `m`
+/// the length of the set.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::{find, util::Sorted};
+///
+/// let digits = Sorted::new_sorted(['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']);
+/// assert_eq!(find::first_not_in("1234", &digits), None);
+/// assert_eq!(find::first_not_in("12a4", &digits), Some((2, 'a')));
+/// ```
+pub fn first_not_in<const N: usize>(input: &str, set: &Sorted<char, N>) -> Option<(usize, char)> {
+    input.char_indices().find(|(_, ch)| !set.contains(ch))
+}
+
+/// Returns the byte index and the last char of `input` that is not in `set`, or [`None`] if all
+/// chars are in `set`.
+///
+/// # Complexity
+/// This algorithm requires `O(n * log m)` time where `n` is the length of the input string and `m`
+/// the length of the set.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::find;
+///
+/// let input = "path/to/dir///";
+/// assert_eq!(find::last_not_in(input, &['/'].try_into()?), Some((10, 'r')));
+/// # Ok(())
+/// # }
+/// ```
+pub fn last_not_in<const N: usize>(input: &str, set: &Sorted<char, N>) -> Option<(usize, char)> {
+    input.char_indices().rev().find(|(_, ch)| !set.contains(ch))
+}
+
+/// Returns the byte range of the shortest substring of `input` that contains every char of `set`
+/// at least once, or [`None`] if some char of `set` does not occur in `input`. Duplicate chars in
+/// `set` only need to occur once. The length is measured in chars, if there are multiple shortest
+/// ranges, the first one is returned. An empty set is contained in the empty range at `0`.
+///
+/// # Complexity
+/// This algorithm requires `O(n * log m)` time where `n` is the length of the input string and `m`
+/// the length of the set.
+///
+/// # Allocation
+/// A count per char of `set` is kept in a [Vec].
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::find;
+///
+/// let input = "the quick brown fox";
+/// let range = find::min_window(input, &['b', 'o', 'q'].try_into()?).unwrap();
+/// assert_eq!(&input[range], "quick bro");
+///
+/// assert_eq!(find::min_window(input, &['z'].try_into()?), None);
+/// # Ok(())
+/// # }
+/// ```
+pub fn min_window<const N: usize>(input: &str, set: &Sorted<char, N>) -> Option<Range<usize>> {
+    if N == 0 {
+        return Some(0..0);
+    }
+
+    // duplicate chars share a count, so only distinct chars can be missing
+    let mut counts = vec![0usize; N];
+    let mut missing = 1 + set
+        .iter()
+        .zip(set.iter().skip(1))
+        .filter(|(a, b)| a != b)
+        .count();
+    let mut left = input.char_indices();
+    let mut start = 0;
+    let mut len = 0;
+    let mut best: Option<(usize, Range<usize>)> = None;
+
+    for (idx, ch) in input.char_indices() {
+        len += 1;
+        if let Some(pos) = set.position(&ch) {
+            counts[pos] += 1;
+            if counts[pos] == 1 {
+                missing -= 1;
+            }
+        }
+
+        // shrink the window from the left while it still contains all chars
+        while missing == 0 {
+            if best.as_ref().is_none_or(|(best, _)| len < *best) {
+                best = Some((len, start..idx + ch.len_utf8()));
+            }
+
+            // the window is not empty, so there is a char to remove
+            let Some((l_idx, l_ch)) = left.next() else {
+                break;
+            };
+
+            len -= 1;
+            start = l_idx + l_ch.len_utf8();
+            if let Some(pos) = set.position(&l_ch) {
+                counts[pos] -= 1;
+                if counts[pos] == 0 {
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    best.map(|(_, range)| range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let set = ['a'].try_into().unwrap();
+        assert_eq!(first_not_in("", &set), None);
+        assert_eq!(last_not_in("", &set), None);
+
+        let set = Sorted::<char, 0>::new_sorted([]);
+        assert_eq!(first_not_in("ab", &set), Some((0, 'a')));
+        assert_eq!(last_not_in("ab", &set), Some((1, 'b')));
+    }
+
+    #[test]
+    fn all_in() {
+        let set = ['a', 'b', 'ö'].try_into().unwrap();
+        assert_eq!(first_not_in("abö", &set), None);
+        assert_eq!(last_not_in("abö", &set), None);
+    }
+
+    #[test]
+    fn some_not_in() {
+        let set = ['a', 'b'].try_into().unwrap();
+        assert_eq!(first_not_in("abxaby", &set), Some((2, 'x')));
+        assert_eq!(last_not_in("abxaby", &set), Some((5, 'y')));
+        assert_eq!(first_not_in("aöb", &set), Some((1, 'ö')));
+        assert_eq!(last_not_in("aöb", &set), Some((1, 'ö')));
+    }
+
+    #[test]
+    fn min_window_empty() {
+        let set = Sorted::<char, 0>::new_sorted([]);
+        assert_eq!(min_window("", &set), Some(0..0));
+        assert_eq!(min_window("abc", &set), Some(0..0));
+        assert_eq!(min_window("", &['a'].try_into().unwrap()), None);
+    }
+
+    #[test]
+    fn min_window_found() {
+        let set = ['a', 'b', 'c'].try_into().unwrap();
+        assert_eq!(min_window("abc", &set), Some(0..3));
+        assert_eq!(min_window("xaxbxcx", &set), Some(1..6));
+        assert_eq!(min_window("aaabxcbca", &set), Some(6..9));
+        assert_eq!(min_window("cabxcab", &set), Some(0..3));
+        assert_eq!(
+            min_window("äxböc", &['ä', 'ö'].try_into().unwrap()),
+            Some(0..6)
+        );
+    }
+
+    #[test]
+    fn min_window_chars() {
+        // both windows are 3 chars long, the first is returned despite having more bytes
+        let set = ['a', 'b'].try_into().unwrap();
+        assert_eq!(min_window("aöb-a", &set), Some(0..4));
+    }
+
+    #[test]
+    fn min_window_duplicates() {
+        let set = ['a', 'a'].try_into().unwrap();
+        assert_eq!(min_window("xxaxx", &set), Some(2..3));
+
+        let set = ['a', 'b', 'b'].try_into().unwrap();
+        assert_eq!(min_window("bxxab", &set), Some(3..5));
+        assert_eq!(min_window("bbb", &set), None);
+    }
+
+    #[test]
+    fn min_window_missing() {
+        let set = ['a', 'b', 'c'].try_into().unwrap();
+        assert_eq!(min_window("abab", &set), None);
+    }
+}
@@ -0,0 +1,337 @@
+/// An [Error][0] for [`hamming`], see it's documentation for more info.
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("the inputs must have the same length, but had {0} and {1} chars")]
+pub struct LengthMismatch(pub usize, pub usize);
+
+/// Returns the Levenshtein distance between `a` and `b`, that is the minimum number of single
+/// char insertions, deletions and substitutions needed to turn `a` into `b`.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input
+/// strings.
+///
+/// # Allocation
+/// The chars of `b` and a single row of `m + 1` distances are collected into [Vec]s.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(find::levenshtein("teh", "the"), 2);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        // the distance of the previous row and column
+        let mut diag = row[0];
+        row[0] = i + 1;
+
+        for (j, &b) in b.iter().enumerate() {
+            let subst = diag + usize::from(a != b);
+            diag = row[j + 1];
+            row[j + 1] = subst.min(row[j] + 1).min(diag + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Returns the restricted Damerau-Levenshtein distance between `a` and `b`, also known as the
+/// optimal string alignment distance. This behaves like [`levenshtein`], but additionally counts
+/// a transposition of two adjacent chars as a single edit. No substring may be edited more than
+/// once, so `"ca"` to `"abc"` is `3` rather than `2`.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input
+/// strings.
+///
+/// # Allocation
+/// The chars of both strings and three rows of `m + 1` distances are collected into [Vec]s.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::damerau_levenshtein("teh", "the"), 1);
+/// assert_eq!(find::damerau_levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(find::damerau_levenshtein("ca", "abc"), 3);
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev_prev = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            curr[j] = (prev[j - 1] + cost).min(prev[j] + 1).min(curr[j - 1] + 1);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                curr[j] = curr[j].min(prev_prev[j - 2] + 1);
+            }
+        }
+
+        // rotate the rows, the old `prev_prev` is overwritten in the next iteration
+        std::mem::swap(&mut prev_prev, &mut prev);
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Returns the Jaro similarity of `a` and `b`, a score in `0.0..=1.0` where `1.0` means both
+/// strings are equal and `0.0` means they have no chars in common. Two empty strings are equal.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input
+/// strings.
+///
+/// # Allocation
+/// The chars of both strings and a flag per char of `b` are collected into [Vec]s.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::jaro("abc", "abc"), 1.0);
+/// assert_eq!(find::jaro("abc", "xyz"), 0.0);
+/// assert!((find::jaro("martha", "marhta") - 0.944).abs() < 0.001);
+/// ```
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::with_capacity(a.len().min(b.len()));
+
+    for (i, &ch) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+
+        if let Some(j) = (start..end).find(|&j| !b_matched[j] && b[j] == ch) {
+            b_matched[j] = true;
+            a_matches.push(ch);
+        }
+    }
+
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b
+        .iter()
+        .zip(&b_matched)
+        .filter_map(|(ch, &m)| m.then_some(ch));
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(a, b)| a != b)
+        .count()
+        / 2;
+
+    let m = a_matches.len() as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Returns the Jaro-Winkler similarity of `a` and `b` using the standard prefix scale of `0.1`,
+/// see [`jaro_winkler_with`].
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert!((find::jaro_winkler("martha", "marhta") - 0.961).abs() < 0.001);
+/// assert!(find::jaro_winkler("dixon", "dicksonx") > find::jaro("dixon", "dicksonx"));
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    jaro_winkler_with(a, b, 0.1)
+}
+
+/// Returns the Jaro-Winkler similarity of `a` and `b`, this is the [`jaro`] similarity with a
+/// bonus for a common prefix of up to 4 chars, weighted by `prefix_scale`. This favors strings
+/// that match from the beginning, as is typical for names.
+///
+/// # Panics
+/// Panics if `prefix_scale` is not in `0.0..=0.25`, larger values could yield scores above `1.0`.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input
+/// strings.
+///
+/// # Allocation
+/// See [`jaro`].
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// // without a prefix scale this is the plain jaro similarity
+/// assert_eq!(find::jaro_winkler_with("martha", "marhta", 0.0), find::jaro("martha", "marhta"));
+/// assert!((find::jaro_winkler_with("martha", "marhta", 0.2) - 0.978).abs() < 0.001);
+/// ```
+pub fn jaro_winkler_with(a: &str, b: &str, prefix_scale: f64) -> f64 {
+    assert!(
+        (0.0..=0.25).contains(&prefix_scale),
+        "prefix scale must be in 0.0..=0.25"
+    );
+
+    let sim = jaro(a, b);
+    let prefix = a
+        .chars()
+        .zip(b.chars())
+        .take(4)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    sim + prefix as f64 * prefix_scale * (1.0 - sim)
+}
+
+/// Returns the Hamming distance between `a` and `b`, that is the number of positions at which
+/// their chars differ.
+///
+/// # Errors
+/// Returns an error if:
+/// - `a` and `b` do not have the same number of chars
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input strings. If both
+/// inputs are ASCII, their bytes are compared directly.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::find::{self, LengthMismatch};
+///
+/// assert_eq!(find::hamming("karolin", "kathrin")?, 3);
+/// assert_eq!(find::hamming("äöü", "aöu")?, 2);
+/// assert_eq!(find::hamming("abc", "ab"), Err(LengthMismatch(3, 2)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn hamming(a: &str, b: &str) -> Result<usize, LengthMismatch> {
+    if a.is_ascii() && b.is_ascii() {
+        return if a.len() == b.len() {
+            Ok(a.bytes().zip(b.bytes()).filter(|(a, b)| a != b).count())
+        } else {
+            Err(LengthMismatch(a.len(), b.len()))
+        };
+    }
+
+    let mut a_chars = a.chars();
+    let mut b_chars = b.chars();
+    let mut distance = 0;
+
+    loop {
+        match (a_chars.next(), b_chars.next()) {
+            (Some(a), Some(b)) => distance += usize::from(a != b),
+            (None, None) => break,
+            _ => return Err(LengthMismatch(a.chars().count(), b.chars().count())),
+        }
+    }
+
+    Ok(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levenshtein_empty() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+
+    #[test]
+    fn levenshtein_edits() {
+        assert_eq!(levenshtein("abc", "abc"), 0);
+        assert_eq!(levenshtein("abc", "abd"), 1);
+        assert_eq!(levenshtein("abc", "ac"), 1);
+        assert_eq!(levenshtein("ac", "abc"), 1);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("äöü", "aöu"), 2);
+    }
+
+    #[test]
+    fn damerau_levenshtein_empty() {
+        assert_eq!(damerau_levenshtein("", ""), 0);
+        assert_eq!(damerau_levenshtein("abc", ""), 3);
+        assert_eq!(damerau_levenshtein("", "abc"), 3);
+    }
+
+    #[test]
+    fn damerau_levenshtein_edits() {
+        assert_eq!(damerau_levenshtein("abc", "abc"), 0);
+        assert_eq!(damerau_levenshtein("abc", "acb"), 1);
+        assert_eq!(damerau_levenshtein("ab", "ba"), 1);
+        assert_eq!(damerau_levenshtein("abcd", "badc"), 2);
+        assert_eq!(damerau_levenshtein("flaw", "lawn"), 2);
+        assert_eq!(damerau_levenshtein("äö", "öä"), 1);
+    }
+
+    #[test]
+    fn jaro_empty() {
+        assert_eq!(jaro("", ""), 1.0);
+        assert_eq!(jaro("abc", ""), 0.0);
+        assert_eq!(jaro("", "abc"), 0.0);
+    }
+
+    #[test]
+    fn jaro_scores() {
+        assert_eq!(jaro("a", "a"), 1.0);
+        assert_eq!(jaro("ab", "ba"), 0.0);
+        assert!((jaro("dwayne", "duane") - 0.822).abs() < 0.001);
+        assert!((jaro("dixon", "dicksonx") - 0.767).abs() < 0.001);
+        assert_eq!(jaro("äöü", "äöü"), 1.0);
+    }
+
+    #[test]
+    fn jaro_winkler_scores() {
+        assert_eq!(jaro_winkler("abc", "abc"), 1.0);
+        assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+        assert!((jaro_winkler("dwayne", "duane") - 0.840).abs() < 0.001);
+        assert!((jaro_winkler("dixon", "dicksonx") - 0.813).abs() < 0.001);
+    }
+
+    #[test]
+    #[should_panic]
+    fn jaro_winkler_scale_too_large() {
+        jaro_winkler_with("a", "a", 0.3);
+    }
+
+    #[test]
+    fn hamming_ascii() {
+        assert_eq!(hamming("", ""), Ok(0));
+        assert_eq!(hamming("abc", "abc"), Ok(0));
+        assert_eq!(hamming("abc", "xbz"), Ok(2));
+        assert_eq!(hamming("abc", "abcd"), Err(LengthMismatch(3, 4)));
+    }
+
+    #[test]
+    fn hamming_unicode() {
+        assert_eq!(hamming("äöü", "äöü"), Ok(0));
+        assert_eq!(hamming("äöü", "aöü"), Ok(1));
+        // same byte length, but different char counts
+        assert_eq!(hamming("ä", "ab"), Err(LengthMismatch(1, 2)));
+        assert_eq!(hamming("abä", "ab"), Err(LengthMismatch(3, 2)));
+    }
+}
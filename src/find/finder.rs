@@ -0,0 +1,257 @@
+use std::iter::FusedIterator;
+
+/// A precompiled substring searcher for a single needle, useful for searching the same needle in
+/// many haystacks. The shift tables of the Boyer-Moore-Horspool algorithm are computed once on
+/// creation rather than on every search.
+///
+/// Matches are always on char boundaries, because both the needle and the haystack are valid
+/// UTF-8. An empty needle matches at every char boundary, like [`str::find`].
+///
+/// # Complexity
+/// Creating a finder requires `O(m)` time where `m` is the length of the needle, searching
+/// requires `O(n)` time on average and `O(n * m)` time in the worst case, where `n` is the length
+/// of the haystack.
+///
+/// # Allocation
+/// No allocations are done, the shift tables are stored inline.
+///
+/// # Examples
+/// ```
+/// use strtools::find::Finder;
+///
+/// let finder = Finder::new("needle");
+/// assert_eq!(finder.find("haystack with a needle"), Some(16));
+/// assert_eq!(finder.find("haystack without"), None);
+///
+/// let finder = Finder::new("ab");
+/// assert_eq!(finder.rfind("abxab"), Some(3));
+/// assert_eq!(finder.find_iter("abxabab").collect::<Vec<_>>(), [0, 3, 5]);
+/// ```
+#[derive(Clone)]
+pub struct Finder<'needle> {
+    needle: &'needle str,
+    forward: [usize; 256],
+    backward: [usize; 256],
+}
+
+impl<'n> Finder<'n> {
+    /// Creates a new [`Finder`] for the given `needle`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::Finder;
+    ///
+    /// let finder = Finder::new("öl");
+    /// assert_eq!(finder.needle(), "öl");
+    /// ```
+    pub fn new(needle: &'n str) -> Self {
+        let bytes = needle.as_bytes();
+        let len = bytes.len();
+
+        // the distance from the last occurrence of a byte to the end, excluding the last byte
+        let mut forward = [len; 256];
+        for (idx, &byte) in bytes.iter().enumerate().take(len.saturating_sub(1)) {
+            forward[byte as usize] = len - 1 - idx;
+        }
+
+        // the distance from the start to the first occurrence of a byte, excluding the first byte
+        let mut backward = [len; 256];
+        for (idx, &byte) in bytes.iter().enumerate().skip(1).rev() {
+            backward[byte as usize] = idx;
+        }
+
+        Self {
+            needle,
+            forward,
+            backward,
+        }
+    }
+
+    /// Returns the needle of this finder.
+    pub fn needle(&self) -> &'n str {
+        self.needle
+    }
+
+    /// Returns the byte index of the first match of the needle in `haystack` starting at or after
+    /// `start`.
+    fn find_at(&self, haystack: &str, start: usize) -> Option<usize> {
+        let hay = haystack.as_bytes();
+        let needle = self.needle.as_bytes();
+        let len = needle.len();
+
+        if len == 0 {
+            return Some(start);
+        }
+
+        let mut pos = start;
+        while pos + len <= hay.len() {
+            let last = hay[pos + len - 1];
+            if last == needle[len - 1] && &hay[pos..pos + len] == needle {
+                return Some(pos);
+            }
+
+            pos += self.forward[last as usize];
+        }
+
+        None
+    }
+
+    /// Returns the byte index of the first match of the needle in `haystack`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::Finder;
+    ///
+    /// let finder = Finder::new("b");
+    /// assert_eq!(finder.find("abcb"), Some(1));
+    /// ```
+    pub fn find(&self, haystack: &str) -> Option<usize> {
+        self.find_at(haystack, 0)
+    }
+
+    /// Returns the byte index of the last match of the needle in `haystack`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::Finder;
+    ///
+    /// let finder = Finder::new("b");
+    /// assert_eq!(finder.rfind("abcb"), Some(3));
+    /// ```
+    pub fn rfind(&self, haystack: &str) -> Option<usize> {
+        let hay = haystack.as_bytes();
+        let needle = self.needle.as_bytes();
+        let len = needle.len();
+
+        let mut pos = hay.len().checked_sub(len)?;
+        loop {
+            if &hay[pos..pos + len] == needle {
+                return Some(pos);
+            }
+
+            pos = pos.checked_sub(self.backward[hay[pos] as usize])?;
+        }
+    }
+
+    /// Returns an [Iterator] over the byte indices of all non-overlapping matches of the needle in
+    /// `haystack`, like [`str::match_indices`].
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::Finder;
+    ///
+    /// let finder = Finder::new("aa");
+    /// assert_eq!(finder.find_iter("aaaaa").collect::<Vec<_>>(), [0, 2]);
+    /// ```
+    pub fn find_iter<'f, 'h>(&'f self, haystack: &'h str) -> FindIter<'f, 'n, 'h> {
+        FindIter {
+            finder: self,
+            haystack,
+            done: Some(0),
+        }
+    }
+}
+
+impl<'n> std::fmt::Debug for Finder<'n> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Finder")
+            .field("needle", &self.needle)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An [Iterator] that yields the byte indices of the matches of a [`Finder`] in a [str]. This
+/// struct is created by the [`Finder::find_iter`] method, see it's documentation for more info.
+#[derive(Debug, Clone)]
+pub struct FindIter<'finder, 'needle, 'haystack> {
+    finder: &'finder Finder<'needle>,
+    haystack: &'haystack str,
+    done: Option<usize>,
+}
+
+impl<'f, 'n, 'h> Iterator for FindIter<'f, 'n, 'h> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.done?;
+        let found = self.finder.find_at(self.haystack, start);
+
+        self.done = match found {
+            // empty needles must advance by a char to not match at the same index again
+            Some(idx) if self.finder.needle.is_empty() => self.haystack[idx..]
+                .chars()
+                .next()
+                .map(|ch| idx + ch.len_utf8()),
+            Some(idx) => Some(idx + self.finder.needle.len()),
+            None => None,
+        };
+
+        found
+    }
+}
+
+impl<'f, 'n, 'h> FusedIterator for FindIter<'f, 'n, 'h> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_needle() {
+        let finder = Finder::new("");
+        assert_eq!(finder.find(""), Some(0));
+        assert_eq!(finder.find("abc"), Some(0));
+        assert_eq!(finder.rfind("abc"), Some(3));
+        assert_eq!(finder.find_iter("aö").collect::<Vec<_>>(), [0, 1, 3]);
+    }
+
+    #[test]
+    fn empty_haystack() {
+        let finder = Finder::new("a");
+        assert_eq!(finder.find(""), None);
+        assert_eq!(finder.rfind(""), None);
+        assert_eq!(finder.find_iter("").next(), None);
+    }
+
+    #[test]
+    fn find() {
+        let finder = Finder::new("abcab");
+        assert_eq!(finder.find("abcab"), Some(0));
+        assert_eq!(finder.find("xabcabcab"), Some(1));
+        assert_eq!(finder.find("abcacab"), None);
+        assert_eq!(Finder::new("öä").find("äöäö"), Some(2));
+    }
+
+    #[test]
+    fn rfind() {
+        let finder = Finder::new("abcab");
+        assert_eq!(finder.rfind("abcab"), Some(0));
+        assert_eq!(finder.rfind("xabcabcab"), Some(4));
+        assert_eq!(finder.rfind("abcacab"), None);
+        assert_eq!(Finder::new("öä").rfind("öäöäx"), Some(4));
+    }
+
+    #[test]
+    fn find_iter() {
+        let finder = Finder::new("aba");
+        assert_eq!(finder.find_iter("abababa").collect::<Vec<_>>(), [0, 4]);
+        assert_eq!(finder.find_iter("xxabaxxaba").collect::<Vec<_>>(), [2, 7]);
+    }
+
+    #[test]
+    fn matches_std() {
+        let haystacks = ["", "a", "abracadabra", "aaaaaaa", "abcabcabd", "xöxöäx"];
+        let needles = ["", "a", "ab", "abra", "aaa", "abd", "ö", "xö", "cab"];
+
+        for haystack in haystacks {
+            for needle in needles {
+                let finder = Finder::new(needle);
+                assert_eq!(finder.find(haystack), haystack.find(needle));
+                assert_eq!(finder.rfind(haystack), haystack.rfind(needle));
+                assert!(finder
+                    .find_iter(haystack)
+                    .eq(haystack.match_indices(needle).map(|(idx, _)| idx)));
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::{collections::BTreeMap, num::NonZeroUsize};
+
+/// Returns the number of occurrences of each char in `input`, ordered by char.
+///
+/// # Complexity
+/// This algorithm requires `O(n * log m)` time where `n` is the length of the input string and `m`
+/// the number of distinct chars.
+///
+/// # Allocation
+/// A [`BTreeMap`] with an entry per distinct char is allocated.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let counts = find::frequencies("hello");
+/// assert_eq!(counts.into_iter().collect::<Vec<_>>(), [('e', 1), ('h', 1), ('l', 2), ('o', 1)]);
+/// ```
+pub fn frequencies(input: &str) -> BTreeMap<char, usize> {
+    let mut counts = BTreeMap::new();
+    for ch in input.chars() {
+        *counts.entry(ch).or_default() += 1;
+    }
+    counts
+}
+
+/// Returns the number of occurrences of each n-gram of `n` consecutive chars in `input`, ordered
+/// by n-gram. The n-grams overlap and are slices of `input`, if `input` has fewer than `n` chars
+/// the map is empty.
+///
+/// # Complexity
+/// This algorithm requires `O(l * log m)` time where `l` is the length of the input string and `m`
+/// the number of distinct n-grams, ignoring the cost of comparing n-grams.
+///
+/// # Allocation
+/// A [`BTreeMap`] with an entry per distinct n-gram is allocated.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::find;
+///
+/// let counts = find::ngram_frequencies("abab", 2.try_into()?);
+/// assert_eq!(counts.into_iter().collect::<Vec<_>>(), [("ab", 2), ("ba", 1)]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn ngram_frequencies(input: &str, n: NonZeroUsize) -> BTreeMap<&str, usize> {
+    let mut counts = BTreeMap::new();
+    let mut starts = input.char_indices().map(|(idx, _)| idx);
+    let ends = input
+        .char_indices()
+        .map(|(idx, _)| idx)
+        .chain([input.len()])
+        .skip(n.get());
+
+    for end in ends {
+        // there is a start for each end, since the ends are n chars ahead
+        let Some(start) = starts.next() else { break };
+        *counts.entry(&input[start..end]).or_default() += 1;
+    }
+
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(n: usize) -> NonZeroUsize {
+        n.try_into().unwrap()
+    }
+
+    #[test]
+    fn chars() {
+        assert!(frequencies("").is_empty());
+        assert_eq!(frequencies("aaa")[&'a'], 3);
+
+        let counts = frequencies("äbäc");
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&'ä'], 2);
+        assert_eq!(counts[&'b'], 1);
+        assert_eq!(counts[&'c'], 1);
+    }
+
+    #[test]
+    fn ngrams() {
+        assert!(ngram_frequencies("", n(1)).is_empty());
+        assert!(ngram_frequencies("ab", n(3)).is_empty());
+        assert_eq!(ngram_frequencies("abc", n(3))["abc"], 1);
+        assert_eq!(ngram_frequencies("aaaa", n(2))["aa"], 3);
+
+        let counts = ngram_frequencies("äöäö", n(2));
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts["äö"], 2);
+        assert_eq!(counts["öä"], 1);
+    }
+
+    #[test]
+    fn unigrams_are_chars() {
+        let input = "hello wörld";
+        let chars = frequencies(input);
+        let ngrams = ngram_frequencies(input, n(1));
+        assert!(chars
+            .iter()
+            .map(|(ch, count)| (ch.to_string(), *count))
+            .eq(ngrams.iter().map(|(s, count)| (s.to_string(), *count))));
+    }
+}
@@ -0,0 +1,202 @@
+use super::eq_ignore_case;
+
+/// The score of a single matched char.
+const SCORE_MATCH: i64 = 16;
+
+/// The penalty for each skipped char between two matched chars.
+const PENALTY_GAP: i64 = 1;
+
+/// The bonus for a match directly following the previous match.
+const BONUS_CONSECUTIVE: i64 = 8;
+
+/// The bonus for a match at the start of a word, eg.: at the start of the haystack or after a
+/// non-alphanumeric char.
+const BONUS_BOUNDARY: i64 = 8;
+
+/// The bonus for a match at a camel case transition, eg.: an uppercase char after a lowercase
+/// char.
+const BONUS_CAMEL: i64 = 6;
+
+/// Returns the bonus for matching the char at index `idx` of `chars`.
+fn bonus(chars: &[(usize, char)], idx: usize) -> i64 {
+    let (_, curr) = chars[idx];
+    match idx.checked_sub(1).map(|prev| chars[prev].1) {
+        None => BONUS_BOUNDARY,
+        Some(prev) if !prev.is_alphanumeric() && curr.is_alphanumeric() => BONUS_BOUNDARY,
+        Some(prev) if prev.is_lowercase() && curr.is_uppercase() => BONUS_CAMEL,
+        Some(_) => 0,
+    }
+}
+
+/// Matches `needle` as a subsequence of `haystack`, eg.: all chars of `needle` must appear in
+/// `haystack` in order, but not necessarily next to each other. Chars are compared ignoring case.
+/// Returns [`None`] if `needle` is not a subsequence of `haystack`, otherwise the score of the
+/// best match and the byte indices of the matched chars in `haystack`.
+///
+/// Matches are scored by the number of matched chars, with bonuses for consecutive matches and
+/// matches at word boundaries or camel case transitions and a penalty for gaps between matched
+/// chars. Higher scores are better matches, scores of different haystacks for the same needle are
+/// comparable, which makes them suitable for ranking in interactive filters. An empty needle
+/// matches every haystack with a score of `0`.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input
+/// strings.
+///
+/// # Allocation
+/// The chars of both strings, their scores and `n * m` back references are collected into [Vec]s.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let (score, indices) = find::fuzzy_match("src/find/fuzzy.rs", "ffr").unwrap();
+/// // the matched chars are at word boundaries rather than the first occurrences
+/// assert_eq!(indices, [4, 9, 15]);
+///
+/// // consecutive and boundary matches score higher
+/// let (better, _) = find::fuzzy_match("find_fuzzy", "fuz").unwrap();
+/// let (worse, _) = find::fuzzy_match("afoobuzz", "fuz").unwrap();
+/// assert!(better > worse);
+///
+/// assert_eq!(find::fuzzy_match("abc", "cb"), None);
+/// ```
+pub fn fuzzy_match(haystack: &str, needle: &str) -> Option<(i64, Vec<usize>)> {
+    let chars: Vec<(usize, char)> = haystack.char_indices().collect();
+    let needle: Vec<char> = needle.chars().collect();
+
+    if needle.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    if needle.len() > chars.len() {
+        return None;
+    }
+
+    let len = chars.len();
+    let bonuses: Vec<i64> = (0..len).map(|idx| bonus(&chars, idx)).collect();
+
+    // the best scores of matching the needle up to the previous char with that char matched at a
+    // given haystack index, and the index the char before it was matched at for backtracking
+    let mut prev: Vec<Option<i64>> = vec![None; len];
+    let mut back = vec![0; needle.len() * len];
+
+    for (n_idx, &n_ch) in needle.iter().enumerate() {
+        let mut curr = vec![None; len];
+
+        // the best gapped predecessor, scores are offset by their index to apply the gap
+        // penalty in constant time
+        let mut best_gapped: Option<(i64, usize)> = None;
+
+        for h_idx in 0..len {
+            if h_idx >= 2
+                && let Some(score) = prev[h_idx - 2]
+            {
+                let offset = score + PENALTY_GAP * (h_idx - 2) as i64;
+                if best_gapped.is_none_or(|(best, _)| offset > best) {
+                    best_gapped = Some((offset, h_idx - 2));
+                }
+            }
+
+            if !eq_ignore_case(chars[h_idx].1, n_ch) {
+                continue;
+            }
+
+            let base = SCORE_MATCH + bonuses[h_idx];
+
+            if n_idx == 0 {
+                curr[h_idx] = Some(base);
+                continue;
+            }
+
+            let consecutive = h_idx
+                .checked_sub(1)
+                .and_then(|p_idx| prev[p_idx].map(|score| (score + BONUS_CONSECUTIVE, p_idx)));
+            let gapped = best_gapped
+                .map(|(offset, p_idx)| (offset - PENALTY_GAP * (h_idx - 1) as i64, p_idx));
+
+            let best = match (consecutive, gapped) {
+                (Some(c), Some(g)) => Some(if c.0 >= g.0 { c } else { g }),
+                (c, g) => c.or(g),
+            };
+
+            if let Some((score, p_idx)) = best {
+                curr[h_idx] = Some(score + base);
+                back[n_idx * len + h_idx] = p_idx;
+            }
+        }
+
+        prev = curr;
+    }
+
+    let (mut h_idx, score) = prev
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, score)| score.map(|score| (idx, score)))
+        .fold(
+            None,
+            |best: Option<(usize, i64)>, (idx, score)| match best {
+                Some((_, best_score)) if best_score >= score => best,
+                _ => Some((idx, score)),
+            },
+        )?;
+
+    let mut indices = vec![0; needle.len()];
+    for n_idx in (0..needle.len()).rev() {
+        indices[n_idx] = chars[h_idx].0;
+        h_idx = back[n_idx * len + h_idx];
+    }
+
+    Some((score, indices))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(fuzzy_match("", ""), Some((0, vec![])));
+        assert_eq!(fuzzy_match("abc", ""), Some((0, vec![])));
+        assert_eq!(fuzzy_match("", "a"), None);
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(fuzzy_match("abc", "abcd"), None);
+        assert_eq!(fuzzy_match("abc", "ca"), None);
+        assert_eq!(fuzzy_match("abc", "x"), None);
+    }
+
+    #[test]
+    fn indices() {
+        assert_eq!(fuzzy_match("abc", "abc").unwrap().1, [0, 1, 2]);
+        assert_eq!(fuzzy_match("axbxc", "abc").unwrap().1, [0, 2, 4]);
+        assert_eq!(fuzzy_match("äöü", "ü").unwrap().1, [4]);
+    }
+
+    #[test]
+    fn ignore_case() {
+        assert_eq!(fuzzy_match("ABC", "abc").unwrap().1, [0, 1, 2]);
+        assert_eq!(fuzzy_match("Äb", "äB").unwrap().1, [0, 2]);
+    }
+
+    #[test]
+    fn prefers_consecutive() {
+        assert_eq!(fuzzy_match("abxab", "ab").unwrap().1, [0, 1]);
+        assert_eq!(fuzzy_match("axxxbab", "ab").unwrap().1, [5, 6]);
+    }
+
+    #[test]
+    fn prefers_boundaries() {
+        assert_eq!(fuzzy_match("xfoo_foo", "f").unwrap().1, [5]);
+        assert_eq!(fuzzy_match("xfooFoo", "f").unwrap().1, [4]);
+    }
+
+    #[test]
+    fn scores() {
+        let (consecutive, _) = fuzzy_match("xabc", "abc").unwrap();
+        let (gapped, _) = fuzzy_match("xaxbxc", "abc").unwrap();
+        assert!(consecutive > gapped);
+    }
+}
@@ -0,0 +1,263 @@
+use std::ops::Range;
+
+/// The full case foldings of chars (status `C` and `F` in Unicode's `CaseFolding.txt`) that
+/// differ from their lowercase mapping, sorted by char. Folding any other char is equivalent to
+/// lowercasing it, the Cherokee letters fold to uppercase instead, but lowercasing them yields
+/// the same equivalence.
+const FOLDS: &[(char, &str)] = &[
+    ('\u{B5}', "\u{3BC}"),
+    ('\u{DF}', "ss"),
+    ('\u{149}', "\u{2BC}n"),
+    ('\u{17F}', "s"),
+    ('\u{1F0}', "j\u{30C}"),
+    ('\u{345}', "\u{3B9}"),
+    ('\u{390}', "\u{3B9}\u{308}\u{301}"),
+    ('\u{3B0}', "\u{3C5}\u{308}\u{301}"),
+    ('\u{3C2}', "\u{3C3}"),
+    ('\u{3D0}', "\u{3B2}"),
+    ('\u{3D1}', "\u{3B8}"),
+    ('\u{3D5}', "\u{3C6}"),
+    ('\u{3D6}', "\u{3C0}"),
+    ('\u{3F0}', "\u{3BA}"),
+    ('\u{3F1}', "\u{3C1}"),
+    ('\u{3F5}', "\u{3B5}"),
+    ('\u{587}', "\u{565}\u{582}"),
+    ('\u{1C80}', "\u{432}"),
+    ('\u{1C81}', "\u{434}"),
+    ('\u{1C82}', "\u{43E}"),
+    ('\u{1C83}', "\u{441}"),
+    ('\u{1C84}', "\u{442}"),
+    ('\u{1C85}', "\u{442}"),
+    ('\u{1C86}', "\u{44A}"),
+    ('\u{1C87}', "\u{463}"),
+    ('\u{1C88}', "\u{A64B}"),
+    ('\u{1E96}', "h\u{331}"),
+    ('\u{1E97}', "t\u{308}"),
+    ('\u{1E98}', "w\u{30A}"),
+    ('\u{1E99}', "y\u{30A}"),
+    ('\u{1E9A}', "a\u{2BE}"),
+    ('\u{1E9B}', "\u{1E61}"),
+    ('\u{1E9E}', "ss"),
+    ('\u{1F50}', "\u{3C5}\u{313}"),
+    ('\u{1F52}', "\u{3C5}\u{313}\u{300}"),
+    ('\u{1F54}', "\u{3C5}\u{313}\u{301}"),
+    ('\u{1F56}', "\u{3C5}\u{313}\u{342}"),
+    ('\u{1F80}', "\u{1F00}\u{3B9}"),
+    ('\u{1F81}', "\u{1F01}\u{3B9}"),
+    ('\u{1F82}', "\u{1F02}\u{3B9}"),
+    ('\u{1F83}', "\u{1F03}\u{3B9}"),
+    ('\u{1F84}', "\u{1F04}\u{3B9}"),
+    ('\u{1F85}', "\u{1F05}\u{3B9}"),
+    ('\u{1F86}', "\u{1F06}\u{3B9}"),
+    ('\u{1F87}', "\u{1F07}\u{3B9}"),
+    ('\u{1F88}', "\u{1F00}\u{3B9}"),
+    ('\u{1F89}', "\u{1F01}\u{3B9}"),
+    ('\u{1F8A}', "\u{1F02}\u{3B9}"),
+    ('\u{1F8B}', "\u{1F03}\u{3B9}"),
+    ('\u{1F8C}', "\u{1F04}\u{3B9}"),
+    ('\u{1F8D}', "\u{1F05}\u{3B9}"),
+    ('\u{1F8E}', "\u{1F06}\u{3B9}"),
+    ('\u{1F8F}', "\u{1F07}\u{3B9}"),
+    ('\u{1F90}', "\u{1F20}\u{3B9}"),
+    ('\u{1F91}', "\u{1F21}\u{3B9}"),
+    ('\u{1F92}', "\u{1F22}\u{3B9}"),
+    ('\u{1F93}', "\u{1F23}\u{3B9}"),
+    ('\u{1F94}', "\u{1F24}\u{3B9}"),
+    ('\u{1F95}', "\u{1F25}\u{3B9}"),
+    ('\u{1F96}', "\u{1F26}\u{3B9}"),
+    ('\u{1F97}', "\u{1F27}\u{3B9}"),
+    ('\u{1F98}', "\u{1F20}\u{3B9}"),
+    ('\u{1F99}', "\u{1F21}\u{3B9}"),
+    ('\u{1F9A}', "\u{1F22}\u{3B9}"),
+    ('\u{1F9B}', "\u{1F23}\u{3B9}"),
+    ('\u{1F9C}', "\u{1F24}\u{3B9}"),
+    ('\u{1F9D}', "\u{1F25}\u{3B9}"),
+    ('\u{1F9E}', "\u{1F26}\u{3B9}"),
+    ('\u{1F9F}', "\u{1F27}\u{3B9}"),
+    ('\u{1FA0}', "\u{1F60}\u{3B9}"),
+    ('\u{1FA1}', "\u{1F61}\u{3B9}"),
+    ('\u{1FA2}', "\u{1F62}\u{3B9}"),
+    ('\u{1FA3}', "\u{1F63}\u{3B9}"),
+    ('\u{1FA4}', "\u{1F64}\u{3B9}"),
+    ('\u{1FA5}', "\u{1F65}\u{3B9}"),
+    ('\u{1FA6}', "\u{1F66}\u{3B9}"),
+    ('\u{1FA7}', "\u{1F67}\u{3B9}"),
+    ('\u{1FA8}', "\u{1F60}\u{3B9}"),
+    ('\u{1FA9}', "\u{1F61}\u{3B9}"),
+    ('\u{1FAA}', "\u{1F62}\u{3B9}"),
+    ('\u{1FAB}', "\u{1F63}\u{3B9}"),
+    ('\u{1FAC}', "\u{1F64}\u{3B9}"),
+    ('\u{1FAD}', "\u{1F65}\u{3B9}"),
+    ('\u{1FAE}', "\u{1F66}\u{3B9}"),
+    ('\u{1FAF}', "\u{1F67}\u{3B9}"),
+    ('\u{1FB2}', "\u{1F70}\u{3B9}"),
+    ('\u{1FB3}', "\u{3B1}\u{3B9}"),
+    ('\u{1FB4}', "\u{3AC}\u{3B9}"),
+    ('\u{1FB6}', "\u{3B1}\u{342}"),
+    ('\u{1FB7}', "\u{3B1}\u{342}\u{3B9}"),
+    ('\u{1FBC}', "\u{3B1}\u{3B9}"),
+    ('\u{1FBE}', "\u{3B9}"),
+    ('\u{1FC2}', "\u{1F74}\u{3B9}"),
+    ('\u{1FC3}', "\u{3B7}\u{3B9}"),
+    ('\u{1FC4}', "\u{3AE}\u{3B9}"),
+    ('\u{1FC6}', "\u{3B7}\u{342}"),
+    ('\u{1FC7}', "\u{3B7}\u{342}\u{3B9}"),
+    ('\u{1FCC}', "\u{3B7}\u{3B9}"),
+    ('\u{1FD2}', "\u{3B9}\u{308}\u{300}"),
+    ('\u{1FD3}', "\u{3B9}\u{308}\u{301}"),
+    ('\u{1FD6}', "\u{3B9}\u{342}"),
+    ('\u{1FD7}', "\u{3B9}\u{308}\u{342}"),
+    ('\u{1FE2}', "\u{3C5}\u{308}\u{300}"),
+    ('\u{1FE3}', "\u{3C5}\u{308}\u{301}"),
+    ('\u{1FE4}', "\u{3C1}\u{313}"),
+    ('\u{1FE6}', "\u{3C5}\u{342}"),
+    ('\u{1FE7}', "\u{3C5}\u{308}\u{342}"),
+    ('\u{1FF2}', "\u{1F7C}\u{3B9}"),
+    ('\u{1FF3}', "\u{3C9}\u{3B9}"),
+    ('\u{1FF4}', "\u{3CE}\u{3B9}"),
+    ('\u{1FF6}', "\u{3C9}\u{342}"),
+    ('\u{1FF7}', "\u{3C9}\u{342}\u{3B9}"),
+    ('\u{1FFC}', "\u{3C9}\u{3B9}"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"),
+    ('\u{FB06}', "st"),
+    ('\u{FB13}', "\u{574}\u{576}"),
+    ('\u{FB14}', "\u{574}\u{565}"),
+    ('\u{FB15}', "\u{574}\u{56B}"),
+    ('\u{FB16}', "\u{57E}\u{576}"),
+    ('\u{FB17}', "\u{574}\u{56D}"),
+];
+
+/// Returns the full case folding of `ch`.
+fn fold(ch: char) -> impl Iterator<Item = char> {
+    let folded = FOLDS
+        .binary_search_by_key(&ch, |&(from, _)| from)
+        .ok()
+        .map(|idx| FOLDS[idx].1);
+    let lower = folded.is_none().then(|| ch.to_lowercase());
+
+    folded
+        .into_iter()
+        .flat_map(str::chars)
+        .chain(lower.into_iter().flatten())
+}
+
+/// Returns the byte length of the prefix of `haystack` that matches `needle` ignoring case, or
+/// [`None`] if `haystack` does not start with `needle`.
+fn match_len(haystack: &str, needle: &str) -> Option<usize> {
+    let mut needle = needle.chars().flat_map(fold).peekable();
+
+    for (idx, ch) in haystack.char_indices() {
+        if needle.peek().is_none() {
+            return Some(idx);
+        }
+
+        for folded in fold(ch) {
+            if needle.next() != Some(folded) {
+                return None;
+            }
+        }
+    }
+
+    needle.peek().is_none().then_some(haystack.len())
+}
+
+/// Returns the byte range of the first occurrence of `needle` in `haystack` ignoring case. The
+/// range refers to the original `haystack`, even if the case folded forms of matched chars have a
+/// different length in bytes. Chars are compared by their full Unicode case folding, so `'ß'`
+/// matches `"ss"` and `'İ'` matches `"i̇"`, but a match must not end inside the folding of a char.
+/// An empty needle matches at the start of the haystack.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input
+/// strings. If both inputs are ASCII, their bytes are compared directly.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::find_ignore_case("Hello World", "WORLD"), Some(6..11));
+/// assert_eq!(find::find_ignore_case("Straße ÄRGER", "ärger"), Some(8..14));
+/// assert_eq!(find::find_ignore_case("Große Straße", "STRASSE"), Some(7..14));
+/// assert_eq!(find::find_ignore_case("abc", "x"), None);
+/// ```
+pub fn find_ignore_case(haystack: &str, needle: &str) -> Option<Range<usize>> {
+    if haystack.is_ascii() && needle.is_ascii() {
+        if needle.is_empty() {
+            return Some(0..0);
+        }
+
+        return haystack
+            .as_bytes()
+            .windows(needle.len())
+            .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+            .map(|start| start..start + needle.len());
+    }
+
+    haystack
+        .char_indices()
+        .map(|(idx, _)| idx)
+        .chain([haystack.len()])
+        .find_map(|start| match_len(&haystack[start..], needle).map(|len| start..start + len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(find_ignore_case("", ""), Some(0..0));
+        assert_eq!(find_ignore_case("abc", ""), Some(0..0));
+        assert_eq!(find_ignore_case("äbc", ""), Some(0..0));
+        assert_eq!(find_ignore_case("", "a"), None);
+        assert_eq!(find_ignore_case("", "ä"), None);
+    }
+
+    #[test]
+    fn ascii() {
+        assert_eq!(find_ignore_case("abc", "ABC"), Some(0..3));
+        assert_eq!(find_ignore_case("xxAbC", "aBc"), Some(2..5));
+        assert_eq!(find_ignore_case("ab", "abc"), None);
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(find_ignore_case("xÄÖÜ", "äöü"), Some(1..7));
+        assert_eq!(find_ignore_case("äöü", "ÖÜ"), Some(2..6));
+        assert_eq!(find_ignore_case("äöü", "öa"), None);
+    }
+
+    #[test]
+    fn different_lengths() {
+        // 'İ' is 2 bytes, but lowercases to 'i' and a combining dot, 3 bytes in total
+        assert_eq!(find_ignore_case("xİx", "i\u{307}"), Some(1..3));
+        // matches must end on a char boundary of the haystack
+        assert_eq!(find_ignore_case("xİx", "ix"), None);
+        // 'K' (Kelvin sign) lowercases to 'k'
+        assert_eq!(find_ignore_case("5 \u{212A}", "k"), Some(2..5));
+    }
+
+    #[test]
+    fn folding() {
+        assert_eq!(find_ignore_case("STRASSE", "straße"), Some(0..7));
+        assert_eq!(find_ignore_case("xßx", "SS"), Some(1..3));
+        assert_eq!(find_ignore_case("xẞx", "ß"), Some(1..4));
+        // 'ς' (final sigma) and 'Σ' both fold to 'σ'
+        assert_eq!(find_ignore_case("ΟΔΟΣ", "οδος"), Some(0..8));
+        // the match must not end inside the folding of 'ß'
+        assert_eq!(find_ignore_case("ß", "s"), None);
+    }
+
+    #[test]
+    fn folds_sorted() {
+        assert!(FOLDS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
@@ -0,0 +1,442 @@
+use crate::util::Span;
+
+/// An [Error][0] for [`matching`] and [`matching_quoted`], see their documentation for more info.
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum MatchingError {
+    /// Indicates that the given index was not on an opening bracket, contains the span of the char
+    /// at the index or an empty span if there is none.
+    #[error("the char at {0} is not an opening bracket")]
+    NotOpening(Span),
+
+    /// Indicates that a closing bracket did not match the innermost open bracket, contains the span
+    /// of the closing bracket.
+    #[error("the closing bracket at {0} does not match the open bracket")]
+    Mismatched(Span),
+
+    /// Indicates that the input ended before the bracket was closed.
+    #[error("the bracket was not closed")]
+    Unclosed,
+}
+
+/// Returns the byte index of the closing bracket matching the opening bracket at `open_idx`.
+/// Nested brackets of all given `pairs` are skipped, brackets preceded by the escape `esc` are
+/// ignored. See [`matching_quoted`] to also ignore brackets in quoted regions.
+///
+/// # Errors
+/// Returns an error if:
+/// - `open_idx` is not on an opening bracket of `pairs`
+/// - a closing bracket does not match the innermost open bracket, eg.: `(]`
+/// - the bracket is not closed before the end of `input`
+///
+/// # Complexity
+/// This algorithm requires `O(n * p)` time where `n` is the length of the input string and `p` the
+/// number of pairs.
+///
+/// # Allocation
+/// The expected closing brackets of nested brackets are kept in a [Vec].
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{find, util::Span};
+///
+/// let pairs = [('(', ')'), ('[', ']')];
+/// assert_eq!(find::matching(r"f(a[0], \), g(b)) + c", 1, &pairs, '\\')?, 16);
+/// assert_eq!(
+///     find::matching("f(a[0)]", 1, &pairs, '\\'),
+///     Err(find::MatchingError::Mismatched(Span::new(5, 6)))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn matching(
+    input: &str,
+    open_idx: usize,
+    pairs: &[(char, char)],
+    esc: char,
+) -> Result<usize, MatchingError> {
+    matching_quoted(input, open_idx, pairs, esc, &[])
+}
+
+/// Behaves like [`matching`], but brackets inside quoted regions are ignored. A quoted region
+/// starts at a non-escaped char of `quotes` and ends at the next non-escaped occurrence of the same
+/// char. The opening bracket must not be inside a quoted region itself.
+///
+/// # Errors
+/// Returns an error if:
+/// - `open_idx` is not on an opening bracket of `pairs`
+/// - a closing bracket does not match the innermost open bracket, eg.: `(]`
+/// - the bracket is not closed before the end of `input`, including if a quoted region is not
+///   closed
+///
+/// # Complexity
+/// This algorithm requires `O(n * (p + q))` time where `n` is the length of the input string, `p`
+/// the number of pairs and `q` the number of quotes.
+///
+/// # Allocation
+/// The expected closing brackets of nested brackets are kept in a [Vec].
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::find;
+///
+/// let input = r#"{ "key": "}" }"#;
+/// assert_eq!(find::matching_quoted(input, 0, &[('{', '}')], '\\', &['"'])?, 13);
+///
+/// // without quotes, the quoted bracket closes the region
+/// assert_eq!(find::matching(input, 0, &[('{', '}')], '\\')?, 10);
+/// # Ok(())
+/// # }
+/// ```
+pub fn matching_quoted(
+    input: &str,
+    open_idx: usize,
+    pairs: &[(char, char)],
+    esc: char,
+    quotes: &[char],
+) -> Result<usize, MatchingError> {
+    let Some(first) = input.get(open_idx..).and_then(|rest| rest.chars().next()) else {
+        return Err(MatchingError::NotOpening(Span::empty(open_idx)));
+    };
+    let Some(&(_, close)) = pairs.iter().find(|(open, _)| *open == first) else {
+        return Err(MatchingError::NotOpening(char_span(open_idx, first)));
+    };
+
+    let mut expected = vec![close];
+    let start = open_idx + first.len_utf8();
+
+    for (idx, token) in Tokens::new(&input[start..], pairs, esc, quotes) {
+        match token {
+            Token::Open(_, close) => expected.push(close),
+            Token::Close(ch) => {
+                if expected.pop() != Some(ch) {
+                    return Err(MatchingError::Mismatched(char_span(start + idx, ch)));
+                }
+
+                if expected.is_empty() {
+                    return Ok(start + idx);
+                }
+            }
+            Token::UnterminatedQuote(_) => break,
+        }
+    }
+
+    Err(MatchingError::Unclosed)
+}
+
+/// The kind of an [`UnbalancedError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnbalancedKind {
+    /// Indicates that an opening bracket was not closed.
+    Unclosed(char),
+
+    /// Indicates that a closing bracket did not match the innermost open bracket or that there
+    /// was no open bracket.
+    UnexpectedClose(char),
+
+    /// Indicates that a quoted region was not closed.
+    UnterminatedQuote(char),
+}
+
+impl std::fmt::Display for UnbalancedKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unclosed(ch) => write!(f, "unclosed bracket '{ch}'"),
+            Self::UnexpectedClose(ch) => write!(f, "unexpected closing bracket '{ch}'"),
+            Self::UnterminatedQuote(ch) => write!(f, "unterminated quote '{ch}'"),
+        }
+    }
+}
+
+/// An [Error][0] for [`validate_balanced`], see it's documentation for more info.
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[error("{kind} at {span}")]
+pub struct UnbalancedError {
+    /// The span of the offending bracket or quote.
+    pub span: Span,
+
+    /// The kind of the error.
+    pub kind: UnbalancedKind,
+}
+
+/// Validates that all brackets of the given `pairs` in `input` are balanced and properly nested,
+/// brackets preceded by the escape `esc` and brackets in quoted regions are ignored, see
+/// [`matching_quoted`] for the quoting rules.
+///
+/// # Errors
+/// Returns an error with the span of:
+/// - the innermost opening bracket that is not closed
+/// - a closing bracket that does not match the innermost open bracket or has no open bracket
+/// - the opening quote of a quoted region that is not closed
+///
+/// # Complexity
+/// This algorithm requires `O(n * (p + q))` time where `n` is the length of the input string, `p`
+/// the number of pairs and `q` the number of quotes.
+///
+/// # Allocation
+/// The open brackets are kept in a [Vec].
+///
+/// # Examples
+/// ```
+/// use strtools::{
+///     find::{self, UnbalancedError, UnbalancedKind},
+///     util::Span,
+/// };
+///
+/// let pairs = [('(', ')'), ('[', ']')];
+/// assert_eq!(find::validate_balanced("f(a[0], ')')", &pairs, &['\''], '\\'), Ok(()));
+/// assert_eq!(
+///     find::validate_balanced("f(a[0)]", &pairs, &[], '\\'),
+///     Err(UnbalancedError {
+///         span: Span::new(5, 6),
+///         kind: UnbalancedKind::UnexpectedClose(')'),
+///     })
+/// );
+/// assert_eq!(
+///     find::validate_balanced("f(a, 'b)", &pairs, &['\''], '\\'),
+///     Err(UnbalancedError {
+///         span: Span::new(5, 6),
+///         kind: UnbalancedKind::UnterminatedQuote('\''),
+///     })
+/// );
+/// ```
+pub fn validate_balanced(
+    input: &str,
+    pairs: &[(char, char)],
+    quotes: &[char],
+    esc: char,
+) -> Result<(), UnbalancedError> {
+    // the offsets and chars of open brackets and their expected closing brackets
+    let mut open: Vec<(usize, char, char)> = vec![];
+
+    for (offset, token) in Tokens::new(input, pairs, esc, quotes) {
+        let (ch, kind) = match token {
+            Token::Open(ch, close) => {
+                open.push((offset, ch, close));
+                continue;
+            }
+            Token::Close(ch) => match open.pop() {
+                Some((_, _, close)) if close == ch => continue,
+                _ => (ch, UnbalancedKind::UnexpectedClose(ch)),
+            },
+            Token::UnterminatedQuote(quote) => (quote, UnbalancedKind::UnterminatedQuote(quote)),
+        };
+
+        return Err(UnbalancedError {
+            span: char_span(offset, ch),
+            kind,
+        });
+    }
+
+    match open.pop() {
+        Some((offset, ch, _)) => Err(UnbalancedError {
+            span: char_span(offset, ch),
+            kind: UnbalancedKind::Unclosed(ch),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the [`Span`] of `ch` at `offset`.
+fn char_span(offset: usize, ch: char) -> Span {
+    Span::new(offset, offset + ch.len_utf8())
+}
+
+/// A significant token for bracket matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    /// An opening bracket and its expected closing bracket.
+    Open(char, char),
+
+    /// A closing bracket.
+    Close(char),
+
+    /// The opening quote of an unterminated quoted region, this is always the last token.
+    UnterminatedQuote(char),
+}
+
+/// An [Iterator] yielding the non-escaped and non-quoted bracket tokens of a [str] with their byte
+/// offsets.
+#[derive(Debug, Clone)]
+struct Tokens<'s> {
+    iter: std::str::CharIndices<'s>,
+    pairs: &'s [(char, char)],
+    esc: char,
+    quotes: &'s [char],
+}
+
+impl<'s> Tokens<'s> {
+    fn new(input: &'s str, pairs: &'s [(char, char)], esc: char, quotes: &'s [char]) -> Self {
+        Self {
+            iter: input.char_indices(),
+            pairs,
+            esc,
+            quotes,
+        }
+    }
+}
+
+impl<'s> Iterator for Tokens<'s> {
+    type Item = (usize, Token);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut is_escaped = false;
+        let mut quote = None;
+
+        for (idx, ch) in self.iter.by_ref() {
+            if is_escaped {
+                is_escaped = false;
+                continue;
+            }
+
+            if ch == self.esc {
+                is_escaped = true;
+                continue;
+            }
+
+            if let Some((_, q)) = quote {
+                if ch == q {
+                    quote = None;
+                }
+                continue;
+            }
+
+            if self.quotes.contains(&ch) {
+                quote = Some((idx, ch));
+            } else if let Some(&(_, close)) = self.pairs.iter().find(|(open, _)| *open == ch) {
+                return Some((idx, Token::Open(ch, close)));
+            } else if self.pairs.iter().any(|(_, close)| *close == ch) {
+                return Some((idx, Token::Close(ch)));
+            }
+        }
+
+        quote.map(|(idx, q)| (idx, Token::UnterminatedQuote(q)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAIRS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
+    #[test]
+    fn not_opening() {
+        assert_eq!(
+            matching("", 0, &PAIRS, '\\'),
+            Err(MatchingError::NotOpening(Span::empty(0)))
+        );
+        assert_eq!(
+            matching("a()", 0, &PAIRS, '\\'),
+            Err(MatchingError::NotOpening(Span::new(0, 1)))
+        );
+        assert_eq!(
+            matching("()", 1, &PAIRS, '\\'),
+            Err(MatchingError::NotOpening(Span::new(1, 2)))
+        );
+        assert_eq!(
+            matching("ö()", 1, &PAIRS, '\\'),
+            Err(MatchingError::NotOpening(Span::empty(1)))
+        );
+        assert_eq!(
+            matching("ö()", 0, &PAIRS, '\\'),
+            Err(MatchingError::NotOpening(Span::new(0, 2)))
+        );
+        assert_eq!(
+            matching("()", 5, &PAIRS, '\\'),
+            Err(MatchingError::NotOpening(Span::empty(5)))
+        );
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(matching("()", 0, &PAIRS, '\\'), Ok(1));
+        assert_eq!(matching("a(bö)c", 1, &PAIRS, '\\'), Ok(5));
+        assert_eq!(matching("(()", 1, &PAIRS, '\\'), Ok(2));
+    }
+
+    #[test]
+    fn nested() {
+        assert_eq!(matching("(())", 0, &PAIRS, '\\'), Ok(3));
+        assert_eq!(matching("([{}])", 0, &PAIRS, '\\'), Ok(5));
+        assert_eq!(
+            matching("([)]", 0, &PAIRS, '\\'),
+            Err(MatchingError::Mismatched(Span::new(2, 3)))
+        );
+        assert_eq!(
+            matching("(()", 0, &PAIRS, '\\'),
+            Err(MatchingError::Unclosed)
+        );
+    }
+
+    #[test]
+    fn escaped() {
+        assert_eq!(matching(r"(\))", 0, &PAIRS, '\\'), Ok(3));
+        assert_eq!(matching(r"(\()", 0, &PAIRS, '\\'), Ok(3));
+        assert_eq!(matching(r"(\\)", 0, &PAIRS, '\\'), Ok(3));
+        assert_eq!(matching(r"(\\\))", 0, &PAIRS, '\\'), Ok(5));
+    }
+
+    #[test]
+    fn quoted() {
+        assert_eq!(matching_quoted("(')')", 0, &PAIRS, '\\', &['\'']), Ok(4));
+        assert_eq!(
+            matching_quoted(r#"("'")"#, 0, &PAIRS, '\\', &['\'', '"']),
+            Ok(4)
+        );
+        assert_eq!(matching_quoted(r"('\')')", 0, &PAIRS, '\\', &['\'']), Ok(6));
+        assert_eq!(
+            matching_quoted("(')", 0, &PAIRS, '\\', &['\'']),
+            Err(MatchingError::Unclosed)
+        );
+    }
+
+    #[test]
+    fn balanced() {
+        assert_eq!(validate_balanced("", &PAIRS, &[], '\\'), Ok(()));
+        assert_eq!(validate_balanced("abc", &PAIRS, &[], '\\'), Ok(()));
+        assert_eq!(validate_balanced("([]{()})", &PAIRS, &[], '\\'), Ok(()));
+        assert_eq!(validate_balanced(r"(\))", &PAIRS, &[], '\\'), Ok(()));
+        assert_eq!(validate_balanced("(')')", &PAIRS, &['\''], '\\'), Ok(()));
+    }
+
+    #[test]
+    fn unbalanced() {
+        let err = |start, end, kind| {
+            Err(UnbalancedError {
+                span: Span::new(start, end),
+                kind,
+            })
+        };
+
+        assert_eq!(
+            validate_balanced("ö(()", &PAIRS, &[], '\\'),
+            err(2, 3, UnbalancedKind::Unclosed('('))
+        );
+        assert_eq!(
+            validate_balanced("())", &PAIRS, &[], '\\'),
+            err(2, 3, UnbalancedKind::UnexpectedClose(')'))
+        );
+        assert_eq!(
+            validate_balanced("(]", &PAIRS, &[], '\\'),
+            err(1, 2, UnbalancedKind::UnexpectedClose(']'))
+        );
+        assert_eq!(
+            validate_balanced("()'(", &PAIRS, &['\''], '\\'),
+            err(2, 3, UnbalancedKind::UnterminatedQuote('\''))
+        );
+        assert_eq!(
+            validate_balanced("('a)", &PAIRS, &['\''], '\\'),
+            err(1, 2, UnbalancedKind::UnterminatedQuote('\''))
+        );
+        assert_eq!(
+            validate_balanced("a«", &[('«', '»')], &[], '\\'),
+            err(1, 3, UnbalancedKind::Unclosed('«'))
+        );
+    }
+}
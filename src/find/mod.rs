@@ -1,4 +1,54 @@
 //! This module contains functions with the primary purpose of searching [str]s.
 
+mod affix;
+pub use affix::*;
+
+mod charset;
+pub use charset::*;
+
+mod distance;
+pub use distance::*;
+
+mod finder;
+pub use finder::*;
+
+mod frequency;
+pub use frequency::*;
+
+mod fuzzy;
+pub use fuzzy::*;
+
+mod ignore_case;
+pub use ignore_case::*;
+
+mod matching;
+pub use matching::*;
+
+mod non_escaped;
+pub use non_escaped::*;
+
+mod multi_finder;
+pub use multi_finder::*;
+
+mod nth;
+pub use nth::*;
+
+mod overlapping;
+pub use overlapping::*;
+
+mod palindrome;
+pub use palindrome::*;
+
+mod runs;
+pub use runs::*;
+
 mod substr;
 pub use substr::*;
+
+mod words;
+pub use words::*;
+
+/// Returns true if both chars are equal ignoring case.
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
@@ -0,0 +1,328 @@
+use std::{collections::VecDeque, iter::FusedIterator, ops::Range};
+
+/// A state of the automaton of a [`MultiFinder`].
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// The transitions to other states by byte, sorted by byte.
+    next: Vec<(u8, usize)>,
+
+    /// The state of the longest proper suffix of this state that is also a prefix of a needle.
+    fail: usize,
+
+    /// The length of the prefix this state represents.
+    depth: usize,
+
+    /// The index of the longest needle that ends in this state, if any.
+    output: Option<usize>,
+}
+
+/// A precompiled searcher for multiple needles at once using the Aho-Corasick algorithm. The
+/// needles are compiled into a single automaton, which allows finding matches of any needle in a
+/// single pass over a haystack.
+///
+/// Matches are non-overlapping and leftmost-longest, eg.: of all matches the one starting first
+/// is yielded and if multiple start at the same index the longest one is, if equal needles are
+/// given the first one is reported. Empty needles never match.
+///
+/// # Complexity
+/// Creating a finder requires `O(m)` time where `m` is the total length of the needles,
+/// searching requires `O(n)` time where `n` is the length of the haystack, bytes may be visited
+/// again after a match has been found, but never more often than the longest needle is long.
+///
+/// # Allocation
+/// The states and transitions of the automaton, one state per unique needle prefix, are
+/// collected into [Vec]s.
+///
+/// # Examples
+/// ```
+/// use strtools::find::MultiFinder;
+///
+/// let finder = MultiFinder::new(&["fn", "let", "letter"]);
+/// let matches: Vec<_> = finder.find_iter("let letters be fn").collect();
+///
+/// assert_eq!(matches, [(1, 0..3), (2, 4..10), (0, 15..17)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiFinder {
+    states: Vec<State>,
+    lens: Vec<usize>,
+}
+
+impl MultiFinder {
+    /// Creates a new [`MultiFinder`] for the given `needles`, the pattern indices of matches refer
+    /// to the index of the needle in `needles`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::MultiFinder;
+    ///
+    /// let finder = MultiFinder::new(&["a", "b"]);
+    /// assert_eq!(finder.len(), 2);
+    /// ```
+    pub fn new<S: AsRef<str>>(needles: &[S]) -> Self {
+        let mut states = vec![State::default()];
+        let lens = needles.iter().map(|needle| needle.as_ref().len()).collect();
+
+        for (pat, needle) in needles.iter().enumerate() {
+            let needle = needle.as_ref();
+            if needle.is_empty() {
+                continue;
+            }
+
+            let mut state = 0;
+            for &byte in needle.as_bytes() {
+                state = match states[state].next.binary_search_by_key(&byte, |&(b, _)| b) {
+                    Ok(idx) => states[state].next[idx].1,
+                    Err(idx) => {
+                        let new = states.len();
+                        let depth = states[state].depth + 1;
+                        states[state].next.insert(idx, (byte, new));
+                        states.push(State {
+                            depth,
+                            ..State::default()
+                        });
+                        new
+                    }
+                };
+            }
+
+            states[state].output.get_or_insert(pat);
+        }
+
+        // compute the fail links in breadth first order, so the fail states of shallower states
+        // are known before they are needed
+        let mut queue: VecDeque<usize> = states[0].next.iter().map(|&(_, s)| s).collect();
+        while let Some(state) = queue.pop_front() {
+            for idx in 0..states[state].next.len() {
+                let (byte, next) = states[state].next[idx];
+                let fail = Self::transition(&states, states[state].fail, byte);
+
+                states[next].fail = fail;
+                if states[next].output.is_none() {
+                    states[next].output = states[fail].output;
+                }
+                queue.push_back(next);
+            }
+        }
+
+        Self { states, lens }
+    }
+
+    /// Returns the state reached from `state` by the given `byte`, following fail links.
+    fn transition(states: &[State], mut state: usize, byte: u8) -> usize {
+        loop {
+            let curr = &states[state];
+            if let Ok(idx) = curr.next.binary_search_by_key(&byte, |&(b, _)| b) {
+                return curr.next[idx].1;
+            }
+
+            if state == 0 {
+                return 0;
+            }
+
+            state = curr.fail;
+        }
+    }
+
+    /// Returns the number of needles of this finder, including empty needles.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::MultiFinder;
+    ///
+    /// assert_eq!(MultiFinder::new(&["a", ""]).len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lens.len()
+    }
+
+    /// Returns true if this finder has no needles.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::MultiFinder;
+    ///
+    /// assert!(MultiFinder::new::<&str>(&[]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lens.is_empty()
+    }
+
+    /// Returns the first match in `haystack` starting at or after `start`.
+    fn find_at(&self, haystack: &str, start: usize) -> Option<(usize, Range<usize>)> {
+        let mut state = 0;
+        let mut found: Option<(usize, Range<usize>)> = None;
+
+        for (idx, &byte) in haystack.as_bytes().iter().enumerate().skip(start) {
+            state = Self::transition(&self.states, state, byte);
+            let end = idx + 1;
+
+            if let Some(pat) = self.states[state].output {
+                let range = end - self.lens[pat]..end;
+                if found.as_ref().is_none_or(|(_, f)| range.start <= f.start) {
+                    found = Some((pat, range));
+                }
+            }
+
+            // no later match can start before the found one
+            if let Some((_, range)) = &found
+                && end - self.states[state].depth > range.start
+            {
+                break;
+            }
+        }
+
+        found
+    }
+
+    /// Returns the pattern index and byte range of the first match in `haystack`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::MultiFinder;
+    ///
+    /// let finder = MultiFinder::new(&["bc", "abcd"]);
+    /// assert_eq!(finder.find("xabcd"), Some((1, 1..5)));
+    /// assert_eq!(finder.find("xabc"), Some((0, 2..4)));
+    /// ```
+    pub fn find(&self, haystack: &str) -> Option<(usize, Range<usize>)> {
+        self.find_at(haystack, 0)
+    }
+
+    /// Returns an [Iterator] over the pattern indices and byte ranges of all non-overlapping
+    /// matches in `haystack`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::find::MultiFinder;
+    ///
+    /// let finder = MultiFinder::new(&["ab", "ba"]);
+    /// assert_eq!(finder.find_iter("abab").collect::<Vec<_>>(), [(0, 0..2), (0, 2..4)]);
+    /// ```
+    pub fn find_iter<'f, 'h>(&'f self, haystack: &'h str) -> MultiFindIter<'f, 'h> {
+        MultiFindIter {
+            finder: self,
+            haystack,
+            done: 0,
+        }
+    }
+}
+
+/// An [Iterator] that yields the matches of a [`MultiFinder`] in a [str]. This struct is
+/// created by the [`MultiFinder::find_iter`] method, see it's documentation for more info.
+#[derive(Debug, Clone)]
+pub struct MultiFindIter<'finder, 'haystack> {
+    finder: &'finder MultiFinder,
+    haystack: &'haystack str,
+    done: usize,
+}
+
+impl<'f, 'h> Iterator for MultiFindIter<'f, 'h> {
+    type Item = (usize, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = self.finder.find_at(self.haystack, self.done);
+        self.done = found
+            .as_ref()
+            .map_or(self.haystack.len(), |(_, range)| range.end);
+        found
+    }
+}
+
+impl<'f, 'h> FusedIterator for MultiFindIter<'f, 'h> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(needles: &[&str], haystack: &str) -> Vec<(usize, Range<usize>)> {
+        MultiFinder::new(needles).find_iter(haystack).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(collect(&[], "abc"), []);
+        assert_eq!(collect(&["a"], ""), []);
+        assert_eq!(collect(&[""], "abc"), []);
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(collect(&["ab"], "abxab"), [(0, 0..2), (0, 3..5)]);
+        assert_eq!(collect(&["aa"], "aaaaa"), [(0, 0..2), (0, 2..4)]);
+        assert_eq!(collect(&["ö"], "aöbö"), [(0, 1..3), (0, 4..6)]);
+    }
+
+    #[test]
+    fn leftmost() {
+        assert_eq!(collect(&["bcd", "abc"], "abcd"), [(1, 0..3)]);
+        assert_eq!(collect(&["bc", "abcd"], "abcd"), [(1, 0..4)]);
+        assert_eq!(collect(&["bc", "abcd"], "abce"), [(0, 1..3)]);
+    }
+
+    #[test]
+    fn longest() {
+        assert_eq!(
+            collect(&["a", "ab", "abc"], "abcab"),
+            [(2, 0..3), (1, 3..5)]
+        );
+        assert_eq!(collect(&["abc", "a"], "abx"), [(1, 0..1)]);
+    }
+
+    #[test]
+    fn duplicates() {
+        assert_eq!(collect(&["a", "a"], "a"), [(0, 0..1)]);
+    }
+
+    #[test]
+    fn suffixes() {
+        assert_eq!(
+            collect(&["he", "she", "his", "hers"], "ushers"),
+            [(1, 1..4)]
+        );
+        assert_eq!(collect(&["he", "his", "hers"], "ushers"), [(2, 2..6)]);
+    }
+
+    #[test]
+    fn brute_force() {
+        // leftmost-longest by checking every start and needle
+        fn naive(needles: &[&str], haystack: &str) -> Vec<(usize, Range<usize>)> {
+            let mut found = vec![];
+            let mut start = 0;
+            while start < haystack.len() {
+                let best = needles
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, n)| !n.is_empty() && haystack[start..].starts_with(**n))
+                    .fold(None, |best: Option<(usize, usize)>, (pat, n)| match best {
+                        Some((_, len)) if len >= n.len() => best,
+                        _ => Some((pat, n.len())),
+                    });
+
+                match best {
+                    Some((pat, len)) => {
+                        found.push((pat, start..start + len));
+                        start += len;
+                    }
+                    None => start += 1,
+                }
+            }
+            found
+        }
+
+        let needles = ["a", "ab", "bab", "bb", "abba", "baa", "aaa"];
+        for len in 0..=8 {
+            for bits in 0..1u32 << len {
+                let haystack: String = (0..len)
+                    .map(|idx| if bits & (1 << idx) == 0 { 'a' } else { 'b' })
+                    .collect();
+
+                assert_eq!(
+                    collect(&needles, &haystack),
+                    naive(&needles, &haystack),
+                    "{haystack}"
+                );
+            }
+        }
+    }
+}
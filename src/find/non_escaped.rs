@@ -0,0 +1,176 @@
+use crate::{split::NonEscapedError, util::CharMembership};
+use std::{iter::FusedIterator, str::CharIndices};
+
+/// Returns an [Iterator] over the byte offsets of all delimiters in `input` that are not preceded
+/// by a given escape. This is the scan [`split::non_escaped_with`][0] is built on, it is useful if
+/// the positions of the delimiters are needed rather than the parts between them.
+///
+/// # Errors
+/// Returns an error if:
+/// - `delims` contains `esc`
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::find;
+///
+/// let indices: Vec<_> = find::non_escaped_indices(r"a:b\:c\\:d", '\\', ':')?.collect();
+///
+/// // the escaped delimiter at 4 is skipped, the one at 8 is preceded by an escaped escape
+/// assert_eq!(indices, [1, 8]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [0]: crate::split::non_escaped_with
+pub fn non_escaped_indices<D: CharMembership>(
+    input: &str,
+    esc: char,
+    delims: D,
+) -> Result<NonEscapedIndices<'_, D>, NonEscapedError> {
+    if delims.contains_char(esc) {
+        Err(NonEscapedError::EscapeContainsDelimiter(esc))
+    } else {
+        Ok(NonEscapedIndices {
+            iter: input.char_indices(),
+            esc,
+            delims,
+        })
+    }
+}
+
+/// An [Iterator] that yields the byte offsets of non-escaped delimiters in a [str]. This struct is
+/// created by the [`non_escaped_indices`] method, see it's documentation for more info.
+#[derive(Debug)]
+pub struct NonEscapedIndices<'input, D> {
+    iter: CharIndices<'input>,
+    esc: char,
+    delims: D,
+}
+
+impl<'s, D: CharMembership> Iterator for NonEscapedIndices<'s, D> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut is_escaped = false;
+
+        for (idx, ch) in self.iter.by_ref() {
+            if ch == self.esc {
+                is_escaped = !is_escaped;
+                continue;
+            }
+
+            if !is_escaped && self.delims.contains_char(ch) {
+                return Some(idx);
+            }
+
+            is_escaped = false;
+        }
+
+        None
+    }
+}
+
+impl<'s, D: CharMembership> FusedIterator for NonEscapedIndices<'s, D> {}
+
+/// Returns the byte index of the first occurrence of `needle` in `haystack` whose first char is
+/// not preceded by the escape `esc`. This extends the semantics of [`non_escaped_indices`] to
+/// multi char needles, an empty needle matches at `0`.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input strings.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// assert_eq!(find::find_non_escaped_str(r"a\::b::c", "::", '\\'), Some(5));
+/// assert_eq!(find::find_non_escaped_str(r"a\\::b", "::", '\\'), Some(3));
+/// assert_eq!(find::find_non_escaped_str(r"a\::b", "::", '\\'), None);
+/// ```
+pub fn find_non_escaped_str(haystack: &str, needle: &str, esc: char) -> Option<usize> {
+    let mut is_escaped = false;
+
+    for (idx, ch) in haystack.char_indices() {
+        if !is_escaped && haystack[idx..].starts_with(needle) {
+            return Some(idx);
+        }
+
+        is_escaped = !is_escaped && ch == esc;
+    }
+
+    needle.is_empty().then_some(haystack.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::Sorted;
+
+    macro_rules! test_impl {
+        ($split:expr; $from:literal => [$($to:literal),*]) => {
+            assert_eq!(
+                non_escaped_indices($from, '\\', Sorted::new($split).unwrap())
+                    .expect("delim and escape are not the same")
+                    .collect::<Vec<usize>>(),
+                vec![$($to),*]
+            )
+        };
+    }
+
+    #[test]
+    fn empty() {
+        test_impl!([':']; "" => []);
+    }
+
+    #[test]
+    fn delim_is_escape() {
+        assert_eq!(
+            non_escaped_indices("", '\\', Sorted::new(['\\']).unwrap()).unwrap_err(),
+            NonEscapedError::EscapeContainsDelimiter('\\')
+        );
+    }
+
+    #[test]
+    fn no_escape() {
+        test_impl!([':']; "aa:bb:cc" => [2, 5]);
+        test_impl!([':', ';']; "aa:bb;cc" => [2, 5]);
+        test_impl!([':']; ":ö:" => [0, 3]);
+    }
+
+    #[test]
+    fn escapes() {
+        test_impl!([':']; r"aa\:bb" => []);
+        test_impl!([':']; r"aa\\:bb" => [4]);
+        test_impl!([':']; r"aa\\\:bb" => []);
+        test_impl!([':']; r"aa\.:bb" => [4]);
+        test_impl!([':']; r"aa:bb\" => [2]);
+    }
+
+    #[test]
+    fn non_escaped_str() {
+        assert_eq!(find_non_escaped_str("", "", '\\'), Some(0));
+        assert_eq!(find_non_escaped_str("ab", "", '\\'), Some(0));
+        assert_eq!(find_non_escaped_str("", "ab", '\\'), None);
+        assert_eq!(find_non_escaped_str("xxab", "ab", '\\'), Some(2));
+        assert_eq!(find_non_escaped_str(r"\abab", "ab", '\\'), Some(3));
+        assert_eq!(find_non_escaped_str(r"\\ab", "ab", '\\'), Some(2));
+        assert_eq!(find_non_escaped_str(r"\\\ab", "ab", '\\'), None);
+        assert_eq!(find_non_escaped_str(r"ö\öäöä", "öä", '\\'), Some(7));
+    }
+
+    #[test]
+    fn non_escaped_str_escape_in_needle() {
+        assert_eq!(find_non_escaped_str(r"a\b", r"\b", '\\'), Some(1));
+        assert_eq!(find_non_escaped_str(r"a\\b", r"\b", '\\'), None);
+    }
+}
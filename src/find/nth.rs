@@ -0,0 +1,88 @@
+use std::str::pattern::{Pattern, ReverseSearcher};
+
+/// Returns the byte index of the `n`th (zero-based) match of `pat` in `input`, or [`None`] if
+/// there are not more than `n` matches. The pattern can be anything implementing [`Pattern`], such
+/// as a [char], a [str] or a set of chars like `&[char]`. Matches do not overlap, like
+/// [`str::match_indices`].
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string, not counting
+/// the cost of the pattern's searcher.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let input = "a,b;c,d";
+/// assert_eq!(find::nth(input, ',', 1), Some(5));
+/// assert_eq!(find::nth(input, [',', ';'].as_slice(), 1), Some(3));
+/// assert_eq!(find::nth(input, "x", 0), None);
+/// ```
+pub fn nth<P: Pattern>(input: &str, pat: P, n: usize) -> Option<usize> {
+    input.match_indices(pat).nth(n).map(|(idx, _)| idx)
+}
+
+/// Returns the byte index of the `n`th (zero-based) match of `pat` in `input`, counting from the
+/// back, or [`None`] if there are not more than `n` matches. See [`nth`] for more info.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string, not counting
+/// the cost of the pattern's searcher.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let input = "a,b;c,d";
+/// assert_eq!(find::rnth(input, ',', 0), Some(5));
+/// assert_eq!(find::rnth(input, [',', ';'].as_slice(), 1), Some(3));
+/// assert_eq!(find::rnth(input, ",", 2), None);
+/// ```
+pub fn rnth<P>(input: &str, pat: P, n: usize) -> Option<usize>
+where
+    P: Pattern,
+    for<'a> P::Searcher<'a>: ReverseSearcher<'a>,
+{
+    input.rmatch_indices(pat).nth(n).map(|(idx, _)| idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(nth("", 'a', 0), None);
+        assert_eq!(rnth("", 'a', 0), None);
+    }
+
+    #[test]
+    fn char() {
+        assert_eq!(nth("aöaöa", 'a', 0), Some(0));
+        assert_eq!(nth("aöaöa", 'a', 2), Some(6));
+        assert_eq!(nth("aöaöa", 'a', 3), None);
+        assert_eq!(rnth("aöaöa", 'a', 0), Some(6));
+        assert_eq!(rnth("aöaöa", 'a', 2), Some(0));
+        assert_eq!(rnth("aöaöa", 'a', 3), None);
+    }
+
+    #[test]
+    fn charset() {
+        let set = ['.', ':'];
+        assert_eq!(nth("a.b:c", set.as_slice(), 1), Some(3));
+        assert_eq!(rnth("a.b:c", set.as_slice(), 1), Some(1));
+    }
+
+    #[test]
+    fn str() {
+        assert_eq!(nth("aaaa", "aa", 1), Some(2));
+        assert_eq!(nth("aaaa", "aa", 2), None);
+        assert_eq!(rnth("aaaa", "aa", 1), Some(0));
+    }
+}
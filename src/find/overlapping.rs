@@ -0,0 +1,94 @@
+use std::iter::FusedIterator;
+
+/// Returns an [Iterator] over the byte indices of all occurrences of `needle` in `haystack`,
+/// including overlapping ones. Unlike [`str::match_indices`], the search continues a char after
+/// the start of a match rather than after its end, so `"aaa"` contains `"aa"` twice. An empty
+/// needle matches at every char boundary.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time in the worst case where `n` and `m` are the lengths of
+/// the input strings.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let matches: Vec<_> = find::find_iter_overlapping("aaaa", "aa").collect();
+/// assert_eq!(matches, [0, 1, 2]);
+///
+/// let matches: Vec<_> = "aaaa".match_indices("aa").map(|(idx, _)| idx).collect();
+/// assert_eq!(matches, [0, 2]);
+/// ```
+pub fn find_iter_overlapping<'h, 'n>(haystack: &'h str, needle: &'n str) -> Overlapping<'h, 'n> {
+    Overlapping {
+        haystack,
+        needle,
+        done: Some(0),
+    }
+}
+
+/// An [Iterator] that yields the byte indices of possibly overlapping matches in a [str]. This
+/// struct is created by the [`find_iter_overlapping`] method, see it's documentation for more
+/// info.
+#[derive(Debug, Clone)]
+pub struct Overlapping<'haystack, 'needle> {
+    haystack: &'haystack str,
+    needle: &'needle str,
+    done: Option<usize>,
+}
+
+impl<'h, 'n> Iterator for Overlapping<'h, 'n> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.done?;
+        let found = self.haystack[start..]
+            .find(self.needle)
+            .map(|idx| start + idx);
+
+        // continue a char after the start of the match, not after the end
+        self.done = found.and_then(|idx| {
+            self.haystack[idx..]
+                .chars()
+                .next()
+                .map(|ch| idx + ch.len_utf8())
+        });
+
+        found
+    }
+}
+
+impl<'h, 'n> FusedIterator for Overlapping<'h, 'n> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(haystack: &str, needle: &str) -> Vec<usize> {
+        find_iter_overlapping(haystack, needle).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(collect("", ""), [0]);
+        assert_eq!(collect("aö", ""), [0, 1, 3]);
+        assert_eq!(collect("", "a"), []);
+    }
+
+    #[test]
+    fn non_overlapping() {
+        assert_eq!(collect("abxab", "ab"), [0, 3]);
+        assert_eq!(collect("abc", "x"), []);
+    }
+
+    #[test]
+    fn overlapping() {
+        assert_eq!(collect("aaa", "aa"), [0, 1]);
+        assert_eq!(collect("ababa", "aba"), [0, 2]);
+        assert_eq!(collect("ööö", "öö"), [0, 2]);
+        assert_eq!(collect("abcabcabc", "abcabc"), [0, 3]);
+    }
+}
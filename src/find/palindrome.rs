@@ -0,0 +1,267 @@
+use super::eq_ignore_case;
+use std::ops::Range;
+
+/// Options for [`longest_palindrome_with`], by default no chars are ignored and case is
+/// significant.
+///
+/// # Examples
+/// ```
+/// use strtools::find::PalindromeOptions;
+///
+/// // ignore everything but alphanumeric chars and their case
+/// let options = PalindromeOptions {
+///     ignore_case: true,
+///     ignore_whitespace: true,
+///     ignore_punctuation: true,
+/// };
+/// assert_eq!(options, PalindromeOptions::LOOSE);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PalindromeOptions {
+    /// Whether chars are compared ignoring case.
+    pub ignore_case: bool,
+
+    /// Whether whitespace chars are skipped.
+    pub ignore_whitespace: bool,
+
+    /// Whether ASCII punctuation chars are skipped.
+    pub ignore_punctuation: bool,
+}
+
+impl PalindromeOptions {
+    /// The options used by [`longest_palindrome`], nothing is ignored.
+    pub const STRICT: Self = Self {
+        ignore_case: false,
+        ignore_whitespace: false,
+        ignore_punctuation: false,
+    };
+
+    /// The options for natural language palindromes, case, whitespace and punctuation are ignored.
+    pub const LOOSE: Self = Self {
+        ignore_case: true,
+        ignore_whitespace: true,
+        ignore_punctuation: true,
+    };
+
+    /// Returns true if the given char is skipped under these options.
+    fn is_ignored(&self, ch: char) -> bool {
+        (self.ignore_whitespace && ch.is_whitespace())
+            || (self.ignore_punctuation && ch.is_ascii_punctuation())
+    }
+}
+
+/// Returns the byte range of the longest palindromic substring of `input`, comparing chars. If
+/// there are multiple longest palindromes, the first is returned. See [`longest_palindrome_with`]
+/// to ignore case, whitespace or punctuation.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// The chars of the input and two radii per char are collected into [Vec]s.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let input = "a racecar";
+/// assert_eq!(&input[find::longest_palindrome(input)], "racecar");
+///
+/// let input = "abccbx";
+/// assert_eq!(&input[find::longest_palindrome(input)], "bccb");
+/// ```
+pub fn longest_palindrome(input: &str) -> Range<usize> {
+    longest_palindrome_with(input, PalindromeOptions::STRICT)
+}
+
+/// Returns the byte range of the longest palindromic substring of `input` under the given
+/// `options`. Ignored chars are skipped when comparing, the returned range starts and ends on a
+/// compared char, so surrounding ignored chars are not included. If there are no compared chars,
+/// an empty range at `0` is returned.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string, using
+/// Manacher's algorithm.
+///
+/// # Allocation
+/// The compared chars of the input and two radii per char are collected into [Vec]s.
+///
+/// # Examples
+/// ```
+/// use strtools::find::{self, PalindromeOptions};
+///
+/// let input = "he said: A man, a plan, a canal: Panama!";
+/// let range = find::longest_palindrome_with(input, PalindromeOptions::LOOSE);
+/// assert_eq!(&input[range], "A man, a plan, a canal: Panama");
+/// ```
+pub fn longest_palindrome_with(input: &str, options: PalindromeOptions) -> Range<usize> {
+    let chars: Vec<(usize, char)> = input
+        .char_indices()
+        .filter(|&(_, ch)| !options.is_ignored(ch))
+        .collect();
+
+    if chars.is_empty() {
+        return 0..0;
+    }
+
+    let eq = |a: usize, b: usize| {
+        let (a, b) = (chars[a].1, chars[b].1);
+        if options.ignore_case {
+            eq_ignore_case(a, b)
+        } else {
+            a == b
+        }
+    };
+
+    let len = chars.len();
+
+    // the radii of the odd palindromes centered at each char, including the center
+    let mut odd = vec![0; len];
+    let (mut left, mut right) = (0, 0);
+    for idx in 0..len {
+        let mut radius = if idx < right {
+            odd[left + right - 1 - idx].min(right - idx)
+        } else {
+            1
+        };
+
+        while radius <= idx && idx + radius < len && eq(idx - radius, idx + radius) {
+            radius += 1;
+        }
+
+        odd[idx] = radius;
+        if idx + radius > right {
+            left = idx + 1 - radius;
+            right = idx + radius;
+        }
+    }
+
+    // the radii of the even palindromes centered before each char
+    let mut even = vec![0; len];
+    let (mut left, mut right) = (0, 0);
+    for idx in 0..len {
+        let mut radius = if idx < right {
+            even[left + right - idx].min(right - idx)
+        } else {
+            0
+        };
+
+        while radius < idx && idx + radius < len && eq(idx - radius - 1, idx + radius) {
+            radius += 1;
+        }
+
+        even[idx] = radius;
+        if idx + radius > right {
+            left = idx - radius;
+            right = idx + radius;
+        }
+    }
+
+    // the longest palindrome as a range of char indices, inclusive
+    let (mut start, mut end) = (0, 0);
+    for idx in 0..len {
+        let (odd_start, odd_end) = (idx + 1 - odd[idx], idx + odd[idx] - 1);
+        if odd_end - odd_start > end - start {
+            (start, end) = (odd_start, odd_end);
+        }
+
+        if even[idx] > 0 {
+            let (even_start, even_end) = (idx - even[idx], idx + even[idx] - 1);
+            if even_end - even_start > end - start {
+                (start, end) = (even_start, even_end);
+            }
+        }
+    }
+
+    let (last_idx, last) = chars[end];
+    chars[start].0..last_idx + last.len_utf8()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strict(input: &str) -> &str {
+        &input[longest_palindrome(input)]
+    }
+
+    fn loose(input: &str) -> &str {
+        &input[longest_palindrome_with(input, PalindromeOptions::LOOSE)]
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(longest_palindrome(""), 0..0);
+        assert_eq!(
+            longest_palindrome_with(" ,. ", PalindromeOptions::LOOSE),
+            0..0
+        );
+    }
+
+    #[test]
+    fn odd() {
+        assert_eq!(strict("a"), "a");
+        assert_eq!(strict("aba"), "aba");
+        assert_eq!(strict("xabay"), "aba");
+        assert_eq!(strict("abacaba"), "abacaba");
+        assert_eq!(strict("xäöäy"), "äöä");
+    }
+
+    #[test]
+    fn even() {
+        assert_eq!(strict("aa"), "aa");
+        assert_eq!(strict("xabbay"), "abba");
+        assert_eq!(strict("xäääy"), "äää");
+        assert_eq!(strict("xääy"), "ää");
+    }
+
+    #[test]
+    fn first_longest() {
+        assert_eq!(longest_palindrome("abc"), 0..1);
+        assert_eq!(longest_palindrome("abaxcdc"), 0..3);
+    }
+
+    #[test]
+    fn case() {
+        assert_eq!(strict("Abba"), "bb");
+        assert_eq!(loose("Abba"), "Abba");
+        assert_eq!(loose("xÄbäy"), "Äbä");
+    }
+
+    #[test]
+    fn ignored() {
+        assert_eq!(loose("x, abba ,y"), "abba");
+        assert_eq!(loose("ab, ,ba"), "ab, ,ba");
+        assert_eq!(
+            loose("Was it a car or a cat I saw?"),
+            "Was it a car or a cat I saw"
+        );
+    }
+
+    #[test]
+    fn brute_force() {
+        fn is_palindrome(s: &str) -> bool {
+            s.chars().eq(s.chars().rev())
+        }
+
+        // every string of up to 10 chars over a two char alphabet
+        for len in 1..=10 {
+            for bits in 0..1u32 << len {
+                let input: String = (0..len)
+                    .map(|idx| if bits & (1 << idx) == 0 { 'a' } else { 'b' })
+                    .collect();
+
+                let expected = (1..=len)
+                    .rev()
+                    .find_map(|l| {
+                        (0..=len - l)
+                            .find(|&s| is_palindrome(&input[s..s + l]))
+                            .map(|s| s..s + l)
+                    })
+                    .unwrap();
+
+                assert_eq!(longest_palindrome(&input), expected, "{input}");
+            }
+        }
+    }
+}
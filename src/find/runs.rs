@@ -0,0 +1,205 @@
+use std::{iter::FusedIterator, ops::Range};
+
+/// Returns an [Iterator] over the maximal runs of identical chars in `input`, yielding the char,
+/// the number of times it is repeated and the byte range of the run.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let runs: Vec<_> = find::runs("aaöök").collect();
+/// assert_eq!(runs, [('a', 2, 0..2), ('ö', 2, 2..6), ('k', 1, 6..7)]);
+///
+/// // reject passwords with long runs of the same char
+/// assert!(find::runs("zzzzzz12").any(|(_, count, _)| count >= 5));
+/// ```
+pub fn runs(input: &str) -> Runs<'_> {
+    Runs { input, done: 0 }
+}
+
+/// An [Iterator] that yields the runs of identical chars in a [str]. This struct is created by
+/// the [`runs`] method, see it's documentation for more info.
+#[derive(Debug, Clone)]
+pub struct Runs<'input> {
+    input: &'input str,
+    done: usize,
+}
+
+impl<'s> Iterator for Runs<'s> {
+    type Item = (char, usize, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.done;
+        let mut chars = self.input[start..].chars();
+        let first = chars.next()?;
+        let count = 1 + chars.take_while(|&ch| ch == first).count();
+
+        self.done = start + count * first.len_utf8();
+        Some((first, count, start..self.done))
+    }
+}
+
+impl<'s> FusedIterator for Runs<'s> {}
+
+/// Returns the byte range of the longest run of chars in `input` that all satisfy `pred`, if there
+/// are multiple runs of the same length, the first one is returned. If no char satisfies `pred`,
+/// an empty range at `0` is returned.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let input = "order 12, id 4711";
+/// assert_eq!(&input[find::longest_run(input, |ch| ch.is_ascii_digit())], "4711");
+/// ```
+pub fn longest_run(input: &str, pred: impl FnMut(char) -> bool) -> Range<usize> {
+    // compare by chars rather than bytes
+    runs_matching(input, pred, 1)
+        .map(|range| (input[range.clone()].chars().count(), range))
+        .fold(
+            (0, 0..0),
+            |longest, run| if run.0 > longest.0 { run } else { longest },
+        )
+        .1
+}
+
+/// Returns an [Iterator] over the byte ranges of all maximal runs of chars in `input` that all
+/// satisfy `pred` and are at least `min_len` chars long.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let input = "a   b c    d";
+/// let gaps: Vec<_> = find::runs_matching(input, char::is_whitespace, 3).collect();
+/// assert_eq!(gaps, [1..4, 7..11]);
+/// ```
+pub fn runs_matching<P: FnMut(char) -> bool>(
+    input: &str,
+    pred: P,
+    min_len: usize,
+) -> RunsMatching<'_, P> {
+    RunsMatching {
+        input,
+        done: 0,
+        pred,
+        min_len,
+    }
+}
+
+/// An [Iterator] that yields the runs of chars matching a predicate in a [str]. This struct is
+/// created by the [`runs_matching`] method, see it's documentation for more info.
+#[derive(Debug, Clone)]
+pub struct RunsMatching<'input, P> {
+    input: &'input str,
+    done: usize,
+    pred: P,
+    min_len: usize,
+}
+
+impl<'s, P: FnMut(char) -> bool> Iterator for RunsMatching<'s, P> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.done < self.input.len() {
+            let rest = &self.input[self.done..];
+            let Some(start) = rest.find(&mut self.pred) else {
+                self.done = self.input.len();
+                break;
+            };
+
+            let mut count = 0;
+            let mut end = rest.len();
+            for (idx, ch) in rest[start..].char_indices() {
+                if !(self.pred)(ch) {
+                    end = start + idx;
+                    break;
+                }
+                count += 1;
+            }
+
+            let range = self.done + start..self.done + end;
+            self.done = range.end;
+
+            if count >= self.min_len {
+                return Some(range);
+            }
+        }
+
+        None
+    }
+}
+
+impl<'s, P: FnMut(char) -> bool> FusedIterator for RunsMatching<'s, P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(runs("").next(), None);
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(runs("a").collect::<Vec<_>>(), [('a', 1, 0..1)]);
+        assert_eq!(runs("aaa").collect::<Vec<_>>(), [('a', 3, 0..3)]);
+        assert_eq!(runs("ööö").collect::<Vec<_>>(), [('ö', 3, 0..6)]);
+    }
+
+    #[test]
+    fn multiple() {
+        assert_eq!(
+            runs("abbccc").collect::<Vec<_>>(),
+            [('a', 1, 0..1), ('b', 2, 1..3), ('c', 3, 3..6)]
+        );
+        assert_eq!(
+            runs("aba").collect::<Vec<_>>(),
+            [('a', 1, 0..1), ('b', 1, 1..2), ('a', 1, 2..3)]
+        );
+    }
+
+    #[test]
+    fn longest() {
+        let digit = |ch: char| ch.is_ascii_digit();
+        assert_eq!(longest_run("", digit), 0..0);
+        assert_eq!(longest_run("abc", digit), 0..0);
+        assert_eq!(longest_run("1a22b3", digit), 2..4);
+        assert_eq!(longest_run("11a22", digit), 0..2);
+        assert_eq!(longest_run("123", digit), 0..3);
+        // 'ö' is 2 bytes, but only a single char
+        assert_eq!(longest_run("öö-aaa", char::is_alphabetic), 5..8);
+    }
+
+    #[test]
+    fn matching() {
+        let collect =
+            |input, min_len| runs_matching(input, |ch| ch == 'a', min_len).collect::<Vec<_>>();
+
+        assert_eq!(collect("", 0), []);
+        assert_eq!(collect("baba", 0), [1..2, 3..4]);
+        assert_eq!(collect("aabaaab", 1), [0..2, 3..6]);
+        assert_eq!(collect("aaabaaab", 3), [0..3, 4..7]);
+        assert_eq!(collect("aabaaab", 3).len(), 1);
+        assert_eq!(collect("aabaaab", 4), []);
+    }
+}
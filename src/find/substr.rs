@@ -1,5 +1,5 @@
 use indexmap::{map::Entry, IndexMap};
-use std::{num::NonZeroUsize, ops::Range};
+use std::{hash::Hash, iter::FusedIterator, num::NonZeroUsize, ops::Range, str::CharIndices};
 
 /// Finds the longest range in `input` such that each char in this range is unique, if there are
 /// multiple unique ranges of the same length, then first one is returned.
@@ -40,6 +40,88 @@ use std::{num::NonZeroUsize, ops::Range};
 /// assert_eq!(&input[range], "defgh_ijklmnopqrstuvwxyz");
 /// ```
 pub fn longest_unique_substr(input: &str, max: Option<NonZeroUsize>) -> Range<usize> {
+    if input.is_ascii() {
+        return longest_unique_ascii(input.as_bytes(), max);
+    }
+
+    longest_unique_by(
+        input
+            .char_indices()
+            .map(|(idx, ch)| (idx, ch, ch.len_utf8())),
+        max,
+    )
+}
+
+/// Behaves like [`longest_unique_substr`], but chars are considered equal if their keys are
+/// equal, this allows comparing chars ignoring case or treating whole classes of chars as equal.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time, ignoring memmoves when draining the indexmap and the
+/// cost of `key`.
+///
+/// # Allocation
+/// See [`longest_unique_substr`], the map holds keys instead of chars.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let input = "abcAdef";
+/// let range = find::longest_unique_substr_by_key(input, None, |ch| ch.to_ascii_lowercase());
+/// assert_eq!(&input[range], "bcAdef");
+///
+/// // treat all digits as equal
+/// let input = "a1b2c";
+/// let digits = |ch: char| if ch.is_ascii_digit() { '0' } else { ch };
+/// let range = find::longest_unique_substr_by_key(input, None, digits);
+/// assert_eq!(&input[range], "a1b");
+/// ```
+pub fn longest_unique_substr_by_key<K: Hash + Eq>(
+    input: &str,
+    max: Option<NonZeroUsize>,
+    key: impl Fn(char) -> K,
+) -> Range<usize> {
+    longest_unique_by(
+        input
+            .char_indices()
+            .map(|(idx, ch)| (idx, key(ch), ch.len_utf8())),
+        max,
+    )
+}
+
+/// Behaves like [`longest_unique_substr`], but the units of uniqueness are extended grapheme
+/// clusters rather than chars, so a base char and its combining marks count as a single unit.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time, ignoring memmoves when draining the indexmap.
+///
+/// # Allocation
+/// See [`longest_unique_substr`], the map holds graphemes instead of chars.
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// // "e\u{301}" and "e" are distinct graphemes, but share the char 'e'
+/// let input = "e\u{301}e";
+/// assert_eq!(&input[find::longest_unique_substr(input, None)], "e\u{301}");
+/// assert_eq!(&input[find::longest_unique_graphemes(input, None)], "e\u{301}e");
+/// ```
+#[cfg(feature = "segmentation")]
+pub fn longest_unique_graphemes(input: &str, max: Option<NonZeroUsize>) -> Range<usize> {
+    longest_unique_by(
+        unicode_segmentation::UnicodeSegmentation::grapheme_indices(input, true)
+            .map(|(idx, grapheme)| (idx, grapheme, grapheme.len())),
+        max,
+    )
+}
+
+/// The implementation of [`longest_unique_substr`], generic over the units of uniqueness, `units`
+/// yields the byte index, the key and the byte length of each unit.
+fn longest_unique_by<K: Hash + Eq>(
+    units: impl Iterator<Item = (usize, K, usize)>,
+    max: Option<NonZeroUsize>,
+) -> Range<usize> {
     let mut seen = IndexMap::new();
     let mut current = 0..0;
     let mut longest = 0..0;
@@ -49,40 +131,80 @@ pub fn longest_unique_substr(input: &str, max: Option<NonZeroUsize>) -> Range<us
     //  ^------^                     // longest range until duplicate 'c'
     //     ^----^                    // the overlap that is retained after encountering 'c'
     //     ^----------------------^  // desired longest range
-    for (idx, char) in input.char_indices() {
+    for (idx, unit, len) in units {
         // yield current if the next would exceed the max
-        if let Some(max) = max && (current.start..idx + char.len_utf8()).len() > max.get() {
+        if let Some(max) = max && (current.start..idx + len).len() > max.get() {
             return current;
         }
 
-        match seen.entry(char) {
-            Entry::Occupied(mut occupied) => {
+        match seen.entry(unit) {
+            Entry::Occupied(occupied) => {
                 if current.len() > longest.len() {
                     longest = current.clone();
                 }
 
-                let prev = occupied.get_mut();
+                // the dupe and all chars before it leave the range, the chars are kept in the
+                // order they occur, so these are exactly the entries up to the dupe
+                let index = occupied.index();
+                let (unit, prev_end) = occupied.shift_remove_entry();
+                seen.drain(..index);
 
-                // set current to start past prev idx
-                current.start = *prev + char.len_utf8();
+                // set current to start past the prev unit, units with equal keys may differ in
+                // length, so this must be the end of the prev unit rather than idx + len
+                current.start = prev_end;
 
-                // last occurrence of dupe is now here after draining the map
-                *prev = idx;
-
-                // the range to remove from the index map (the chars are added in the order they
-                // occur)
-                let range = ..occupied.index();
-                seen.drain(range);
+                // last occurrence of dupe is now here, it is the latest so it goes to the back
+                seen.insert(unit, idx + len);
             }
             Entry::Vacant(vacant) => {
-                vacant.insert(idx);
+                vacant.insert(idx + len);
             }
         }
 
         // exclusive range, dupe or not this will go to at least until here
-        current.end = idx + char.len_utf8();
+        current.end = idx + len;
+    }
+
+    select(current, longest, max)
+}
+
+/// The ASCII fast path of [`longest_unique_substr`], this behaves exactly like
+/// [`longest_unique_by`], but keeps track of the last occurrence of each byte in a table instead
+/// of an [`IndexMap`]. Bytes before the start of the current range are treated as unseen, which is
+/// equivalent to draining them from the map.
+fn longest_unique_ascii(input: &[u8], max: Option<NonZeroUsize>) -> Range<usize> {
+    let mut last_seen = [None; 128];
+    let mut current = 0..0;
+    let mut longest = 0..0;
+
+    for (idx, &byte) in input.iter().enumerate() {
+        if let Some(max) = max
+            && (current.start..idx + 1).len() > max.get()
+        {
+            return current;
+        }
+
+        let last = &mut last_seen[usize::from(byte & 0x7f)];
+        if let Some(prev) = *last
+            && prev >= current.start
+        {
+            if current.len() > longest.len() {
+                longest = current.clone();
+            }
+
+            current.start = prev + 1;
+        }
+
+        *last = Some(idx);
+        current.end = idx + 1;
     }
 
+    select(current, longest, max)
+}
+
+/// Selects the result of [`longest_unique_by`] from the `current` and `longest` range after all
+/// units have been visited.
+fn select(current: Range<usize>, longest: Range<usize>, max: Option<NonZeroUsize>) -> Range<usize> {
     // the longest can never exceed max as it is set after checking for exceeding
     if let Some(max) = max && longest.len() == max.get() {
         return longest;
@@ -96,6 +218,97 @@ pub fn longest_unique_substr(input: &str, max: Option<NonZeroUsize>) -> Range<us
     longest
 }
 
+/// Returns an [Iterator] over all maximal ranges in `input` such that each char in the range is
+/// unique and the range is at least `min_len` bytes long. A range is maximal if it cannot be
+/// extended by a char to the left or right without containing a duplicate char. The ranges are
+/// yielded in order of their start, they may overlap.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time, ignoring memmoves when draining the indexmap.
+///
+/// # Allocation
+/// An [`IndexMap`] is allocated to keep track of unique chars, see [`longest_unique_substr`].
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let input = "abcabd";
+/// let ranges: Vec<_> = find::unique_substrs(input, 0).map(|range| &input[range]).collect();
+/// assert_eq!(ranges, ["abc", "bca", "cabd"]);
+///
+/// // all ranges tied for the longest
+/// let input = "abcxcde";
+/// let ranges: Vec<_> = find::unique_substrs(input, 0).collect();
+/// let longest = ranges.iter().map(|range| range.len()).max().unwrap_or(0);
+/// let tied: Vec<_> = ranges.into_iter().filter(|range| range.len() == longest).collect();
+/// assert_eq!(tied, [0..4, 3..7]);
+/// ```
+pub fn unique_substrs(input: &str, min_len: usize) -> UniqueSubstrs<'_> {
+    UniqueSubstrs {
+        iter: input.char_indices(),
+        seen: IndexMap::new(),
+        current: Some(0..0),
+        min_len,
+    }
+}
+
+/// An [Iterator] that yields the maximal unique ranges of a [str]. This struct is created by the
+/// [`unique_substrs`] method, see it's documentation for more info.
+#[derive(Debug, Clone)]
+pub struct UniqueSubstrs<'input> {
+    iter: CharIndices<'input>,
+    seen: IndexMap<char, usize>,
+    current: Option<Range<usize>>,
+    min_len: usize,
+}
+
+impl<'s> Iterator for UniqueSubstrs<'s> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current.as_mut()?;
+
+        for (idx, char) in self.iter.by_ref() {
+            let len = char.len_utf8();
+            let mut maximal = None;
+
+            // see longest_unique_by for how the ranges are moved
+            match self.seen.entry(char) {
+                Entry::Occupied(occupied) => {
+                    // the current range cannot be extended by this dupe, so it is maximal
+                    maximal = Some(current.clone());
+
+                    let index = occupied.index();
+                    let (char, prev) = occupied.shift_remove_entry();
+                    self.seen.drain(..index);
+
+                    current.start = prev + len;
+                    self.seen.insert(char, idx);
+                }
+                Entry::Vacant(vacant) => {
+                    vacant.insert(idx);
+                }
+            }
+
+            current.end = idx + len;
+
+            if let Some(maximal) = maximal
+                && maximal.len() >= self.min_len
+            {
+                return Some(maximal);
+            }
+        }
+
+        // the rest is maximal unless the input was empty
+        self.current
+            .take()
+            .filter(|current| !current.is_empty() && current.len() >= self.min_len)
+    }
+}
+
+impl<'s> FusedIterator for UniqueSubstrs<'s> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +335,103 @@ mod tests {
     test_impl!(max_reached_end: "aaaaabcdef", Some(6) => "abcdef" 4..10);
     test_impl!(max_not_exceeded: "abcdeöfghijkl", Some(6) => "abcde" 0..5);
     test_impl!(max_not_exceeded_end: "aaaaabcdeö", Some(6) => "abcde" 4..9);
+    test_impl!(repeated_dupes: "ababa", None => "ab" 0..2);
+    test_impl!(repeated_dupes_longer: "abcbadef", None => "cbadef" 2..8);
+
+    #[test]
+    #[cfg(feature = "segmentation")]
+    fn graphemes() {
+        assert_eq!(longest_unique_graphemes("", None), 0..0);
+        assert_eq!(longest_unique_graphemes("e\u{301}ee", None), 0..4);
+        assert_eq!(longest_unique_graphemes("e\u{301}e\u{301}", None), 0..3);
+        assert_eq!(
+            longest_unique_graphemes("ae\u{301}b", Some(3.try_into().unwrap())),
+            0..1
+        );
+    }
+
+    #[test]
+    fn unique_substrs_empty() {
+        assert_eq!(unique_substrs("", 0).next(), None);
+    }
+
+    #[test]
+    fn unique_substrs_maximal() {
+        let collect = |input, min_len| unique_substrs(input, min_len).collect::<Vec<_>>();
+
+        assert_eq!(collect("abca", 0), [0..3, 1..4]);
+        assert_eq!(collect("aaa", 0), [0..1, 1..2, 2..3]);
+        assert_eq!(collect("abab", 0), [0..2, 1..3, 2..4]);
+        assert_eq!(collect("abba", 0), [0..2, 2..4]);
+        assert_eq!(collect("abcba", 0), [0..3, 2..5]);
+        assert_eq!(collect("äöä", 0), [0..4, 2..6]);
+    }
+
+    #[test]
+    fn unique_substrs_min_len() {
+        let collect = |input, min_len| unique_substrs(input, min_len).collect::<Vec<_>>();
+
+        assert_eq!(collect("abcxcde", 4), [0..4, 3..7]);
+        assert_eq!(collect("aaa", 2), []);
+
+        let mut iter = unique_substrs("abcxcdefa", 5);
+        assert_eq!(iter.next(), Some(3..9));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn by_key() {
+        let lower = |ch: char| ch.to_lowercase().next();
+        assert_eq!(longest_unique_substr_by_key("", None, lower), 0..0);
+        assert_eq!(longest_unique_substr_by_key("abAB", None, lower), 0..2);
+        assert_eq!(longest_unique_substr_by_key("ÄbäcÖ", None, lower), 2..8);
+        assert_eq!(
+            longest_unique_substr_by_key("abcAdef", Some(3.try_into().unwrap()), lower),
+            0..3
+        );
+        assert_eq!(longest_unique_substr_by_key("abc", None, |_| ()), 0..1);
+    }
+
+    #[test]
+    fn by_key_different_lengths() {
+        // the Kelvin sign 'K' and 'ẞ' are 3 bytes long, but share their keys with 'k' and 'ß',
+        // which are 1 and 2 bytes long
+        let lower = |ch: char| ch.to_lowercase().next();
+        for (input, expected) in [("\u{212A}kab", 0..3), ("ẞßxyz", 3..8), ("kK", 0..1)] {
+            let range = longest_unique_substr_by_key(input, None, lower);
+            assert!(input.get(range.clone()).is_some(), "{input}");
+            assert_eq!(range, expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn ascii_matches_generic() {
+        let alphabet = ['a', 'b', 'c'];
+        for len in 0..=7 {
+            for mut num in 0..3usize.pow(len) {
+                let input: String = (0..len)
+                    .map(|_| {
+                        let ch = alphabet[num % 3];
+                        num /= 3;
+                        ch
+                    })
+                    .collect();
+
+                for max in [None, Some(1), Some(2), Some(3)] {
+                    let max = max.map(|max: usize| max.try_into().unwrap());
+                    let generic = longest_unique_by(
+                        input
+                            .char_indices()
+                            .map(|(idx, ch)| (idx, ch, ch.len_utf8())),
+                        max,
+                    );
+                    assert_eq!(
+                        longest_unique_ascii(input.as_bytes(), max),
+                        generic,
+                        "{input}"
+                    );
+                }
+            }
+        }
+    }
 }
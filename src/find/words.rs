@@ -0,0 +1,107 @@
+use std::{iter::FusedIterator, ops::Range};
+
+/// Returns an [Iterator] over the byte ranges of the word-like segments of `input`. By default
+/// words are maximal runs of alphanumeric chars, with the `segmentation` feature enabled the
+/// words are determined by the Unicode word boundary rules of [UAX #29][0] instead, so
+/// `"can't"` is a single word.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// [0]: https://www.unicode.org/reports/tr29/#Word_Boundaries
+///
+/// # Examples
+/// ```
+/// use strtools::find;
+///
+/// let input = "hello, wörld 42";
+/// let words: Vec<_> = find::word_bounds(input).map(|range| &input[range]).collect();
+/// assert_eq!(words, ["hello", "wörld", "42"]);
+/// ```
+pub fn word_bounds(input: &str) -> WordBounds<'_> {
+    WordBounds {
+        #[cfg(not(feature = "segmentation"))]
+        input,
+        #[cfg(not(feature = "segmentation"))]
+        done: 0,
+        #[cfg(feature = "segmentation")]
+        iter: unicode_segmentation::UnicodeSegmentation::unicode_word_indices(input),
+    }
+}
+
+/// An [Iterator] that yields the byte ranges of words in a [str]. This struct is created by the
+/// [`word_bounds`] method, see it's documentation for more info.
+#[derive(Debug)]
+pub struct WordBounds<'input> {
+    #[cfg(not(feature = "segmentation"))]
+    input: &'input str,
+    #[cfg(not(feature = "segmentation"))]
+    done: usize,
+    #[cfg(feature = "segmentation")]
+    iter: unicode_segmentation::UnicodeWordIndices<'input>,
+}
+
+impl<'s> Iterator for WordBounds<'s> {
+    type Item = Range<usize>;
+
+    #[cfg(not(feature = "segmentation"))]
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.done..];
+        let start = self.done + rest.find(char::is_alphanumeric)?;
+        let len = self.input[start..]
+            .find(|ch: char| !ch.is_alphanumeric())
+            .unwrap_or(self.input.len() - start);
+
+        self.done = start + len;
+        Some(start..self.done)
+    }
+
+    #[cfg(feature = "segmentation")]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(start, word)| start..start + word.len())
+    }
+}
+
+impl<'s> FusedIterator for WordBounds<'s> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(input: &str) -> Vec<&str> {
+        word_bounds(input).map(|range| &input[range]).collect()
+    }
+
+    #[test]
+    fn empty() {
+        assert!(words("").is_empty());
+        assert!(words(" ,.- ").is_empty());
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(words("abc"), ["abc"]);
+        assert_eq!(words("  abc  "), ["abc"]);
+        assert_eq!(words("a b\tc"), ["a", "b", "c"]);
+        assert_eq!(words("äöü, ß"), ["äöü", "ß"]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "segmentation"))]
+    fn alphanumeric() {
+        assert_eq!(words("can't"), ["can", "t"]);
+        assert_eq!(words("foo_bar"), ["foo", "bar"]);
+    }
+
+    #[test]
+    #[cfg(feature = "segmentation")]
+    fn segmentation() {
+        assert_eq!(words("can't"), ["can't"]);
+        assert_eq!(words("foo_bar"), ["foo_bar"]);
+    }
+}
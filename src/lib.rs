@@ -55,7 +55,8 @@
     const_mut_refs,
     decl_macro,
     is_sorted,
-    let_chains
+    let_chains,
+    pattern
 )]
 // check for missing documentation
 #![warn(
@@ -67,8 +68,9 @@
 // reduce unsafe scopes to their minimum
 #![deny(unsafe_op_in_unsafe_fn)]
 
-use parse::{FromStrBack, FromStrFront};
-use util::Sorted;
+use parse::{FromStrBack, FromStrFront, FromStrPartialRadixExt};
+use std::{borrow::Cow, num::NonZeroUsize, ops::Range};
+use util::{CharMembership, LineEnding, Sorted};
 
 pub mod escape;
 pub mod find;
@@ -79,6 +81,23 @@ pub mod util;
 /// The main trait of this crate, providing various extension methods for [`str`].
 /// See the individual function documentation for more info. **The methods on this trait are subject
 /// to change during the development of the crates core functionality.**
+///
+/// The trait is also implemented for [`String`] and [`Cow<str>`][Cow] by delegating to [`str`],
+/// so generic code can accept owned strings without relying on deref.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+/// use strtools::StrTools;
+///
+/// fn field_count<T: StrTools + ?Sized>(line: &T) -> usize {
+///     line.split_non_escaped('\\', ',').map_or(0, Iterator::count)
+/// }
+///
+/// assert_eq!(field_count(r"a,b\,c"), 2);
+/// assert_eq!(field_count(&String::from("a,b,c")), 3);
+/// assert_eq!(field_count(&Cow::Borrowed("a")), 1);
+/// ```
 pub trait StrTools: util::sealed::Sealed {
     /// Behaves similar to [`str::split`] but generic of the the amount of indices.
     ///
@@ -102,6 +121,31 @@ pub trait StrTools: util::sealed::Sealed {
     /// ```
     fn split_n_times<const N: usize>(&self, indices: &Sorted<usize, N>) -> ([&str; N], &str);
 
+    /// Behaves similar to [`StrTools::split_n_times`] but returns an error instead of panicking,
+    /// see [`split::try_n_times`].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - an index is out of bounds, `index > input.len()`
+    /// - an index is not on a UTF-8 sequence boundary
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let ([first], second) = "0123".try_split_n_times(&[2].try_into()?)?;
+    /// assert_eq!((first, second), ("01", "23"));
+    ///
+    /// assert!("0123".try_split_n_times(&[5].try_into()?).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_split_n_times<const N: usize>(
+        &self,
+        indices: &Sorted<usize, N>,
+    ) -> Result<([&str; N], &str), split::CharBoundaryError>;
+
     /// Splits a [`str`] by the given delimiters unless they are preceded by an escape.
     /// Escapes before significant chars are removed, significant chars are the delimiters and the
     /// escape itself. Trailing escapes are ignored as if followed by a non-significant char.
@@ -180,67 +224,1790 @@ pub trait StrTools: util::sealed::Sealed {
         delims: D,
     ) -> Result<split::NonEscaped<'_, N>, split::NonEscapedError>;
 
-    /// Attempts to parse `T` from the beginning of the [`str`], returns the rest of the `input` and
-    /// `T` if parsing succeeded.
+    /// Splits a [`str`] by the given delimiters unless they are preceded by an escape, like
+    /// [`StrTools::split_non_escaped`]. Like [`str::split_terminator`], no trailing empty part is
+    /// yielded if the [`str`] ends with a non-escaped delimiter, see the [free version][free] of
+    /// this function for more control over delimiter sorting.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - the start of `input` contain any valid representation of `Self`
-    /// - `input` did not contain a complete representation of `Self`
+    /// - `esc == delim`
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the delimiters.
+    ///
+    /// # Allocation
+    /// No allocations are done.
     ///
     /// # Examples
     /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use strtools::StrTools;
     ///
-    /// let result = "-128 Look mom, no error!".parse_front::<i8>();
-    /// assert_eq!(result, Ok((-128, " Look mom, no error!")));
+    /// let lines: Vec<_> = "a\nb\\\nc\n".split_terminator_non_escaped('\\', '\n')?.collect();
+    /// assert_eq!(lines, ["a", "b\\\nc"]);
+    /// # Ok(())
+    /// # }
     /// ```
-    fn parse_front<T: FromStrFront>(&self) -> Result<(T, &str), T::Error>;
+    ///
+    /// [free]: split::non_escaped_terminator
+    fn split_terminator_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+        &self,
+        esc: char,
+        delims: D,
+    ) -> Result<split::NonEscapedTerminator<'_, Sorted<char, N>>, split::NonEscapedError>;
 
-    /// Attempts to parse `T` from the end of the [`str`], returns the rest of the `input` and T` if
-    /// parsing succeeded.
+    /// Returns an [Iterator] over the lines of the [`str`] including their line endings, together
+    /// with the kind of line ending, see [`split::lines_with_endings`] for more info.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::{util::LineEnding, StrTools};
+    ///
+    /// let endings: Vec<_> = "a\r\nb\nc".lines_with_endings().map(|(_, ending)| ending).collect();
+    /// assert_eq!(endings, [Some(LineEnding::CrLf), Some(LineEnding::Lf), None]);
+    /// ```
+    fn lines_with_endings(&self) -> split::LinesWithEndings<'_>;
+
+    /// Splits the [`str`] into a triple of before, the char at `index` and after, see
+    /// [`split::char_boundary`] for more info.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - the start of `input` contain any valid representation of `Self`
-    /// - `input` did not contain a complete representation of `Self`
+    /// - `input == ""`, eg.: it contains no char
+    /// - `index >= input.len()`, eg.: there is no char starting at index
+    /// - `index` is not on a UTF-8 sequence boundary
     ///
     /// # Examples
     /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// use strtools::StrTools;
     ///
-    /// let result = "Look mom, no error! -128".parse_back::<i8>();
-    /// assert_eq!(result, Ok((-128, "Look mom, no error! ")));
+    /// let (before, char_at_idx, after) = "aöböc".char_boundary(3)?;
+    /// assert_eq!((before, char_at_idx, after), ("aö", 'b', "öc"));
+    ///
+    /// // that's not a sequence boundary
+    /// assert!("aöböc".char_boundary(2).is_err());
+    /// # Ok(())
+    /// # }
     /// ```
-    fn parse_back<T: FromStrBack>(&self) -> Result<(T, &str), T::Error>;
-}
+    fn char_boundary(&self, index: usize) -> Result<(&str, char, &str), split::CharBoundaryError>;
 
-impl StrTools for str {
-    fn split_n_times<const N: usize>(&self, indices: &Sorted<usize, N>) -> ([&str; N], &str) {
-        split::n_times(self, indices)
-    }
+    /// Splits the [`str`] mutably into a triple of before, the char at `index` and after, see
+    /// [`split::char_boundary_mut`] for more info.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `input == ""`, eg.: it contains no char
+    /// - `index >= input.len()`, eg.: there is no char starting at index
+    /// - `index` is not on a UTF-8 sequence boundary
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// let mut input = String::from("aöböc");
+    /// let (_, _, after) = input.char_boundary_mut(3)?;
+    /// after.make_ascii_uppercase();
+    ///
+    /// assert_eq!(input, "aöböC");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn char_boundary_mut(
+        &mut self,
+        index: usize,
+    ) -> Result<(&mut str, char, &mut str), split::CharBoundaryError>;
 
-    fn split_non_escaped_sanitize<D: Into<Sorted<char, N>>, const N: usize>(
+    /// Returns the byte offset of the first of the given delimiters in the [`str`] that is not
+    /// preceded by an escape, see [`find::non_escaped_indices`] for more info.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `esc == delim`
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the delimiters.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::{split::NonEscapedError, StrTools};
+    ///
+    /// assert_eq!(r"a\:b:c".find_non_escaped('\\', ':')?, Some(4));
+    /// assert_eq!(r"a\:b".find_non_escaped('\\', ':')?, None);
+    /// assert_eq!("a:b;c".find_non_escaped('\\', [';', ':'])?, Some(1));
+    ///
+    /// assert_eq!(
+    ///     "a:b".find_non_escaped(':', ':'),
+    ///     Err(NonEscapedError::EscapeContainsDelimiter(':'))
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn find_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
         &self,
         esc: char,
         delims: D,
-    ) -> Result<split::NonEscapedSanitize<'_, N>, split::NonEscapedError> {
-        split::non_escaped_sanitize(self, esc, delims.into())
-    }
+    ) -> Result<Option<usize>, split::NonEscapedError>;
 
-    fn split_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+    /// Returns true if any of the given delimiters occurs in the [`str`] without being preceded by
+    /// an escape, see [`StrTools::find_non_escaped`] for more info.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `esc == delim`
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the delimiters.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// assert!(r"key\=with\=escapes=value".contains_non_escaped('\\', '=')?);
+    /// assert!(!r"key\=with\=escapes".contains_non_escaped('\\', '=')?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn contains_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
         &self,
         esc: char,
         delims: D,
-    ) -> Result<split::NonEscaped<'_, N>, split::NonEscapedError> {
-        split::non_escaped(self, esc, delims.into())
-    }
+    ) -> Result<bool, split::NonEscapedError>;
 
-    fn parse_front<T: FromStrFront>(&self) -> Result<(T, &str), T::Error> {
-        T::from_str_front(self)
-    }
+    /// Returns the amount of the given delimiters in the [`str`] that are not preceded by an
+    /// escape, see [`StrTools::find_non_escaped`] for more info.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `esc == delim`
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the delimiters.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrTools;
+    ///
+    /// // validate the field count before splitting
+    /// let input = r"user:C\:\\Users:admin";
+    /// assert_eq!(input.count_non_escaped('\\', ':')?, 2);
+    /// assert!(input.count_non_escaped(':', ':').is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn count_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+        &self,
+        esc: char,
+        delims: D,
+    ) -> Result<usize, split::NonEscapedError>;
 
-    fn parse_back<T: FromStrBack>(&self) -> Result<(T, &str), T::Error> {
-        T::from_str_back(self)
+    /// Returns the [`str`] with `prefix` removed like [`str::strip_prefix`], unless the prefix
+    /// ends with an escape that is not itself escaped, as that escape applies to the char after
+    /// the prefix.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(m)` time where `m` is the length of the prefix.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!(r"-\-flag".strip_prefix_non_escaped('\\', "-"), Some(r"\-flag"));
+    /// assert_eq!(r"-\-flag".strip_prefix_non_escaped('\\', r"-\"), None);
+    /// assert_eq!(r"\\path".strip_prefix_non_escaped('\\', r"\\"), Some("path"));
+    /// assert_eq!("flag".strip_prefix_non_escaped('\\', "-"), None);
+    /// ```
+    fn strip_prefix_non_escaped(&self, esc: char, prefix: &str) -> Option<&str>;
+
+    /// Returns the [`str`] with `suffix` removed like [`str::strip_suffix`], unless the suffix is
+    /// preceded by an escape that is not itself escaped.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(m + k)` time where `m` is the length of the suffix and `k` is
+    /// the amount of escapes directly preceding it.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!(r#"foo""#.strip_suffix_non_escaped('\\', "\""), Some("foo"));
+    /// assert_eq!(r#"foo\""#.strip_suffix_non_escaped('\\', "\""), None);
+    /// assert_eq!(r#"foo\\""#.strip_suffix_non_escaped('\\', "\""), Some(r"foo\\"));
+    /// ```
+    fn strip_suffix_non_escaped(&self, esc: char, suffix: &str) -> Option<&str>;
+
+    /// Returns the [`str`] with `prefix` prepended, unless it already starts with `prefix`.
+    ///
+    /// # Allocation
+    /// If the [`str`] already starts with `prefix`, no allocations are done and the input is
+    /// borrowed, otherwise a [`String`] is allocated and both are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("path/to".ensure_prefix("/"), "/path/to");
+    /// assert_eq!("/path/to".ensure_prefix("/"), "/path/to");
+    /// ```
+    fn ensure_prefix(&self, prefix: &str) -> Cow<'_, str>;
+
+    /// Returns the [`str`] with `suffix` appended, unless it already ends with `suffix`.
+    ///
+    /// # Allocation
+    /// If the [`str`] already ends with `suffix`, no allocations are done and the input is
+    /// borrowed, otherwise a [`String`] is allocated and both are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("https://example.com".ensure_suffix("/"), "https://example.com/");
+    /// assert_eq!("https://example.com/".ensure_suffix("/"), "https://example.com/");
+    /// ```
+    fn ensure_suffix(&self, suffix: &str) -> Cow<'_, str>;
+
+    /// Behaves like [`StrTools::ensure_suffix`], but a suffix preceded by an escape that is not
+    /// itself escaped is treated as absent, see [`StrTools::strip_suffix_non_escaped`].
+    ///
+    /// # Allocation
+    /// If the [`str`] already ends with a non-escaped `suffix`, no allocations are done and the
+    /// input is borrowed, otherwise a [`String`] is allocated and both are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("dir/".ensure_suffix_non_escaped('\\', "/"), "dir/");
+    /// assert_eq!(r"dir\/".ensure_suffix_non_escaped('\\', "/"), r"dir\//");
+    /// assert_eq!(r"dir\\/".ensure_suffix_non_escaped('\\', "/"), r"dir\\/");
+    /// ```
+    fn ensure_suffix_non_escaped(&self, esc: char, suffix: &str) -> Cow<'_, str>;
+
+    /// Escapes all chars in `charset` and the escape itself inside the [`str`], see
+    /// [`escape::charset_with`] for more info.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * c)` time where `n` is the length of the input string and `c`
+    /// is the cost of a membership check, a [`util::CharSet`] requires `O(1)` time for ASCII chars.
+    ///
+    /// # Allocation
+    /// If no chars need to be escaped, no allocations are done and the input is borrowed,
+    /// otherwise a [`String`] is allocated and the input is copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::{util::sorted, StrTools};
+    ///
+    /// assert_eq!("it's 'quoted'".escape_charset('\\', &sorted!['\'']), r"it\'s \'quoted\'");
+    /// ```
+    fn escape_charset<C: CharMembership + ?Sized>(&self, esc: char, charset: &C) -> Cow<'_, str>;
+
+    /// Removes the escapes before all chars in `charset` and the escape itself inside the
+    /// [`str`], this reverses [`StrTools::escape_charset`], see [`escape::unescape`] for more
+    /// info.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the input ends with an escape that is not itself escaped
+    /// - an escape is followed by a char that is neither in `charset` nor `esc`
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * c)` time where `n` is the length of the input string and `c`
+    /// is the cost of a membership check, a [`util::CharSet`] requires `O(1)` time for ASCII chars.
+    ///
+    /// # Allocation
+    /// If the input contains no escapes, no allocations are done and the input is borrowed,
+    /// otherwise a [`String`] is allocated and all but the escapes are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::{escape::UnescapeError, util::sorted, StrTools};
+    ///
+    /// let quotes = sorted!['\''];
+    /// let escaped = "it's".escape_charset('\\', &quotes);
+    /// assert_eq!(escaped.unescape('\\', &quotes)?, "it's");
+    ///
+    /// assert_eq!(r"it\'s\".unescape('\\', &quotes), Err(UnescapeError::Dangling(5)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn unescape<C: CharMembership + ?Sized>(
+        &self,
+        esc: char,
+        charset: &C,
+    ) -> Result<Cow<'_, str>, escape::UnescapeError>;
+
+    /// Returns a sub slice of the [`str`] with all leading and trailing chars contained in `set`
+    /// removed. The `set` parameter can be any [`CharMembership`], like a [`Sorted`] or
+    /// [`SortedSlice`][0] of chars, which are checked using binary search.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the set.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::{util::sorted, StrTools};
+    ///
+    /// assert_eq!("--=a-b=--".trim_charset(&sorted!['-', '=']), "a-b");
+    /// ```
+    ///
+    /// [0]: util::SortedSlice
+    fn trim_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str;
+
+    /// Returns a sub slice of the [`str`] with all leading chars contained in `set` removed, see
+    /// [`StrTools::trim_charset`] for more info.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the set.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::{util::sorted, StrTools};
+    ///
+    /// assert_eq!("0x00ff".trim_start_charset(&sorted!['0', 'x']), "ff");
+    /// ```
+    fn trim_start_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str;
+
+    /// Returns a sub slice of the [`str`] with all trailing chars contained in `set` removed, see
+    /// [`StrTools::trim_charset`] for more info.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the set.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::{util::SortedSlice, StrTools};
+    ///
+    /// let newlines: &SortedSlice<char> = ['\n', '\r'][..].try_into()?;
+    /// assert_eq!("line\r\n\n".trim_end_charset(newlines), "line");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trim_end_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str;
+
+    /// Returns the amount of chars in the [`str`] that are contained in `set`, see
+    /// [`StrTools::trim_charset`] for the accepted sets.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the set.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::{util::sorted, StrTools};
+    ///
+    /// let separators = sorted![',', ';'];
+    /// assert_eq!("a,b;c,d".count_chars_in(&separators), 3);
+    /// assert_eq!("abc".count_chars_in(&separators), 0);
+    /// ```
+    fn count_chars_in<C: CharMembership + ?Sized>(&self, set: &C) -> usize;
+
+    /// Returns the longest sub slice of the [`str`] in which each char is unique together with its
+    /// byte range, see [`find::longest_unique_substr`] for more info.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time, ignoring memmoves when draining the indexmap.
+    ///
+    /// # Allocation
+    /// An [`IndexMap`][0] is allocated to keep track of unique chars.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use strtools::StrTools;
+    ///
+    /// let (substr, range) = "abcabcbb".longest_unique_substr(None);
+    /// assert_eq!((substr, range), ("abc", 0..3));
+    ///
+    /// let (substr, _) = "abcdef".longest_unique_substr(NonZeroUsize::new(4));
+    /// assert_eq!(substr, "abcd");
+    /// ```
+    ///
+    /// [0]: indexmap::IndexMap
+    fn longest_unique_substr(&self, max: Option<NonZeroUsize>) -> (&str, Range<usize>);
+
+    /// Returns the Levenshtein distance between the [`str`] and `other`, see
+    /// [`find::levenshtein`] for more info.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input
+    /// strings.
+    ///
+    /// # Allocation
+    /// The chars of `other` and a single row of `m + 1` distances are collected into [Vec]s.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("kitten".edit_distance("sitting"), 3);
+    /// ```
+    fn edit_distance(&self, other: &str) -> usize;
+
+    /// Returns the similarity of the [`str`] and `other` in `0.0..=1.0`, this is the
+    /// [`StrTools::edit_distance`] normalized by the char count of the longer string, so `1.0`
+    /// means equal. Two empty strings are equal. See [`find::jaro_winkler`] for a metric better
+    /// suited for short strings like names.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * m)` time where `n` and `m` are the lengths of the input
+    /// strings.
+    ///
+    /// # Allocation
+    /// See [`StrTools::edit_distance`].
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("kitten".similarity("sitting"), 1.0 - 3.0 / 7.0);
+    /// assert_eq!("same".similarity("same"), 1.0);
+    /// assert_eq!("abc".similarity("xyz"), 0.0);
+    /// ```
+    fn similarity(&self, other: &str) -> f64;
+
+    /// Returns true if the [`str`] is empty or contains only whitespace as defined by
+    /// [`char::is_whitespace`], this includes Unicode whitespace like `'\u{3000}'`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert!("".is_blank());
+    /// assert!(" \t\r\n\u{3000}".is_blank());
+    /// assert!(!" a ".is_blank());
+    /// ```
+    fn is_blank(&self) -> bool;
+
+    /// Returns true if the [`str`] looks like a decimal number matching
+    /// `[+-]?([0-9]+\.?[0-9]*|\.[0-9]+)([eE][+-]?[0-9]+)?`. Unlike [`char::is_numeric`] only ASCII
+    /// digits are accepted, special values like `inf` or `NaN` are rejected.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert!("42".is_numeric_like());
+    /// assert!("-3.5e-2".is_numeric_like());
+    /// assert!(".5".is_numeric_like());
+    /// assert!("5.".is_numeric_like());
+    ///
+    /// assert!(!".".is_numeric_like());
+    /// assert!(!"1e".is_numeric_like());
+    /// assert!(!"inf".is_numeric_like());
+    /// assert!(!"٣".is_numeric_like());
+    /// ```
+    fn is_numeric_like(&self) -> bool;
+
+    /// Returns true if the whole [`str`] is an identifier matching `[A-Za-z_][A-Za-z0-9_]*`, see
+    /// [`parse::ident_front`].
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert!("foo_bar2".is_identifier());
+    /// assert!("_".is_identifier());
+    ///
+    /// assert!(!"".is_identifier());
+    /// assert!(!"2x".is_identifier());
+    /// assert!(!"foo-bar".is_identifier());
+    /// assert!(!"größe".is_identifier());
+    /// ```
+    fn is_identifier(&self) -> bool;
+
+    /// Returns true if the [`str`] contains only printable ASCII chars, these are the chars from
+    /// `' '` to `'~'`, excluding control chars like `'\t'` or `'\n'`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert!("Hello, World!".is_ascii_printable());
+    /// assert!(!"tab\tseparated".is_ascii_printable());
+    /// assert!(!"café".is_ascii_printable());
+    /// ```
+    fn is_ascii_printable(&self) -> bool;
+
+    /// Removes the common leading whitespace of all lines which are not blank. Only `' '` and
+    /// `'\t'` are treated as leading whitespace and they are compared literally, a tab never
+    /// matches any amount of spaces. Blank lines, which contain only whitespace, are ignored when
+    /// determining the common whitespace, if they are shorter than it all of their whitespace is
+    /// removed. Line endings are kept as they are.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// If there is no common leading whitespace, no allocations are done and the input is
+    /// borrowed, otherwise a [`String`] is allocated and the lines are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// let input = "    fn main() {\n        println!();\n\n    }\n";
+    /// assert_eq!(input.dedent(), "fn main() {\n    println!();\n\n}\n");
+    ///
+    /// // tabs and spaces don't mix, there is no common leading whitespace here
+    /// let input = "\tfoo\n    bar";
+    /// assert_eq!(input.dedent(), input);
+    /// ```
+    fn dedent(&self) -> Cow<'_, str>;
+
+    /// Prepends `prefix` to all lines which are not blank, blank lines contain only `' '` and
+    /// `'\t'`. Line endings are kept as they are.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// If `prefix` is empty or all lines are blank, no allocations are done and the input is
+    /// borrowed, otherwise a [`String`] is allocated and the lines are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("foo\n\nbar\r\n".indent("> "), "> foo\n\n> bar\r\n");
+    /// assert_eq!("foo\nbar".dedent().indent("\t"), "\tfoo\n\tbar");
+    /// ```
+    fn indent(&self, prefix: &str) -> Cow<'_, str>;
+
+    /// Shortens the [`str`] to at most `max_width` columns including `ellipsis`, which is only
+    /// appended if the [`str`] was truncated, see [`StrTools::pad_left`] for how columns are
+    /// counted. With the `segmentation` feature enabled the cut is made at an extended grapheme
+    /// cluster boundary, so combining marks are never separated from their base char. If
+    /// `ellipsis` itself is wider than `max_width`, it is truncated instead.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// If the [`str`] is at most `max_width` columns wide, no allocations are done and the input
+    /// is borrowed, otherwise a [`String`] is allocated and the kept prefix and `ellipsis` are
+    /// copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("short".truncate_ellipsis(8, "..."), "short");
+    /// assert_eq!("a longer title".truncate_ellipsis(8, "..."), "a lon...");
+    /// assert_eq!("größenwahn".truncate_ellipsis(6, "…"), "größe…");
+    ///
+    /// #[cfg(feature = "unicode-width")]
+    /// assert_eq!("日本語のテキスト".truncate_ellipsis(8, "…"), "日本語…");
+    /// ```
+    fn truncate_ellipsis(&self, max_width: usize, ellipsis: &str) -> Cow<'_, str>;
+
+    /// Removes leading and trailing whitespace and replaces every run of whitespace in between
+    /// with a single `' '`, whitespace is defined by [`char::is_whitespace`].
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// If the whitespace is already collapsed, no allocations are done and the trimmed input is
+    /// borrowed, otherwise a [`String`] is allocated and the words are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("  hello \t\n wörld  ".collapse_whitespace(), "hello wörld");
+    /// assert!(matches!(" hello wörld ".collapse_whitespace(), Cow::Borrowed("hello wörld")));
+    /// ```
+    fn collapse_whitespace(&self) -> Cow<'_, str>;
+
+    /// Replaces every run of `ch` with a single `ch`, like `tr -s`.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// If there are no runs of `ch`, no allocations are done and the input is borrowed, otherwise
+    /// a [`String`] is allocated and the squeezed input is copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("path//to///file".squeeze('/'), "path/to/file");
+    /// assert!(matches!("path/to/file".squeeze('/'), Cow::Borrowed(_)));
+    /// ```
+    fn squeeze(&self, ch: char) -> Cow<'_, str>;
+
+    /// Replaces every run of the same char contained in `set` with a single occurrence of it,
+    /// like `tr -s`. Runs of different chars are not squeezed together, see
+    /// [`StrTools::trim_charset`] for the accepted sets.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the set.
+    ///
+    /// # Allocation
+    /// If there are no runs of chars in `set`, no allocations are done and the input is borrowed,
+    /// otherwise a [`String`] is allocated and the squeezed input is copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::{util::sorted, StrTools};
+    ///
+    /// assert_eq!("a--b  c-- d".squeeze_charset(&sorted![' ', '-']), "a-b c- d");
+    /// assert_eq!("aabbcc".squeeze_charset(&sorted!['a', 'c']), "abbc");
+    /// ```
+    fn squeeze_charset<C: CharMembership + ?Sized>(&self, set: &C) -> Cow<'_, str>;
+
+    /// Pads the start of the [`str`] with `fill` until it is `width` columns wide. Without the
+    /// `unicode-width` feature every char is one column wide, with it the display width is used,
+    /// so wide chars like CJK ideographs take two columns, see `util::display_width`. If a wide
+    /// `fill` doesn't fit exactly, the result is slightly narrower than `width`.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n + w)` time where `n` is the length of the input string and `w`
+    /// is `width`.
+    ///
+    /// # Allocation
+    /// If the [`str`] is already at least `width` columns wide, no allocations are done and the
+    /// input is borrowed, otherwise a [`String`] is allocated and the padded input is copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("42".pad_left(5, '0'), "00042");
+    /// assert_eq!("123456".pad_left(5, '0'), "123456");
+    ///
+    /// #[cfg(feature = "unicode-width")]
+    /// assert_eq!("日本".pad_left(6, ' '), "  日本");
+    /// ```
+    fn pad_left(&self, width: usize, fill: char) -> Cow<'_, str>;
+
+    /// Pads the end of the [`str`] with `fill` until it is `width` columns wide, see
+    /// [`StrTools::pad_left`] for how columns are counted.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n + w)` time where `n` is the length of the input string and `w`
+    /// is `width`.
+    ///
+    /// # Allocation
+    /// If the [`str`] is already at least `width` columns wide, no allocations are done and the
+    /// input is borrowed, otherwise a [`String`] is allocated and the padded input is copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("name".pad_right(8, '.'), "name....");
+    ///
+    /// #[cfg(feature = "unicode-width")]
+    /// assert_eq!("日本".pad_right(6, '.'), "日本..");
+    /// ```
+    fn pad_right(&self, width: usize, fill: char) -> Cow<'_, str>;
+
+    /// Pads both sides of the [`str`] with `fill` until it is `width` columns wide, if the padding
+    /// cannot be split evenly, the end receives the extra column. See [`StrTools::pad_left`] for
+    /// how columns are counted.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n + w)` time where `n` is the length of the input string and `w`
+    /// is `width`.
+    ///
+    /// # Allocation
+    /// If the [`str`] is already at least `width` columns wide, no allocations are done and the
+    /// input is borrowed, otherwise a [`String`] is allocated and the padded input is copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("title".center(11, '='), "===title===");
+    /// assert_eq!("ab".center(5, ' '), " ab  ");
+    ///
+    /// #[cfg(feature = "unicode-width")]
+    /// assert_eq!("日本".center(8, '-'), "--日本--");
+    /// ```
+    fn center(&self, width: usize, fill: char) -> Cow<'_, str>;
+
+    /// Replaces all `"\r\n"` and lone `'\r'` line endings with `'\n'`, see
+    /// [`StrTools::normalize_newlines_to`] for other line endings.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// If the input contains no `'\r'`, no allocations are done and the input is borrowed,
+    /// otherwise a [`String`] is allocated and the lines are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// assert_eq!("a\r\nb\rc\n".normalize_newlines(), "a\nb\nc\n");
+    /// ```
+    fn normalize_newlines(&self) -> Cow<'_, str>;
+
+    /// Replaces all line endings with `ending`, `"\r\n"` is a single line ending. See
+    /// [`LineEnding::write_normalized`][0] for a version writing to a [`fmt::Write`][1].
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// If all line endings already are `ending`, no allocations are done and the input is
+    /// borrowed, otherwise a [`String`] is allocated and the lines are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use strtools::{util::LineEnding, StrTools};
+    ///
+    /// assert_eq!("a\nb\rc".normalize_newlines_to(LineEnding::CrLf), "a\r\nb\r\nc");
+    /// assert!(matches!("a\r\nb".normalize_newlines_to(LineEnding::CrLf), Cow::Borrowed(_)));
+    /// ```
+    ///
+    /// [0]: util::LineEnding::write_normalized
+    /// [1]: std::fmt::Write
+    fn normalize_newlines_to(&self, ending: LineEnding) -> Cow<'_, str>;
+
+    /// Attempts to parse `T` from the beginning of the [`str`], returns the rest of the `input` and
+    /// `T` if parsing succeeded.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the start of `input` contain any valid representation of `Self`
+    /// - `input` did not contain a complete representation of `Self`
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// let result = "-128 Look mom, no error!".parse_front::<i8>();
+    /// assert_eq!(result, Ok((-128, " Look mom, no error!")));
+    /// ```
+    fn parse_front<T: FromStrFront>(&self) -> Result<(T, &str), T::Error>;
+
+    /// Attempts to parse `T` from the end of the [`str`], returns the rest of the `input` and T` if
+    /// parsing succeeded.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the start of `input` contain any valid representation of `Self`
+    /// - `input` did not contain a complete representation of `Self`
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// let result = "Look mom, no error! -128".parse_back::<i8>();
+    /// assert_eq!(result, Ok((-128, "Look mom, no error! ")));
+    /// ```
+    fn parse_back<T: FromStrBack>(&self) -> Result<(T, &str), T::Error>;
+
+    /// Attempts to parse `T` from the beginning of the [`str`] in the given radix, returns the rest
+    /// of the `input` and `T` if parsing succeeded.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the start of `input` contain any valid representation of `Self`
+    /// - `input` did not contain a complete representation of `Self`
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// let result = "ff Look mom, no error!".parse_front_radix::<u8>(16);
+    /// assert_eq!(result, Ok((255, " Look mom, no error!")));
+    /// ```
+    fn parse_front_radix<T: FromStrPartialRadixExt>(
+        &self,
+        radix: u32,
+    ) -> Result<(T, &str), <T as FromStrFront>::Error>;
+
+    /// Attempts to parse `T` from the end of the [`str`] in the given radix, returns the rest of
+    /// the `input` and `T` if parsing succeeded.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the start of `input` contain any valid representation of `Self`
+    /// - `input` did not contain a complete representation of `Self`
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// let result = "Look mom, no error! -1010".parse_back_radix::<i8>(2);
+    /// assert_eq!(result, Ok((-10, "Look mom, no error! ")));
+    /// ```
+    fn parse_back_radix<T: FromStrPartialRadixExt>(
+        &self,
+        radix: u32,
+    ) -> Result<(T, &str), <T as FromStrBack>::Error>;
+
+    /// Parses `T` from the beginning of the [`str`] using the given `parser`, which returns `T`
+    /// and the rest of its input. This allows ad-hoc parsers without implementing
+    /// [`FromStrFront`] for a new type, `T` cannot borrow from the input.
+    ///
+    /// # Errors
+    /// Returns an error if `parser` returned an error.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrTools;
+    ///
+    /// let sign = "-5".parse_front_with(|input| match input.strip_prefix('-') {
+    ///     Some(rest) => Ok::<_, ()>((-1, rest)),
+    ///     None => Ok((1, input)),
+    /// });
+    /// assert_eq!(sign, Ok((-1, "5")));
+    ///
+    /// // parsers compose with the other parse methods
+    /// let pair = "3x4".parse_front_with(|input| {
+    ///     let (width, rest) = input.parse_front::<u8>()?;
+    ///     let (height, rest) = rest.strip_prefix('x').unwrap_or(rest).parse_front::<u8>()?;
+    ///     Ok::<_, Box<dyn std::error::Error>>(((width, height), rest))
+    /// });
+    /// assert_eq!(pair.unwrap(), ((3, 4), ""));
+    /// ```
+    fn parse_front_with<T, E, F>(&self, parser: F) -> Result<(T, &str), E>
+    where
+        F: FnOnce(&str) -> Result<(T, &str), E>;
+}
+
+impl StrTools for str {
+    fn split_n_times<const N: usize>(&self, indices: &Sorted<usize, N>) -> ([&str; N], &str) {
+        split::n_times(self, indices)
+    }
+
+    fn try_split_n_times<const N: usize>(
+        &self,
+        indices: &Sorted<usize, N>,
+    ) -> Result<([&str; N], &str), split::CharBoundaryError> {
+        split::try_n_times(self, indices)
+    }
+
+    fn split_non_escaped_sanitize<D: Into<Sorted<char, N>>, const N: usize>(
+        &self,
+        esc: char,
+        delims: D,
+    ) -> Result<split::NonEscapedSanitize<'_, N>, split::NonEscapedError> {
+        split::non_escaped_sanitize(self, esc, delims.into())
+    }
+
+    fn split_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+        &self,
+        esc: char,
+        delims: D,
+    ) -> Result<split::NonEscaped<'_, N>, split::NonEscapedError> {
+        split::non_escaped(self, esc, delims.into())
+    }
+
+    fn split_terminator_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+        &self,
+        esc: char,
+        delims: D,
+    ) -> Result<split::NonEscapedTerminator<'_, Sorted<char, N>>, split::NonEscapedError> {
+        split::non_escaped_terminator(self, esc, delims.into())
+    }
+
+    fn lines_with_endings(&self) -> split::LinesWithEndings<'_> {
+        split::lines_with_endings(self)
+    }
+
+    fn char_boundary(&self, index: usize) -> Result<(&str, char, &str), split::CharBoundaryError> {
+        split::char_boundary(self, index)
+    }
+
+    fn char_boundary_mut(
+        &mut self,
+        index: usize,
+    ) -> Result<(&mut str, char, &mut str), split::CharBoundaryError> {
+        split::char_boundary_mut(self, index)
+    }
+
+    fn find_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+        &self,
+        esc: char,
+        delims: D,
+    ) -> Result<Option<usize>, split::NonEscapedError> {
+        Ok(find::non_escaped_indices(self, esc, delims.into())?.next())
+    }
+
+    fn contains_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+        &self,
+        esc: char,
+        delims: D,
+    ) -> Result<bool, split::NonEscapedError> {
+        Ok(self.find_non_escaped(esc, delims)?.is_some())
+    }
+
+    fn count_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+        &self,
+        esc: char,
+        delims: D,
+    ) -> Result<usize, split::NonEscapedError> {
+        Ok(find::non_escaped_indices(self, esc, delims.into())?.count())
+    }
+
+    fn strip_prefix_non_escaped(&self, esc: char, prefix: &str) -> Option<&str> {
+        let rest = self.strip_prefix(prefix)?;
+        (!ends_with_escape(prefix, esc)).then_some(rest)
+    }
+
+    fn strip_suffix_non_escaped(&self, esc: char, suffix: &str) -> Option<&str> {
+        let rest = self.strip_suffix(suffix)?;
+        (!ends_with_escape(rest, esc)).then_some(rest)
+    }
+
+    fn ensure_prefix(&self, prefix: &str) -> Cow<'_, str> {
+        if self.starts_with(prefix) {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned([prefix, self].concat())
+        }
+    }
+
+    fn ensure_suffix(&self, suffix: &str) -> Cow<'_, str> {
+        if self.ends_with(suffix) {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned([self, suffix].concat())
+        }
+    }
+
+    fn ensure_suffix_non_escaped(&self, esc: char, suffix: &str) -> Cow<'_, str> {
+        if self.strip_suffix_non_escaped(esc, suffix).is_some() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned([self, suffix].concat())
+        }
+    }
+
+    fn escape_charset<C: CharMembership + ?Sized>(&self, esc: char, charset: &C) -> Cow<'_, str> {
+        escape::charset_with(self, esc, charset)
+    }
+
+    fn unescape<C: CharMembership + ?Sized>(
+        &self,
+        esc: char,
+        charset: &C,
+    ) -> Result<Cow<'_, str>, escape::UnescapeError> {
+        escape::unescape(self, esc, charset)
+    }
+
+    fn trim_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str {
+        self.trim_matches(|ch| set.contains_char(ch))
+    }
+
+    fn trim_start_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str {
+        self.trim_start_matches(|ch| set.contains_char(ch))
+    }
+
+    fn trim_end_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str {
+        self.trim_end_matches(|ch| set.contains_char(ch))
+    }
+
+    fn count_chars_in<C: CharMembership + ?Sized>(&self, set: &C) -> usize {
+        self.chars().filter(|&ch| set.contains_char(ch)).count()
+    }
+
+    fn longest_unique_substr(&self, max: Option<NonZeroUsize>) -> (&str, Range<usize>) {
+        let range = find::longest_unique_substr(self, max);
+        (&self[range.clone()], range)
+    }
+
+    fn edit_distance(&self, other: &str) -> usize {
+        find::levenshtein(self, other)
+    }
+
+    fn similarity(&self, other: &str) -> f64 {
+        let len = self.chars().count().max(other.chars().count());
+        if len == 0 {
+            return 1.0;
+        }
+
+        1.0 - self.edit_distance(other) as f64 / len as f64
+    }
+
+    fn is_blank(&self) -> bool {
+        self.chars().all(char::is_whitespace)
+    }
+
+    fn is_numeric_like(&self) -> bool {
+        let digits = |bytes: &[u8]| bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+
+        let mut rest = self.as_bytes();
+        if let [b'+' | b'-', tail @ ..] = rest {
+            rest = tail;
+        }
+
+        let int = digits(rest);
+        rest = &rest[int..];
+
+        let mut frac = 0;
+        if let [b'.', tail @ ..] = rest {
+            frac = digits(tail);
+            rest = &tail[frac..];
+        }
+
+        if int + frac == 0 {
+            return false;
+        }
+
+        if let [b'e' | b'E', tail @ ..] = rest {
+            let tail = match tail {
+                [b'+' | b'-', tail @ ..] => tail,
+                _ => tail,
+            };
+
+            let exp = digits(tail);
+            if exp == 0 {
+                return false;
+            }
+            rest = &tail[exp..];
+        }
+
+        rest.is_empty()
+    }
+
+    fn is_identifier(&self) -> bool {
+        matches!(parse::ident_front(self), Ok((_, "")))
+    }
+
+    fn is_ascii_printable(&self) -> bool {
+        self.bytes().all(|b| matches!(b, b' '..=b'~'))
+    }
+
+    fn dedent(&self) -> Cow<'_, str> {
+        let mut margin: Option<&str> = None;
+        for line in self.split_inclusive('\n') {
+            let rest = line.trim_start_matches([' ', '\t']);
+            if is_blank_line(rest) {
+                continue;
+            }
+
+            let leading = &line[..line.len() - rest.len()];
+            margin = Some(match margin {
+                Some(margin) => {
+                    let common = margin
+                        .bytes()
+                        .zip(leading.bytes())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    &margin[..common]
+                }
+                None => leading,
+            });
+        }
+
+        let margin = margin.unwrap_or("");
+        if margin.is_empty() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut result = String::with_capacity(self.len());
+        for line in self.split_inclusive('\n') {
+            result.push_str(
+                line.strip_prefix(margin)
+                    .unwrap_or_else(|| line.trim_start_matches([' ', '\t'])),
+            );
+        }
+
+        Cow::Owned(result)
+    }
+
+    fn indent(&self, prefix: &str) -> Cow<'_, str> {
+        let is_blank = |line: &str| is_blank_line(line.trim_start_matches([' ', '\t']));
+
+        if prefix.is_empty() || self.split_inclusive('\n').all(is_blank) {
+            return Cow::Borrowed(self);
+        }
+
+        let mut result = String::with_capacity(self.len());
+        for line in self.split_inclusive('\n') {
+            if !is_blank(line) {
+                result.push_str(prefix);
+            }
+            result.push_str(line);
+        }
+
+        Cow::Owned(result)
+    }
+
+    fn truncate_ellipsis(&self, max_width: usize, ellipsis: &str) -> Cow<'_, str> {
+        if columns(self) <= max_width {
+            return Cow::Borrowed(self);
+        }
+
+        let ellipsis = columns_prefix(ellipsis, max_width);
+        let cut = columns_prefix(self, max_width.saturating_sub(columns(ellipsis))).len();
+
+        #[cfg(feature = "segmentation")]
+        let cut = unicode_segmentation::UnicodeSegmentation::grapheme_indices(self, true)
+            .map(|(idx, _)| idx)
+            .take_while(|&idx| idx <= cut)
+            .last()
+            .unwrap_or(0);
+
+        let mut result = String::with_capacity(cut + ellipsis.len());
+        result.push_str(&self[..cut]);
+        result.push_str(ellipsis);
+
+        Cow::Owned(result)
+    }
+
+    fn collapse_whitespace(&self) -> Cow<'_, str> {
+        let trimmed = self.trim();
+
+        let mut prev_whitespace = false;
+        let collapsed = trimmed.chars().all(|ch| {
+            let is_whitespace = ch.is_whitespace();
+            let valid = !is_whitespace || (ch == ' ' && !prev_whitespace);
+            prev_whitespace = is_whitespace;
+            valid
+        });
+
+        if collapsed {
+            return Cow::Borrowed(trimmed);
+        }
+
+        let mut result = String::with_capacity(trimmed.len());
+        for word in trimmed.split_whitespace() {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            result.push_str(word);
+        }
+
+        Cow::Owned(result)
+    }
+
+    fn squeeze(&self, ch: char) -> Cow<'_, str> {
+        squeeze_by(self, |c| c == ch)
+    }
+
+    fn squeeze_charset<C: CharMembership + ?Sized>(&self, set: &C) -> Cow<'_, str> {
+        squeeze_by(self, |ch| set.contains_char(ch))
+    }
+
+    fn pad_left(&self, width: usize, fill: char) -> Cow<'_, str> {
+        pad(self, width, fill, |_| 0)
+    }
+
+    fn pad_right(&self, width: usize, fill: char) -> Cow<'_, str> {
+        pad(self, width, fill, |missing| missing)
+    }
+
+    fn center(&self, width: usize, fill: char) -> Cow<'_, str> {
+        pad(self, width, fill, |missing| missing - missing / 2)
+    }
+
+    fn normalize_newlines(&self) -> Cow<'_, str> {
+        self.normalize_newlines_to(LineEnding::Lf)
+    }
+
+    fn normalize_newlines_to(&self, ending: LineEnding) -> Cow<'_, str> {
+        let mut rest = self;
+        while let Some((idx, found)) = LineEnding::find(rest) {
+            if found != ending {
+                let mut result = String::with_capacity(self.len());
+
+                // writing to a String never fails
+                let _ = ending.write_normalized(self, &mut result);
+                return Cow::Owned(result);
+            }
+
+            rest = &rest[idx + found.as_str().len()..];
+        }
+
+        Cow::Borrowed(self)
+    }
+
+    fn parse_front<T: FromStrFront>(&self) -> Result<(T, &str), T::Error> {
+        T::from_str_front(self)
+    }
+
+    fn parse_back<T: FromStrBack>(&self) -> Result<(T, &str), T::Error> {
+        T::from_str_back(self)
+    }
+
+    fn parse_front_radix<T: FromStrPartialRadixExt>(
+        &self,
+        radix: u32,
+    ) -> Result<(T, &str), <T as FromStrFront>::Error> {
+        T::from_str_radix_front(self, radix)
+    }
+
+    fn parse_back_radix<T: FromStrPartialRadixExt>(
+        &self,
+        radix: u32,
+    ) -> Result<(T, &str), <T as FromStrBack>::Error> {
+        T::from_str_radix_back(self, radix)
+    }
+
+    fn parse_front_with<T, E, F>(&self, parser: F) -> Result<(T, &str), E>
+    where
+        F: FnOnce(&str) -> Result<(T, &str), E>,
+    {
+        parser(self)
+    }
+}
+
+/// Returns true if the rest of a line after its leading whitespace is only the line ending.
+fn is_blank_line(rest: &str) -> bool {
+    matches!(rest, "" | "\n" | "\r\n")
+}
+
+/// Returns true if `input` ends with an escape that is not itself escaped.
+fn ends_with_escape(input: &str, esc: char) -> bool {
+    input.chars().rev().take_while(|&ch| ch == esc).count() % 2 == 1
+}
+
+/// Replaces every run of the same char matching `squeezable` with a single occurrence of it.
+fn squeeze_by(input: &str, squeezable: impl Fn(char) -> bool) -> Cow<'_, str> {
+    let is_run = |(a, b): (char, char)| a == b && squeezable(a);
+    if !input.chars().zip(input.chars().skip(1)).any(is_run) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut result = String::with_capacity(input.len());
+    let mut prev = None;
+    for ch in input.chars() {
+        if prev != Some(ch) || !squeezable(ch) {
+            result.push(ch);
+        }
+        prev = Some(ch);
+    }
+
+    Cow::Owned(result)
+}
+
+/// Returns the width of `input` in columns, see [`StrTools::pad_left`] for how columns are counted.
+#[cfg(feature = "unicode-width")]
+fn columns(input: &str) -> usize {
+    util::display_width(input)
+}
+
+#[cfg(not(feature = "unicode-width"))]
+fn columns(input: &str) -> usize {
+    input.chars().count()
+}
+
+/// Returns the longest prefix of `input` that is at most `width` columns wide.
+#[cfg(feature = "unicode-width")]
+fn columns_prefix(input: &str, width: usize) -> &str {
+    util::width_at_most(input, width)
+}
+
+#[cfg(not(feature = "unicode-width"))]
+fn columns_prefix(input: &str, width: usize) -> &str {
+    let end = input
+        .char_indices()
+        .nth(width)
+        .map_or(input.len(), |(idx, _)| idx);
+    &input[..end]
+}
+
+/// Pads `input` with `fill` until it is `width` columns wide, `end` returns how many of the
+/// missing columns are added to the end, the rest is added to the start.
+fn pad(input: &str, width: usize, fill: char, end: impl FnOnce(usize) -> usize) -> Cow<'_, str> {
+    #[cfg(feature = "unicode-width")]
+    let (current, fill_width) = (util::display_width(input), util::char_width(fill).max(1));
+    #[cfg(not(feature = "unicode-width"))]
+    let (current, fill_width) = (input.chars().count(), 1);
+
+    let Some(missing) = width.checked_sub(current).filter(|&missing| missing != 0) else {
+        return Cow::Borrowed(input);
+    };
+
+    let end = end(missing);
+    let (start, end) = ((missing - end) / fill_width, end / fill_width);
+
+    let mut result = String::with_capacity(input.len() + (start + end) * fill.len_utf8());
+    (0..start).for_each(|_| result.push(fill));
+    result.push_str(input);
+    (0..end).for_each(|_| result.push(fill));
+
+    Cow::Owned(result)
+}
+
+/// Implements [`StrTools`] for owned string types by delegating to the [`str`] implementation,
+/// `$as_mut` borrows `$this` as a `&mut str` for the mutable methods.
+macro_rules! impl_delegated {
+    ($($t:ty => |$this:ident| $as_mut:expr),+ $(,)?) => {
+        $(
+            impl StrTools for $t {
+                fn split_n_times<const N: usize>(
+                    &self,
+                    indices: &Sorted<usize, N>,
+                ) -> ([&str; N], &str) {
+                    <str as StrTools>::split_n_times(self, indices)
+                }
+
+                fn try_split_n_times<const N: usize>(
+                    &self,
+                    indices: &Sorted<usize, N>,
+                ) -> Result<([&str; N], &str), split::CharBoundaryError> {
+                    <str as StrTools>::try_split_n_times(self, indices)
+                }
+
+                fn split_non_escaped_sanitize<D: Into<Sorted<char, N>>, const N: usize>(
+                    &self,
+                    esc: char,
+                    delims: D,
+                ) -> Result<split::NonEscapedSanitize<'_, N>, split::NonEscapedError>
+                {
+                    <str as StrTools>::split_non_escaped_sanitize(self, esc, delims)
+                }
+
+                fn split_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+                    &self,
+                    esc: char,
+                    delims: D,
+                ) -> Result<split::NonEscaped<'_, N>, split::NonEscapedError> {
+                    <str as StrTools>::split_non_escaped(self, esc, delims)
+                }
+
+                fn split_terminator_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+                    &self,
+                    esc: char,
+                    delims: D,
+                ) -> Result<
+                    split::NonEscapedTerminator<'_, Sorted<char, N>>,
+                    split::NonEscapedError,
+                > {
+                    <str as StrTools>::split_terminator_non_escaped(self, esc, delims)
+                }
+
+                fn lines_with_endings(&self) -> split::LinesWithEndings<'_> {
+                    <str as StrTools>::lines_with_endings(self)
+                }
+
+                fn char_boundary(
+                    &self,
+                    index: usize,
+                ) -> Result<(&str, char, &str), split::CharBoundaryError> {
+                    <str as StrTools>::char_boundary(self, index)
+                }
+
+                fn char_boundary_mut(
+                    &mut self,
+                    index: usize,
+                ) -> Result<(&mut str, char, &mut str), split::CharBoundaryError> {
+                    let $this = self;
+                    <str as StrTools>::char_boundary_mut($as_mut, index)
+                }
+
+                fn find_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+                    &self,
+                    esc: char,
+                    delims: D,
+                ) -> Result<Option<usize>, split::NonEscapedError> {
+                    <str as StrTools>::find_non_escaped(self, esc, delims)
+                }
+
+                fn contains_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+                    &self,
+                    esc: char,
+                    delims: D,
+                ) -> Result<bool, split::NonEscapedError> {
+                    <str as StrTools>::contains_non_escaped(self, esc, delims)
+                }
+
+                fn count_non_escaped<D: Into<Sorted<char, N>>, const N: usize>(
+                    &self,
+                    esc: char,
+                    delims: D,
+                ) -> Result<usize, split::NonEscapedError> {
+                    <str as StrTools>::count_non_escaped(self, esc, delims)
+                }
+
+                fn strip_prefix_non_escaped(&self, esc: char, prefix: &str) -> Option<&str> {
+                    <str as StrTools>::strip_prefix_non_escaped(self, esc, prefix)
+                }
+
+                fn strip_suffix_non_escaped(&self, esc: char, suffix: &str) -> Option<&str> {
+                    <str as StrTools>::strip_suffix_non_escaped(self, esc, suffix)
+                }
+
+                fn ensure_prefix(&self, prefix: &str) -> Cow<'_, str> {
+                    <str as StrTools>::ensure_prefix(self, prefix)
+                }
+
+                fn ensure_suffix(&self, suffix: &str) -> Cow<'_, str> {
+                    <str as StrTools>::ensure_suffix(self, suffix)
+                }
+
+                fn ensure_suffix_non_escaped(&self, esc: char, suffix: &str) -> Cow<'_, str> {
+                    <str as StrTools>::ensure_suffix_non_escaped(self, esc, suffix)
+                }
+
+                fn escape_charset<C: CharMembership + ?Sized>(
+                    &self,
+                    esc: char,
+                    charset: &C,
+                ) -> Cow<'_, str> {
+                    <str as StrTools>::escape_charset(self, esc, charset)
+                }
+
+                fn unescape<C: CharMembership + ?Sized>(
+                    &self,
+                    esc: char,
+                    charset: &C,
+                ) -> Result<Cow<'_, str>, escape::UnescapeError> {
+                    <str as StrTools>::unescape(self, esc, charset)
+                }
+
+                fn trim_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str {
+                    <str as StrTools>::trim_charset(self, set)
+                }
+
+                fn trim_start_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str {
+                    <str as StrTools>::trim_start_charset(self, set)
+                }
+
+                fn trim_end_charset<C: CharMembership + ?Sized>(&self, set: &C) -> &str {
+                    <str as StrTools>::trim_end_charset(self, set)
+                }
+
+                fn count_chars_in<C: CharMembership + ?Sized>(&self, set: &C) -> usize {
+                    <str as StrTools>::count_chars_in(self, set)
+                }
+
+                fn longest_unique_substr(
+                    &self,
+                    max: Option<NonZeroUsize>,
+                ) -> (&str, Range<usize>) {
+                    <str as StrTools>::longest_unique_substr(self, max)
+                }
+
+                fn edit_distance(&self, other: &str) -> usize {
+                    <str as StrTools>::edit_distance(self, other)
+                }
+
+                fn similarity(&self, other: &str) -> f64 {
+                    <str as StrTools>::similarity(self, other)
+                }
+
+                fn is_blank(&self) -> bool {
+                    <str as StrTools>::is_blank(self)
+                }
+
+                fn is_numeric_like(&self) -> bool {
+                    <str as StrTools>::is_numeric_like(self)
+                }
+
+                fn is_identifier(&self) -> bool {
+                    <str as StrTools>::is_identifier(self)
+                }
+
+                fn is_ascii_printable(&self) -> bool {
+                    <str as StrTools>::is_ascii_printable(self)
+                }
+
+                fn dedent(&self) -> Cow<'_, str> {
+                    <str as StrTools>::dedent(self)
+                }
+
+                fn indent(&self, prefix: &str) -> Cow<'_, str> {
+                    <str as StrTools>::indent(self, prefix)
+                }
+
+                fn truncate_ellipsis(&self, max_width: usize, ellipsis: &str) -> Cow<'_, str> {
+                    <str as StrTools>::truncate_ellipsis(self, max_width, ellipsis)
+                }
+
+                fn collapse_whitespace(&self) -> Cow<'_, str> {
+                    <str as StrTools>::collapse_whitespace(self)
+                }
+
+                fn squeeze(&self, ch: char) -> Cow<'_, str> {
+                    <str as StrTools>::squeeze(self, ch)
+                }
+
+                fn squeeze_charset<C: CharMembership + ?Sized>(&self, set: &C) -> Cow<'_, str> {
+                    <str as StrTools>::squeeze_charset(self, set)
+                }
+
+                fn pad_left(&self, width: usize, fill: char) -> Cow<'_, str> {
+                    <str as StrTools>::pad_left(self, width, fill)
+                }
+
+                fn pad_right(&self, width: usize, fill: char) -> Cow<'_, str> {
+                    <str as StrTools>::pad_right(self, width, fill)
+                }
+
+                fn center(&self, width: usize, fill: char) -> Cow<'_, str> {
+                    <str as StrTools>::center(self, width, fill)
+                }
+
+                fn normalize_newlines(&self) -> Cow<'_, str> {
+                    <str as StrTools>::normalize_newlines(self)
+                }
+
+                fn normalize_newlines_to(&self, ending: LineEnding) -> Cow<'_, str> {
+                    <str as StrTools>::normalize_newlines_to(self, ending)
+                }
+
+                fn parse_front<T: FromStrFront>(&self) -> Result<(T, &str), T::Error> {
+                    <str as StrTools>::parse_front(self)
+                }
+
+                fn parse_back<T: FromStrBack>(&self) -> Result<(T, &str), T::Error> {
+                    <str as StrTools>::parse_back(self)
+                }
+
+                fn parse_front_radix<T: FromStrPartialRadixExt>(
+                    &self,
+                    radix: u32,
+                ) -> Result<(T, &str), <T as FromStrFront>::Error> {
+                    <str as StrTools>::parse_front_radix(self, radix)
+                }
+
+                fn parse_back_radix<T: FromStrPartialRadixExt>(
+                    &self,
+                    radix: u32,
+                ) -> Result<(T, &str), <T as FromStrBack>::Error> {
+                    <str as StrTools>::parse_back_radix(self, radix)
+                }
+
+                fn parse_front_with<T, E, F>(&self, parser: F) -> Result<(T, &str), E>
+                where
+                    F: FnOnce(&str) -> Result<(T, &str), E>,
+                {
+                    <str as StrTools>::parse_front_with(self, parser)
+                }
+            }
+        )+
+    };
+}
+
+impl_delegated! {
+    String => |this| this.as_mut_str(),
+    Cow<'_, str> => |this| this.to_mut().as_mut_str(),
+}
+
+/// A companion trait to [`StrTools`] providing in-place operations on [`String`], these avoid
+/// allocating a new [`String`] when the caller already owns the buffer. It is not implemented for
+/// `&mut str` as all operations may change the length of the input. **The methods on this trait
+/// are subject to change during the development of the crates core functionality.**
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{util::sorted, StrToolsMut};
+///
+/// let mut input = String::from(r"a\:b:c");
+/// input.sanitize_in_place('\\', ':')?;
+/// assert_eq!(input, "a:b:c");
+///
+/// input.escape_charset_in_place('\\', &sorted![':']);
+/// assert_eq!(input, r"a\:b\:c");
+/// # Ok(())
+/// # }
+/// ```
+pub trait StrToolsMut: util::sealed::Sealed {
+    /// Removes escapes before significant chars like [`StrTools::split_non_escaped_sanitize`]
+    /// without splitting, significant chars are the delimiters and the escape itself. Trailing
+    /// escapes are ignored as if followed by a non-significant char.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `esc == delim`
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the delimiters, ignoring the memmoves of removing escapes.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::StrToolsMut;
+    ///
+    /// let mut input = String::from(r"Pa\rt0:Part1\:\\Part2\");
+    /// input.sanitize_in_place('\\', ':')?;
+    /// assert_eq!(input, r"Pa\rt0:Part1:\Part2\");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn sanitize_in_place<D: Into<Sorted<char, N>>, const N: usize>(
+        &mut self,
+        esc: char,
+        delims: D,
+    ) -> Result<(), split::NonEscapedError>;
+
+    /// Escapes all chars in `charset` and the `escape` itself, like [`escape::charset_with`] but in
+    /// place.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * c)` time where `n` is the length of the input string and `c`
+    /// is the cost of a membership check, ignoring the memmoves of inserting escapes.
+    ///
+    /// # Allocation
+    /// The [`String`] may grow to fit the inserted escapes.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::{util::sorted, StrToolsMut};
+    ///
+    /// let mut input = String::from("abcdefg");
+    /// input.escape_charset_in_place('\\', &sorted!['a', 'e']);
+    /// assert_eq!(input, r"\abcd\efg");
+    /// ```
+    fn escape_charset_in_place<C: CharMembership + ?Sized>(&mut self, escape: char, charset: &C);
+
+    /// Shortens the [`String`] to at most `max_len` bytes, unlike [`String::truncate`] this does
+    /// not panic if `max_len` is not on a char boundary, instead the char containing it is
+    /// removed as well. Has no effect if `max_len` is greater than the length of the [`String`].
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::StrToolsMut;
+    ///
+    /// let mut input = String::from("aöb");
+    /// input.truncate_to_char_boundary(2);
+    /// assert_eq!(input, "a");
+    ///
+    /// input.truncate_to_char_boundary(10);
+    /// assert_eq!(input, "a");
+    /// ```
+    fn truncate_to_char_boundary(&mut self, max_len: usize);
+
+    /// Retains only the chars contained in `set`, see [`StrTools::trim_charset`] for the
+    /// accepted sets.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n * max(log m, 1))` time where `n` is the length of the input
+    /// string and `m` is the length of the set.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::{util::sorted, StrToolsMut};
+    ///
+    /// let mut input = String::from("+49 (030) 123-456");
+    /// input.retain_charset(&sorted!['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']);
+    /// assert_eq!(input, "49030123456");
+    /// ```
+    fn retain_charset<C: CharMembership + ?Sized>(&mut self, set: &C);
+}
+
+impl StrToolsMut for String {
+    fn sanitize_in_place<D: Into<Sorted<char, N>>, const N: usize>(
+        &mut self,
+        esc: char,
+        delims: D,
+    ) -> Result<(), split::NonEscapedError> {
+        let delims = delims.into();
+        if delims.contains_char(esc) {
+            return Err(split::NonEscapedError::EscapeContainsDelimiter(esc));
+        }
+
+        let mut idx = 0;
+        while let Some(ch) = self[idx..].chars().next() {
+            if ch == esc
+                && let Some(escaped) = self[idx + ch.len_utf8()..].chars().next()
+            {
+                if escaped == esc || delims.contains_char(escaped) {
+                    self.remove(idx);
+                } else {
+                    idx += ch.len_utf8();
+                }
+
+                idx += escaped.len_utf8();
+            } else {
+                idx += ch.len_utf8();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn escape_charset_in_place<C: CharMembership + ?Sized>(&mut self, escape: char, charset: &C) {
+        let mut idx = 0;
+        while let Some(ch) = self[idx..].chars().next() {
+            if ch == escape || charset.contains_char(ch) {
+                self.insert(idx, escape);
+                idx += escape.len_utf8();
+            }
+
+            idx += ch.len_utf8();
+        }
+    }
+
+    fn truncate_to_char_boundary(&mut self, max_len: usize) {
+        if max_len < self.len() {
+            let mut end = max_len;
+            while !self.is_char_boundary(end) {
+                end -= 1;
+            }
+
+            self.truncate(end);
+        }
+    }
+
+    fn retain_charset<C: CharMembership + ?Sized>(&mut self, set: &C) {
+        self.retain(|ch| set.contains_char(ch));
     }
 }
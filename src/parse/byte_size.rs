@@ -0,0 +1,186 @@
+use super::{forward, num::scan_digits_front, FromStrFront, ParseErrorKind, PartialParseError};
+
+/// An [`Error`][0] for [`FromStrFront`] on [`ByteSize`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseByteSizeError {
+    /// The input was empty.
+    #[error("empty input, expected: `['0' - '9']+ ['.' ['0' - '9']+]? [<unit>]`")]
+    Empty,
+
+    /// The input did not start with a number.
+    #[error("invalid input, expected: `['0' - '9']+ ['.' ['0' - '9']+]? [<unit>]`")]
+    Invalid,
+
+    /// The size would not fit into a [`u64`].
+    #[error("the given size would cause overflow")]
+    Overflow,
+}
+
+impl PartialParseError for ParseByteSizeError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Empty => ParseErrorKind::Insufficient,
+            Self::Invalid => ParseErrorKind::Invalid,
+            Self::Overflow => ParseErrorKind::Overflow,
+        }
+    }
+}
+
+/// A size in bytes, parsed from a number with an optional unit like `512`, `10K`, `4kB` or
+/// `1.5MiB`. Decimal units (`K`, `KB`, `M`, `MB`, ...) are powers of 1000, binary units (`Ki`,
+/// `KiB`, `Mi`, `MiB`, ...) are powers of 1024, units up to exa bytes are supported and are
+/// matched ignoring case. Fractional sizes are truncated to whole bytes.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{ByteSize, FromStrFront};
+///
+/// assert_eq!(ByteSize::from_str_front("10K"), Ok((ByteSize(10_000), "")));
+/// assert_eq!(ByteSize::from_str_front("1.5MiB free"), Ok((ByteSize(1_572_864), " free")));
+/// assert_eq!(ByteSize::from_str_front("512"), Ok((ByteSize(512), "")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSize(pub u64);
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> Self {
+        size.0
+    }
+}
+
+/// Strips an optional unit from the start of `input` and returns the rest and the unit's factor.
+fn unit_front(input: &str) -> (&str, u128) {
+    let mut rest = input;
+    let mut factor = 1;
+
+    if let Some(exp) = rest
+        .chars()
+        .next()
+        .and_then(|ch| "KMGTPE".find(ch.to_ascii_uppercase()))
+    {
+        rest = &rest[1..];
+        let base: u128 = match rest.strip_prefix(['i', 'I']) {
+            Some(after) => {
+                rest = after;
+                1024
+            }
+            None => 1000,
+        };
+        factor = base.pow(exp as u32 + 1);
+    }
+
+    (rest.strip_prefix(['b', 'B']).unwrap_or(rest), factor)
+}
+
+// fractional digits past this are consumed but ignored, this keeps the computation from
+// overflowing and is well below byte precision for all units
+const MAX_FRAC_DIGITS: usize = 19;
+
+impl FromStrFront for ByteSize {
+    type Error = ParseByteSizeError;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        if input.is_empty() {
+            return Err(ParseByteSizeError::Empty);
+        }
+
+        let int_len = scan_digits_front(input, 10, None);
+        if int_len == 0 {
+            return Err(ParseByteSizeError::Invalid);
+        }
+
+        let int: u128 = input[..int_len]
+            .parse::<u64>()
+            .map_err(|_| ParseByteSizeError::Overflow)?
+            .into();
+
+        let mut rest = &input[int_len..];
+        let mut frac = "";
+        if let Some(after) = rest.strip_prefix('.') {
+            let frac_len = scan_digits_front(after, 10, None);
+            if frac_len != 0 {
+                frac = &after[..frac_len];
+                rest = &after[frac_len..];
+            }
+        }
+
+        let (rest, factor) = unit_front(rest);
+
+        let frac = &frac[..frac.len().min(MAX_FRAC_DIGITS)];
+        let frac_bytes = match frac.parse::<u128>() {
+            Ok(digits) => digits * factor / 10u128.pow(frac.len() as u32),
+            Err(_) => 0,
+        };
+
+        int.checked_mul(factor)
+            .and_then(|bytes| bytes.checked_add(frac_bytes))
+            .and_then(|bytes| u64::try_from(bytes).ok())
+            .map(|bytes| (Self(bytes), rest))
+            .ok_or(ParseByteSizeError::Overflow)
+    }
+}
+
+forward!(front for ByteSize; |_, _| ParseByteSizeError::Invalid);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn units() {
+        assert_eq!(ByteSize::from_str_front("1"), Ok((ByteSize(1), "")));
+        assert_eq!(ByteSize::from_str_front("1B"), Ok((ByteSize(1), "")));
+        assert_eq!(ByteSize::from_str_front("1kb"), Ok((ByteSize(1_000), "")));
+        assert_eq!(ByteSize::from_str_front("1Ki"), Ok((ByteSize(1_024), "")));
+        assert_eq!(
+            ByteSize::from_str_front("2GB"),
+            Ok((ByteSize(2_000_000_000), ""))
+        );
+        assert_eq!(
+            ByteSize::from_str_front("2GiB"),
+            Ok((ByteSize(2 << 30), ""))
+        );
+        assert_eq!(
+            ByteSize::from_str_front("1TiB"),
+            Ok((ByteSize(1 << 40), ""))
+        );
+        assert_eq!(
+            ByteSize::from_str_front("1E"),
+            Ok((ByteSize(10u64.pow(18)), ""))
+        );
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(ByteSize::from_str_front("0.5K"), Ok((ByteSize(500), "")));
+        assert_eq!(ByteSize::from_str_front("0.5B"), Ok((ByteSize(0), "")));
+        assert_eq!(ByteSize::from_str_front("1. MB"), Ok((ByteSize(1), ". MB")));
+    }
+
+    #[test]
+    fn rest() {
+        assert_eq!(
+            ByteSize::from_str_front("4kBps"),
+            Ok((ByteSize(4_000), "ps"))
+        );
+        assert_eq!(ByteSize::from_str_front("4 kB"), Ok((ByteSize(4), " kB")));
+        assert_eq!(ByteSize::from_str_front("4x"), Ok((ByteSize(4), "x")));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(ByteSize::from_str_front(""), Err(ParseByteSizeError::Empty));
+        assert_eq!(
+            ByteSize::from_str_front("K"),
+            Err(ParseByteSizeError::Invalid)
+        );
+        assert_eq!(
+            ByteSize::from_str_front("16EiB"),
+            Err(ParseByteSizeError::Overflow)
+        );
+        assert_eq!("1K!".parse::<ByteSize>(), Err(ParseByteSizeError::Invalid));
+        assert_eq!("1K".parse::<ByteSize>(), Ok(ByteSize(1_000)));
+    }
+}
@@ -0,0 +1,228 @@
+use super::{FromStrBack, FromStrFront, ParseBoolError, ParseIntPartialError};
+use std::str;
+
+/// Types that may try parsing from the beginning of a byte string, which may not be valid UTF-8.
+/// This mirrors [`FromStrFront`], see it's documentation for more info.
+pub trait FromBytesFront: Sized {
+    /// The [`Error`][0] that is returned if parsing fails.
+    ///
+    /// [0]: std::error::Error
+    type Error;
+
+    /// Attempts to parse `Self` from the beginning of the byte string, returns the rest of the
+    /// `input` and `Self` if parsing succeeded.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the start of `input` doesn't contain any valid representation of `Self`
+    /// - `input` doesn't contain a complete representation of `Self`
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromBytesFront;
+    ///
+    /// assert_eq!(u8::from_bytes_front(b"123\xff"), Ok((123, &b"\xff"[..])));
+    /// ```
+    fn from_bytes_front(input: &[u8]) -> Result<(Self, &[u8]), Self::Error>;
+
+    /// Removes the prefix of the given byte string in place if parsing succeeds, see
+    /// [`FromStrFront::yield_front`] for more info.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the start of `input` doesn't contain any valid representation of `Self`
+    /// - `input` doesn't contain a complete representation of `Self`
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromBytesFront;
+    ///
+    /// let mut input = &b"1-2\x80"[..];
+    ///
+    /// assert_eq!(u8::yield_bytes_front(&mut input), Ok(1));
+    /// assert_eq!(i8::yield_bytes_front(&mut input), Ok(-2));
+    /// assert_eq!(input, b"\x80");
+    /// ```
+    #[inline]
+    fn yield_bytes_front(input: &mut &[u8]) -> Result<Self, Self::Error> {
+        let (result, rest) = Self::from_bytes_front(input)?;
+        *input = rest;
+        Ok(result)
+    }
+}
+
+/// Types that may try parsing from the end of a byte string, which may not be valid UTF-8. This
+/// mirrors [`FromStrBack`], see it's documentation for more info.
+pub trait FromBytesBack: Sized {
+    /// The [`Error`][0] that is returned if parsing fails.
+    ///
+    /// [0]: std::error::Error
+    type Error;
+
+    /// Attempts to parse `Self` from the end of the byte string, returns the rest of the `input`
+    /// and `Self` if parsing succeeded.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the end of `input` doesn't contain any valid representation of `Self`
+    /// - `input` doesn't contain a complete representation of `Self`
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromBytesBack;
+    ///
+    /// assert_eq!(u8::from_bytes_back(b"\xff123"), Ok((123, &b"\xff"[..])));
+    /// ```
+    fn from_bytes_back(input: &[u8]) -> Result<(Self, &[u8]), Self::Error>;
+
+    /// Removes the suffix of the given byte string in place if parsing succeeds, see
+    /// [`FromStrBack::yield_back`] for more info.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the end of `input` doesn't contain any valid representation of `Self`
+    /// - `input` doesn't contain a complete representation of `Self`
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromBytesBack;
+    ///
+    /// let mut input = &b"\x80true1"[..];
+    ///
+    /// assert_eq!(u8::yield_bytes_back(&mut input), Ok(1));
+    /// assert_eq!(bool::yield_bytes_back(&mut input), Ok(true));
+    /// assert_eq!(input, b"\x80");
+    /// ```
+    #[inline]
+    fn yield_bytes_back(input: &mut &[u8]) -> Result<Self, Self::Error> {
+        let (result, rest) = Self::from_bytes_back(input)?;
+        *input = rest;
+        Ok(result)
+    }
+}
+
+/// Returns the longest ASCII prefix of `input` as a [`str`].
+fn ascii_prefix(input: &[u8]) -> &str {
+    let len = input.iter().take_while(|byte| byte.is_ascii()).count();
+
+    // ASCII is always valid UTF-8
+    str::from_utf8(&input[..len]).expect("ASCII is valid UTF-8")
+}
+
+/// Returns the longest ASCII suffix of `input` as a [`str`].
+fn ascii_suffix(input: &[u8]) -> &str {
+    let len = input
+        .iter()
+        .rev()
+        .take_while(|byte| byte.is_ascii())
+        .count();
+
+    // ASCII is always valid UTF-8
+    str::from_utf8(&input[input.len() - len..]).expect("ASCII is valid UTF-8")
+}
+
+/// Errors of types with pure ASCII representations, used to map errors caused by the non-ASCII
+/// bytes that were cut off before parsing.
+trait AsciiError {
+    /// Maps an error that occurred on an ASCII prefix or suffix that was followed or preceded by
+    /// non-ASCII bytes to the error the same input would cause as a [`str`].
+    fn truncated(self) -> Self;
+}
+
+impl AsciiError for ParseIntPartialError {
+    fn truncated(self) -> Self {
+        // the input only ran out because a non-ASCII byte was where a digit was expected
+        match self {
+            Self::Empty => Self::Invalid,
+            err => err,
+        }
+    }
+}
+
+impl AsciiError for ParseBoolError {
+    fn truncated(self) -> Self {
+        self
+    }
+}
+
+/// Maps `err` with [`AsciiError::truncated`] if `ascii` is shorter than `input`.
+fn map_truncated<E: AsciiError>(err: E, ascii: &str, input: &[u8]) -> E {
+    if ascii.len() < input.len() {
+        err.truncated()
+    } else {
+        err
+    }
+}
+
+// the representations of these types are pure ASCII, so parsing the ASCII part of the input as a
+// str is equivalent to parsing the bytes directly
+macro_rules! ascii_impl {
+    ($error:ty; $($type:ty),+) => {
+        $(
+            impl FromBytesFront for $type {
+                type Error = $error;
+
+                fn from_bytes_front(input: &[u8]) -> Result<(Self, &[u8]), Self::Error> {
+                    let ascii = ascii_prefix(input);
+                    let (value, rest) = <$type as FromStrFront>::from_str_front(ascii)
+                        .map_err(|err| map_truncated(err, ascii, input))?;
+                    Ok((value, &input[ascii.len() - rest.len()..]))
+                }
+            }
+
+            impl FromBytesBack for $type {
+                type Error = $error;
+
+                fn from_bytes_back(input: &[u8]) -> Result<(Self, &[u8]), Self::Error> {
+                    let ascii = ascii_suffix(input);
+                    let (value, rest) = <$type as FromStrBack>::from_str_back(ascii)
+                        .map_err(|err| map_truncated(err, ascii, input))?;
+                    Ok((value, &input[..input.len() - ascii.len() + rest.len()]))
+                }
+            }
+        )+
+    };
+}
+
+ascii_impl!(ParseIntPartialError; i8, i16, i32, i64, i128, isize);
+ascii_impl!(ParseIntPartialError; u8, u16, u32, u64, u128, usize);
+ascii_impl!(ParseBoolError; bool);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn front() {
+        assert_eq!(u8::from_bytes_front(b"12a"), Ok((12, &b"a"[..])));
+        assert_eq!(i16::from_bytes_front(b"-300\xc3"), Ok((-300, &b"\xc3"[..])));
+        assert_eq!(
+            u8::from_bytes_front(b"\xff12"),
+            Err(ParseIntPartialError::Invalid)
+        );
+        assert_eq!(
+            i8::from_bytes_front(b"-\xff"),
+            Err(ParseIntPartialError::Invalid)
+        );
+        assert_eq!(i8::from_bytes_front(b"-"), Err(ParseIntPartialError::Empty));
+        assert_eq!(u8::from_bytes_front(b""), Err(ParseIntPartialError::Empty));
+        assert_eq!(
+            u8::from_bytes_front(b"256"),
+            Err(ParseIntPartialError::Overflow)
+        );
+        assert_eq!(bool::from_bytes_front(b"false!"), Ok((false, &b"!"[..])));
+    }
+
+    #[test]
+    fn back() {
+        assert_eq!(u8::from_bytes_back(b"a12"), Ok((12, &b"a"[..])));
+        assert_eq!(i16::from_bytes_back(b"\xc3-300"), Ok((-300, &b"\xc3"[..])));
+        assert_eq!(
+            u8::from_bytes_back(b"12\xff"),
+            Err(ParseIntPartialError::Invalid)
+        );
+        assert_eq!(u8::from_bytes_back(b""), Err(ParseIntPartialError::Empty));
+        assert_eq!(bool::from_bytes_back(b"\xfftrue"), Ok((true, &b"\xff"[..])));
+        assert_eq!(bool::from_bytes_back(b"x"), Err(ParseBoolError));
+    }
+}
@@ -0,0 +1,194 @@
+use super::{forward, FromStrFront, ParseErrorKind, PartialParseError};
+
+/// An [`Error`][0] for the [`FromStrFront`] implementation of [`Color`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The input did not start with a `#`.
+    #[error("invalid input, expected: `'#' <hex digits>`")]
+    MissingHash,
+
+    /// The hex digits were directly followed by a non hex digit alphanumeric char.
+    #[error("invalid hex digit {0:?}")]
+    InvalidDigit(char),
+
+    /// The number of hex digits was not one of 3, 4, 6 or 8.
+    #[error("invalid number of hex digits {0}, expected 3, 4, 6 or 8")]
+    InvalidLength(usize),
+
+    /// The input was empty.
+    #[error("empty input, expected: `'#' <hex digits>`")]
+    Empty,
+}
+
+impl PartialParseError for ParseColorError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::MissingHash | Self::InvalidDigit(_) | Self::InvalidLength(_) => {
+                ParseErrorKind::Invalid
+            }
+            Self::Empty => ParseErrorKind::Insufficient,
+        }
+    }
+}
+
+/// An RGBA color parsed from `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`, the alpha channel is
+/// `0xff` if not given.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{Color, FromStrFront};
+///
+/// assert_eq!(
+///     Color::from_str_front("#ff8000 orange"),
+///     Ok((Color { r: 0xff, g: 0x80, b: 0x00, a: 0xff }, " orange"))
+/// );
+/// assert_eq!(
+///     Color::from_str_front("#f008"),
+///     Ok((Color { r: 0xff, g: 0x00, b: 0x00, a: 0x88 }, ""))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// The red channel.
+    pub r: u8,
+
+    /// The green channel.
+    pub g: u8,
+
+    /// The blue channel.
+    pub b: u8,
+
+    /// The alpha channel.
+    pub a: u8,
+}
+
+impl Color {
+    /// Returns the channels of this color as `[r, g, b, a]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::Color;
+    ///
+    /// let color: Color = "#123".parse()?;
+    /// assert_eq!(color.to_array(), [0x11, 0x22, 0x33, 0xff]);
+    /// # Ok::<_, strtools::parse::ParseColorError>(())
+    /// ```
+    pub const fn to_array(self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+impl From<Color> for [u8; 4] {
+    fn from(color: Color) -> Self {
+        color.to_array()
+    }
+}
+
+impl FromStrFront for Color {
+    type Error = ParseColorError;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        if input.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+
+        let digits = input
+            .strip_prefix('#')
+            .ok_or(ParseColorError::MissingHash)?;
+
+        // hex digits are always single byte chars
+        let len = digits.bytes().take_while(u8::is_ascii_hexdigit).count();
+        let rest = &digits[len..];
+
+        if let Some(ch) = rest.chars().next()
+            && ch.is_alphanumeric()
+        {
+            return Err(ParseColorError::InvalidDigit(ch));
+        }
+
+        let nibbles = digits[..len].bytes().map(|byte| match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'a'..=b'f' => byte - b'a' + 10,
+            _ => byte - b'A' + 10,
+        });
+
+        let mut channels = [0xff; 4];
+        match len {
+            3 | 4 => {
+                for (channel, nibble) in channels.iter_mut().zip(nibbles) {
+                    *channel = nibble << 4 | nibble;
+                }
+            }
+            6 | 8 => {
+                let mut nibbles = nibbles;
+                for channel in channels.iter_mut().take(len / 2) {
+                    // the length is even, there are always two nibbles left
+                    if let (Some(high), Some(low)) = (nibbles.next(), nibbles.next()) {
+                        *channel = high << 4 | low;
+                    }
+                }
+            }
+            _ => return Err(ParseColorError::InvalidLength(len)),
+        }
+
+        let [r, g, b, a] = channels;
+        Ok((Self { r, g, b, a }, rest))
+    }
+}
+
+forward!(front for Color; |_, rest| {
+    // the rest is never empty in this case
+    ParseColorError::InvalidDigit(rest.chars().next().unwrap_or_default())
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn color(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color { r, g, b, a }
+    }
+
+    #[test]
+    fn lengths() {
+        assert_eq!(
+            Color::from_str_front("#abc"),
+            Ok((color(0xaa, 0xbb, 0xcc, 0xff), ""))
+        );
+        assert_eq!(
+            Color::from_str_front("#ABCD"),
+            Ok((color(0xaa, 0xbb, 0xcc, 0xdd), ""))
+        );
+        assert_eq!(
+            Color::from_str_front("#123456;"),
+            Ok((color(0x12, 0x34, 0x56, 0xff), ";"))
+        );
+        assert_eq!(
+            Color::from_str_front("#12345678 "),
+            Ok((color(0x12, 0x34, 0x56, 0x78), " "))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Color::from_str_front(""), Err(ParseColorError::Empty));
+        assert_eq!(
+            Color::from_str_front("123"),
+            Err(ParseColorError::MissingHash)
+        );
+        assert_eq!(
+            Color::from_str_front("#12345"),
+            Err(ParseColorError::InvalidLength(5))
+        );
+        assert_eq!(
+            Color::from_str_front("#12g"),
+            Err(ParseColorError::InvalidDigit('g'))
+        );
+        assert_eq!(
+            "#123 ".parse::<Color>(),
+            Err(ParseColorError::InvalidDigit(' '))
+        );
+    }
+}
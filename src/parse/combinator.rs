@@ -0,0 +1,275 @@
+//! This module contains small combinators for parsers in the style of
+//! [`FromStrFront::yield_front`][0], a parser is any `FnMut(&mut &str) -> Result<T, E>` that removes
+//! what it parsed from the front of the input. All parsers returned from this module leave the
+//! input untouched if they fail.
+//!
+//! # Examples
+//! ```
+//! use strtools::parse::{combinator, FromStrFront};
+//!
+//! let mut input = "1,2,3;true";
+//!
+//! let mut list = combinator::separated(u8::yield_front, ",");
+//! assert_eq!(list(&mut input), Ok(vec![1, 2, 3]));
+//! assert_eq!(input, ";true");
+//!
+//! let mut number = combinator::opt(u8::yield_front);
+//! assert_eq!(number(&mut input), Ok(None));
+//! assert_eq!(input, ";true");
+//! ```
+//!
+//! [0]: super::FromStrFront::yield_front
+
+use super::yield_literal_front;
+
+/// A set of alternative parsers, see [`alt`] for more info. This is implemented for tuples of up
+/// to 8 parsers with the same output and error type.
+pub trait Alt<T, E> {
+    /// Tries each parser in order and returns the first success, or the error of the last parser
+    /// if all of them failed.
+    ///
+    /// # Errors
+    /// Returns the error of the last parser if all parsers failed.
+    fn choice(&mut self, input: &mut &str) -> Result<T, E>;
+}
+
+macro_rules! alt_impl {
+    ($($parser:ident)+; $last:ident) => {
+        impl<T, E, $($parser,)+ $last> Alt<T, E> for ($($parser,)+ $last,)
+        where
+            $($parser: FnMut(&mut &str) -> Result<T, E>,)+
+            $last: FnMut(&mut &str) -> Result<T, E>,
+        {
+            #[allow(non_snake_case)]
+            fn choice(&mut self, input: &mut &str) -> Result<T, E> {
+                let ($($parser,)+ $last,) = self;
+                let start = *input;
+
+                $(
+                    if let Ok(value) = $parser(input) {
+                        return Ok(value);
+                    }
+                    *input = start;
+                )+
+
+                $last(input).inspect_err(|_| *input = start)
+            }
+        }
+    };
+}
+
+alt_impl!(A; B);
+alt_impl!(A B; C);
+alt_impl!(A B C; D);
+alt_impl!(A B C D; F);
+alt_impl!(A B C D F; G);
+alt_impl!(A B C D F G; H);
+alt_impl!(A B C D F G H; I);
+
+/// Returns a parser that tries the given `parsers` in order, returning the first success.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{combinator, FromStrFront};
+///
+/// let mut input = "-3";
+/// let mut parser = combinator::alt((u8::yield_front, |input: &mut &str| {
+///     i8::yield_front(input).map(|int| int.unsigned_abs())
+/// }));
+///
+/// assert_eq!(parser(&mut input), Ok(3));
+/// ```
+pub fn alt<T, E>(mut parsers: impl Alt<T, E>) -> impl FnMut(&mut &str) -> Result<T, E> {
+    move |input| parsers.choice(input)
+}
+
+/// Returns a parser that never fails, yielding [`None`] if the given `parser` failed.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{combinator, FromStrFront};
+///
+/// let mut parser = combinator::opt(u8::yield_front);
+///
+/// assert_eq!(parser(&mut "1"), Ok(Some(1)));
+/// assert_eq!(parser(&mut "a"), Ok(None));
+/// ```
+pub fn opt<T, E>(
+    mut parser: impl FnMut(&mut &str) -> Result<T, E>,
+) -> impl FnMut(&mut &str) -> Result<Option<T>, E> {
+    move |input| {
+        let start = *input;
+        match parser(input) {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => {
+                *input = start;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Applies `parser` until it fails or stops consuming input.
+fn many<T, E>(
+    parser: &mut impl FnMut(&mut &str) -> Result<T, E>,
+    input: &mut &str,
+    values: &mut Vec<T>,
+) {
+    loop {
+        let start = *input;
+        match parser(input) {
+            Ok(value) if input.len() < start.len() => values.push(value),
+            Ok(value) => {
+                // the parser didn't consume anything, it would yield this forever
+                values.push(value);
+                break;
+            }
+            Err(_) => {
+                *input = start;
+                break;
+            }
+        }
+    }
+}
+
+/// Returns a parser that never fails, applying the given `parser` as often as possible. Stops
+/// after the first success that didn't consume any input.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{combinator, FromStrFront};
+///
+/// let mut input = "truefalse!";
+/// let mut parser = combinator::many0(bool::yield_front);
+///
+/// assert_eq!(parser(&mut input), Ok(vec![true, false]));
+/// assert_eq!(parser(&mut input), Ok(vec![]));
+/// assert_eq!(input, "!");
+/// ```
+pub fn many0<T, E>(
+    mut parser: impl FnMut(&mut &str) -> Result<T, E>,
+) -> impl FnMut(&mut &str) -> Result<Vec<T>, E> {
+    move |input| {
+        let mut values = vec![];
+        many(&mut parser, input, &mut values);
+        Ok(values)
+    }
+}
+
+/// Returns a parser that applies the given `parser` as often as possible, but at least once. Stops
+/// after the first success that didn't consume any input.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{combinator, FromStrFront};
+///
+/// let mut parser = combinator::many1(bool::yield_front);
+///
+/// assert_eq!(parser(&mut "truefalse!"), Ok(vec![true, false]));
+/// assert!(parser(&mut "!").is_err());
+/// ```
+pub fn many1<T, E>(
+    mut parser: impl FnMut(&mut &str) -> Result<T, E>,
+) -> impl FnMut(&mut &str) -> Result<Vec<T>, E> {
+    move |input| {
+        let start = *input;
+        let first = parser(input).inspect_err(|_| *input = start)?;
+
+        let mut values = vec![first];
+        if input.len() < start.len() {
+            many(&mut parser, input, &mut values);
+        }
+
+        Ok(values)
+    }
+}
+
+/// Returns a parser that applies the given `parser` at least once, separated by `delim`. A trailing
+/// `delim` which is not followed by a successful parse is not consumed.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{combinator, FromStrFront};
+///
+/// let mut input = "1, 2, 3, rest";
+/// let mut parser = combinator::separated(u8::yield_front, ", ");
+///
+/// assert_eq!(parser(&mut input), Ok(vec![1, 2, 3]));
+/// assert_eq!(input, ", rest");
+/// ```
+pub fn separated<'d, T: 'd, E: 'd>(
+    mut parser: impl FnMut(&mut &str) -> Result<T, E> + 'd,
+    delim: &'d str,
+) -> impl FnMut(&mut &str) -> Result<Vec<T>, E> + 'd {
+    move |input| {
+        let start = *input;
+        let mut values = vec![parser(input).inspect_err(|_| *input = start)?];
+
+        loop {
+            let before_delim = *input;
+            if !yield_literal_front(input, delim) {
+                break;
+            }
+
+            match parser(input) {
+                Ok(value) => values.push(value),
+                Err(_) => {
+                    *input = before_delim;
+                    break;
+                }
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{FromStrFront, ParseIntPartialError};
+
+    #[test]
+    fn alt_restores() {
+        let mut input = "12a";
+        let mut parser = alt((
+            |input: &mut &str| {
+                u8::yield_front(input)?;
+                Err(ParseIntPartialError::Invalid)
+            },
+            |input: &mut &str| u8::yield_front(input).map(|int| int * 2),
+        ));
+
+        assert_eq!(parser(&mut input), Ok(24));
+        assert_eq!(input, "a");
+        assert_eq!(parser(&mut input), Err(ParseIntPartialError::Invalid));
+        assert_eq!(input, "a");
+    }
+
+    #[test]
+    fn many_non_consuming() {
+        let mut input = "abc";
+        let mut parser = many0(|_: &mut &str| Ok::<_, ()>(()));
+        assert_eq!(parser(&mut input), Ok(vec![()]));
+        assert_eq!(input, "abc");
+    }
+
+    #[test]
+    fn many1_restores() {
+        let mut input = "a";
+        let mut parser = many1(u8::yield_front);
+        assert_eq!(parser(&mut input), Err(ParseIntPartialError::Invalid));
+        assert_eq!(input, "a");
+    }
+
+    #[test]
+    fn separated_trailing() {
+        let mut input = "1,2,";
+        let mut parser = separated(u8::yield_front, ",");
+        assert_eq!(parser(&mut input), Ok(vec![1, 2]));
+        assert_eq!(input, ",");
+
+        let mut input = "";
+        assert_eq!(parser(&mut input), Err(ParseIntPartialError::Empty));
+    }
+}
@@ -0,0 +1,476 @@
+//! This module contains simple structs for the common subset of ISO-8601 dates, times and
+//! timestamps and their [`FromStrFront`]/[`FromStrBack`] implementations.
+//!
+//! # Examples
+//! ```
+//! use strtools::parse::{datetime::{Date, DateTime, Time}, FromStrFront};
+//!
+//! let (timestamp, rest) = DateTime::from_str_front("2022-07-09T13:37:00.250Z: started")?;
+//! assert_eq!(timestamp.date, Date { year: 2022, month: 7, day: 9 });
+//! assert_eq!(timestamp.time, Time { hour: 13, minute: 37, second: 0, nanosecond: 250_000_000 });
+//! assert_eq!(timestamp.offset, Some(0));
+//! assert_eq!(rest, ": started");
+//! # Ok::<_, strtools::parse::datetime::ParseDateTimeError>(())
+//! ```
+
+use super::{
+    forward, from_str_back_via_front, FromStrBack, FromStrFront, ParseErrorKind, PartialParseError,
+};
+use std::time::{Duration, SystemTime};
+
+/// An [`Error`][0] for [`FromStrFront`]/[`FromStrBack`] implementations of [`Date`], [`Time`] and
+/// [`DateTime`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseDateTimeError {
+    /// The input did not match the expected format.
+    #[error("invalid input, expected: `YYYY-MM-DD`, `HH:MM:SS[.f+]` or a combination of both")]
+    Invalid,
+
+    /// A component was outside of it's valid range, like the 13th month.
+    #[error("a component of the date or time was out of range")]
+    OutOfRange,
+
+    /// The input was empty.
+    #[error("empty input, expected: `YYYY-MM-DD`, `HH:MM:SS[.f+]` or a combination of both")]
+    Empty,
+}
+
+impl PartialParseError for ParseDateTimeError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Invalid | Self::OutOfRange => ParseErrorKind::Invalid,
+            Self::Empty => ParseErrorKind::Insufficient,
+        }
+    }
+}
+
+/// A calendar date in the `YYYY-MM-DD` format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The year, always 4 digits.
+    pub year: u16,
+
+    /// The month in `[1, 12]`.
+    pub month: u8,
+
+    /// The day of the month in `[1, 31]`, depending on the month.
+    pub day: u8,
+}
+
+/// A time of the day in the `HH:MM:SS[.f+]` format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    /// The hour in `[0, 23]`.
+    pub hour: u8,
+
+    /// The minute in `[0, 59]`.
+    pub minute: u8,
+
+    /// The second in `[0, 59]`.
+    pub second: u8,
+
+    /// The fraction of the second in nanoseconds, digits past nanosecond precision are ignored.
+    pub nanosecond: u32,
+}
+
+/// A timestamp in the `YYYY-MM-DD('T' | ' ')HH:MM:SS[.f+][('Z' | ('+' | '-')HH:MM)]` format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    /// The date of this timestamp.
+    pub date: Date,
+
+    /// The time of this timestamp.
+    pub time: Time,
+
+    /// The offset from UTC in minutes if one was given.
+    pub offset: Option<i16>,
+}
+
+impl DateTime {
+    /// Returns the [`SystemTime`] of this timestamp if it had an offset.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// use strtools::parse::{datetime::DateTime, FromStrFront};
+    ///
+    /// let (timestamp, _) = DateTime::from_str_front("1970-01-01T01:00:00+01:00")?;
+    /// assert_eq!(timestamp.to_system_time(), Some(SystemTime::UNIX_EPOCH));
+    /// # Ok::<_, strtools::parse::datetime::ParseDateTimeError>(())
+    /// ```
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let offset = self.offset?;
+        let secs = days_from_civil(self.date) * 86_400
+            + i64::from(self.time.hour) * 3_600
+            + i64::from(self.time.minute) * 60
+            + i64::from(self.time.second)
+            - i64::from(offset) * 60;
+
+        let since_epoch = Duration::new(secs.unsigned_abs(), 0);
+        let time = if secs < 0 {
+            SystemTime::UNIX_EPOCH.checked_sub(since_epoch)?
+        } else {
+            SystemTime::UNIX_EPOCH.checked_add(since_epoch)?
+        };
+
+        time.checked_add(Duration::from_nanos(self.time.nanosecond.into()))
+    }
+}
+
+/// Returns the number of days since `1970-01-01`, see
+/// <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(date: Date) -> i64 {
+    let (month, day) = (i64::from(date.month), i64::from(date.day));
+    let year = i64::from(date.year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses exactly `n` ascii digits from the start of `input`.
+fn digits(input: &str, n: usize) -> Result<(u32, &str), ParseDateTimeError> {
+    match input.get(..n) {
+        Some(digits) if digits.bytes().all(|byte| byte.is_ascii_digit()) => {
+            // at most 4 digits, this cannot overflow
+            let value = digits
+                .bytes()
+                .fold(0, |acc, byte| acc * 10 + u32::from(byte - b'0'));
+            Ok((value, &input[n..]))
+        }
+        _ => Err(ParseDateTimeError::Invalid),
+    }
+}
+
+fn literal(input: &str, literal: char) -> Result<&str, ParseDateTimeError> {
+    input
+        .strip_prefix(literal)
+        .ok_or(ParseDateTimeError::Invalid)
+}
+
+/// Rejects a value that is directly followed or preceded by a digit, it would have been cut off.
+fn not_cut_off(rest: Option<char>) -> Result<(), ParseDateTimeError> {
+    match rest {
+        Some(ch) if ch.is_ascii_digit() => Err(ParseDateTimeError::Invalid),
+        _ => Ok(()),
+    }
+}
+
+fn date_front(input: &str) -> Result<(Date, &str), ParseDateTimeError> {
+    let (year, rest) = digits(input, 4)?;
+    let (month, rest) = digits(literal(rest, '-')?, 2)?;
+    let (day, rest) = digits(literal(rest, '-')?, 2)?;
+
+    // the digit counts guarantee that these fit
+    let (year, month, day) = (year as u16, month as u8, day as u8);
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return Err(ParseDateTimeError::OutOfRange);
+    }
+
+    Ok((Date { year, month, day }, rest))
+}
+
+fn time_front(input: &str) -> Result<(Time, &str), ParseDateTimeError> {
+    let (hour, rest) = digits(input, 2)?;
+    let (minute, rest) = digits(literal(rest, ':')?, 2)?;
+    let (second, mut rest) = digits(literal(rest, ':')?, 2)?;
+
+    // a `.` without digits is not part of the time
+    let mut nanosecond = 0;
+    if let Some(frac) = rest.strip_prefix('.')
+        && let len = frac.bytes().take_while(u8::is_ascii_digit).count()
+        && len != 0
+    {
+        let (nanos, _) = digits(frac, len.min(9))?;
+        nanosecond = nanos * 10u32.pow(9 - len.min(9) as u32);
+        rest = &frac[len..];
+    }
+
+    if hour > 23 || minute > 59 || second > 59 {
+        return Err(ParseDateTimeError::OutOfRange);
+    }
+
+    // the digit counts guarantee that these fit
+    let time = Time {
+        hour: hour as u8,
+        minute: minute as u8,
+        second: second as u8,
+        nanosecond,
+    };
+
+    Ok((time, rest))
+}
+
+fn offset_front(input: &str) -> Result<(Option<i16>, &str), ParseDateTimeError> {
+    let (sign, rest) = match input.as_bytes() {
+        [b'Z' | b'z', ..] => return Ok((Some(0), &input[1..])),
+        [b'+', ..] => (1, &input[1..]),
+        [b'-', ..] => (-1, &input[1..]),
+        _ => return Ok((None, input)),
+    };
+
+    // a sign without digits is not part of the offset
+    if !rest.starts_with(|ch: char| ch.is_ascii_digit()) {
+        return Ok((None, input));
+    }
+
+    let (hours, rest) = digits(rest, 2)?;
+    let (minutes, rest) = digits(literal(rest, ':')?, 2)?;
+    if hours > 23 || minutes > 59 {
+        return Err(ParseDateTimeError::OutOfRange);
+    }
+
+    // at most 23 * 60 + 59
+    Ok((Some(sign * (hours * 60 + minutes) as i16), rest))
+}
+
+fn datetime_front(input: &str) -> Result<(DateTime, &str), ParseDateTimeError> {
+    let (date, rest) = date_front(input)?;
+    let rest = match rest.as_bytes() {
+        [b'T' | b't' | b' ', ..] => &rest[1..],
+        _ => return Err(ParseDateTimeError::Invalid),
+    };
+    let (time, rest) = time_front(rest)?;
+    let (offset, rest) = offset_front(rest)?;
+
+    Ok((DateTime { date, time, offset }, rest))
+}
+
+macro_rules! impl_partial {
+    ($type:ty, $front:ident, $is_part:expr) => {
+        impl FromStrFront for $type {
+            type Error = ParseDateTimeError;
+
+            fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+                if input.is_empty() {
+                    return Err(ParseDateTimeError::Empty);
+                }
+
+                let (value, rest) = $front(input)?;
+                not_cut_off(rest.chars().next())?;
+                Ok((value, rest))
+            }
+        }
+
+        impl FromStrBack for $type {
+            type Error = ParseDateTimeError;
+
+            fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+                let (value, rest) = from_str_back_via_front(
+                    input,
+                    $is_part,
+                    Self::from_str_front,
+                    || ParseDateTimeError::Empty,
+                    || ParseDateTimeError::Invalid,
+                )?;
+                not_cut_off(rest.chars().next_back())?;
+                Ok((value, rest))
+            }
+        }
+
+        forward!(front for $type; |_, _| ParseDateTimeError::Invalid);
+    };
+}
+
+fn is_date_part(ch: char) -> bool {
+    ch.is_ascii_digit() || ch == '-'
+}
+
+fn is_time_part(ch: char) -> bool {
+    ch.is_ascii_digit() || matches!(ch, ':' | '.')
+}
+
+fn is_datetime_part(ch: char) -> bool {
+    is_date_part(ch) || is_time_part(ch) || matches!(ch, '+' | 'T' | 't' | 'Z' | 'z' | ' ')
+}
+
+impl_partial!(Date, date_front, is_date_part);
+impl_partial!(Time, time_front, is_time_part);
+impl_partial!(DateTime, datetime_front, is_datetime_part);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn date(year: u16, month: u8, day: u8) -> Date {
+        Date { year, month, day }
+    }
+
+    const fn time(hour: u8, minute: u8, second: u8, nanosecond: u32) -> Time {
+        Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+        }
+    }
+
+    #[test]
+    fn date_valid() {
+        assert_eq!(
+            Date::from_str_front("2022-07-09 rest"),
+            Ok((date(2022, 7, 9), " rest"))
+        );
+        assert_eq!(
+            Date::from_str_back("due 2024-02-29"),
+            Ok((date(2024, 2, 29), "due "))
+        );
+        assert_eq!("2000-01-01".parse(), Ok(date(2000, 1, 1)));
+    }
+
+    #[test]
+    fn date_invalid() {
+        assert_eq!(Date::from_str_front(""), Err(ParseDateTimeError::Empty));
+        assert_eq!(
+            Date::from_str_front("2022-7-09"),
+            Err(ParseDateTimeError::Invalid)
+        );
+        assert_eq!(
+            Date::from_str_front("2023-02-29"),
+            Err(ParseDateTimeError::OutOfRange)
+        );
+        assert_eq!(
+            Date::from_str_front("2022-13-01"),
+            Err(ParseDateTimeError::OutOfRange)
+        );
+        assert_eq!(
+            Date::from_str_front("2022-01-011"),
+            Err(ParseDateTimeError::Invalid)
+        );
+        assert_eq!(
+            Date::from_str_back("12022-01-01"),
+            Err(ParseDateTimeError::Invalid)
+        );
+    }
+
+    #[test]
+    fn time_valid() {
+        assert_eq!(
+            Time::from_str_front("13:37:00"),
+            Ok((time(13, 37, 0, 0), ""))
+        );
+        assert_eq!(
+            Time::from_str_front("23:59:59.5 PM"),
+            Ok((time(23, 59, 59, 500_000_000), " PM"))
+        );
+        assert_eq!(
+            Time::from_str_front("00:00:00.1234567891"),
+            Ok((time(0, 0, 0, 123_456_789), ""))
+        );
+        assert_eq!(
+            Time::from_str_back("at 08:15:00"),
+            Ok((time(8, 15, 0, 0), "at "))
+        );
+    }
+
+    #[test]
+    fn time_invalid() {
+        assert_eq!(
+            Time::from_str_front("24:00:00"),
+            Err(ParseDateTimeError::OutOfRange)
+        );
+        assert_eq!(
+            Time::from_str_front("12:0:00"),
+            Err(ParseDateTimeError::Invalid)
+        );
+    }
+
+    #[test]
+    fn time_dangling_dot() {
+        assert_eq!(
+            Time::from_str_front("12:00:00."),
+            Ok((time(12, 0, 0, 0), "."))
+        );
+        assert_eq!(
+            Time::from_str_front("12:00:00.x"),
+            Ok((time(12, 0, 0, 0), ".x"))
+        );
+    }
+
+    #[test]
+    fn datetime() {
+        assert_eq!(
+            DateTime::from_str_front("2022-07-09 13:37:00-02:30!"),
+            Ok((
+                DateTime {
+                    date: date(2022, 7, 9),
+                    time: time(13, 37, 0, 0),
+                    offset: Some(-150),
+                },
+                "!"
+            ))
+        );
+        assert_eq!(
+            DateTime::from_str_back("logged at 2022-07-09T13:37:00"),
+            Ok((
+                DateTime {
+                    date: date(2022, 7, 9),
+                    time: time(13, 37, 0, 0),
+                    offset: None,
+                },
+                "logged at "
+            ))
+        );
+        assert_eq!(
+            DateTime::from_str_front("2022-07-09"),
+            Err(ParseDateTimeError::Invalid)
+        );
+    }
+
+    #[test]
+    fn datetime_dangling_sign() {
+        let timestamp = DateTime {
+            date: date(2022, 7, 9),
+            time: time(13, 37, 0, 0),
+            offset: None,
+        };
+        assert_eq!(
+            DateTime::from_str_front("2022-07-09T13:37:00+"),
+            Ok((timestamp, "+"))
+        );
+        assert_eq!(
+            DateTime::from_str_front("2022-07-09T13:37:00-x"),
+            Ok((timestamp, "-x"))
+        );
+        assert_eq!(
+            DateTime::from_str_front("2022-07-09T13:37:00-"),
+            Ok((timestamp, "-"))
+        );
+        assert_eq!(
+            DateTime::from_str_front("2022-07-09T13:37:00+1:00"),
+            Err(ParseDateTimeError::Invalid)
+        );
+    }
+
+    #[test]
+    fn system_time() {
+        let (timestamp, _) = DateTime::from_str_front("2000-03-01T00:00:00.5Z").unwrap();
+        assert_eq!(
+            timestamp.to_system_time(),
+            Some(SystemTime::UNIX_EPOCH + Duration::new(951_868_800, 500_000_000))
+        );
+
+        let (timestamp, _) = DateTime::from_str_front("1969-12-31T23:59:59Z").unwrap();
+        assert_eq!(
+            timestamp.to_system_time(),
+            Some(SystemTime::UNIX_EPOCH - Duration::from_secs(1))
+        );
+    }
+}
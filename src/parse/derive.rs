@@ -0,0 +1,73 @@
+use super::{ParseErrorKind, PartialParseError};
+use std::error::Error;
+
+pub use strtools_derive::{FromStrBack, FromStrFront};
+
+/// An [`Error`] for derived [`FromStrFront`][0]/[`FromStrBack`][1] implementations.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{DeriveError, FromStrFront};
+///
+/// #[derive(Debug, FromStrFront)]
+/// struct Pair(u8, #[strtools(before = ":")] u8);
+/// # strtools::parse::forward!(front for Pair);
+///
+/// assert!(matches!(Pair::from_str_front("1;2"), Err(DeriveError::Literal(":"))));
+/// assert!(matches!(
+///     Pair::from_str_front("1:a"),
+///     Err(DeriveError::Field { field: "1", .. })
+/// ));
+/// ```
+///
+/// [0]: super::FromStrFront
+/// [1]: super::FromStrBack
+#[derive(thiserror::Error, Debug, Default)]
+pub enum DeriveError {
+    /// A literal given with `#[strtools(before = "...")]` or `#[strtools(after = "...")]` was
+    /// missing.
+    #[error("expected literal {0:?}")]
+    Literal(&'static str),
+
+    /// A field failed to parse.
+    #[error("the field `{field}` could not be parsed: {source}")]
+    Field {
+        /// The name or index of the field.
+        field: &'static str,
+
+        /// The error of the field.
+        source: Box<dyn Error + Send + Sync>,
+    },
+
+    /// None of the variants of an enum could be parsed.
+    #[error("none of the variants could be parsed")]
+    NoVariant,
+
+    /// The input was not completely consumed, this is the default used by [`forward`][0].
+    ///
+    /// [0]: super::forward
+    #[default]
+    #[error("the input was not completely consumed")]
+    Incomplete,
+}
+
+impl PartialParseError for DeriveError {
+    /// Returns [`ParseErrorKind::Invalid`] for [`DeriveError::Field`], the kind of the field's
+    /// error is not known.
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Literal(_) | Self::Field { .. } | Self::NoVariant => ParseErrorKind::Invalid,
+            Self::Incomplete => ParseErrorKind::Incomplete,
+        }
+    }
+}
+
+impl DeriveError {
+    /// Creates a new [`DeriveError::Field`] for the field with the given name or index.
+    pub fn field(field: &'static str, source: impl Error + Send + Sync + 'static) -> Self {
+        Self::Field {
+            field,
+            source: Box::new(source),
+        }
+    }
+}
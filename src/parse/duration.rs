@@ -0,0 +1,179 @@
+use super::{num::scan_digits_front, ParseErrorKind, PartialParseError};
+use std::time::Duration;
+
+/// An [`Error`][0] for [`duration_front`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseDurationError {
+    /// The input was empty.
+    #[error("empty input, expected: `(['0' - '9']+ ['.' ['0' - '9']+]? <unit>)+`")]
+    Empty,
+
+    /// The input did not start with a number.
+    #[error("invalid input, expected: `(['0' - '9']+ ['.' ['0' - '9']+]? <unit>)+`")]
+    Invalid,
+
+    /// The first number was not followed by a unit.
+    #[error("missing unit, expected one of: `d`, `h`, `m`, `s`, `ms`, `us`, `µs`, `ns`")]
+    MissingUnit,
+
+    /// The duration would not fit into a [`Duration`].
+    #[error("the given duration would cause overflow")]
+    Overflow,
+}
+
+impl PartialParseError for ParseDurationError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Empty => ParseErrorKind::Insufficient,
+            Self::Invalid | Self::MissingUnit => ParseErrorKind::Invalid,
+            Self::Overflow => ParseErrorKind::Overflow,
+        }
+    }
+}
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+// longer units first, otherwise `m` would match before `ms`
+const UNITS: [(&str, u128); 8] = [
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("ns", 1),
+    ("d", 86_400 * NANOS_PER_SEC),
+    ("h", 3_600 * NANOS_PER_SEC),
+    ("m", 60 * NANOS_PER_SEC),
+    ("s", NANOS_PER_SEC),
+];
+
+// fractional digits past this are consumed but ignored, this keeps the computation from
+// overflowing and is well below nanosecond precision for all units
+const MAX_FRAC_DIGITS: usize = 18;
+
+/// Parses a single `<number><unit>` component from the start of `input` into nanoseconds.
+fn component_front(input: &str) -> Result<(u128, &str), ParseDurationError> {
+    let int_len = scan_digits_front(input, 10, None);
+    if int_len == 0 {
+        return Err(ParseDurationError::Invalid);
+    }
+
+    let int: u128 = input[..int_len]
+        .parse::<u64>()
+        .map_err(|_| ParseDurationError::Overflow)?
+        .into();
+
+    let mut rest = &input[int_len..];
+    let mut frac = "";
+    if let Some(after) = rest.strip_prefix('.') {
+        let frac_len = scan_digits_front(after, 10, None);
+        if frac_len != 0 {
+            frac = &after[..frac_len];
+            rest = &after[frac_len..];
+        }
+    }
+
+    let (rest, unit) = UNITS
+        .iter()
+        .find_map(|&(unit, nanos)| rest.strip_prefix(unit).map(|rest| (rest, nanos)))
+        .ok_or(ParseDurationError::MissingUnit)?;
+
+    let frac = &frac[..frac.len().min(MAX_FRAC_DIGITS)];
+    let frac_nanos = match frac.parse::<u128>() {
+        Ok(digits) => digits * unit / 10u128.pow(frac.len() as u32),
+        Err(_) => 0,
+    };
+
+    int.checked_mul(unit)
+        .and_then(|nanos| nanos.checked_add(frac_nanos))
+        .map(|nanos| (nanos, rest))
+        .ok_or(ParseDurationError::Overflow)
+}
+
+/// Attempts to parse a [`Duration`] from the beginning of the [`str`], returns the rest of the
+/// `input` and the [`Duration`] if parsing succeeded. A duration is a sequence of numbers, which
+/// may have a fractional part, each directly followed by one of the units `d`, `h`, `m`, `s`,
+/// `ms`, `us`, `µs` or `ns`. Parsing stops at the first component that is not valid.
+///
+/// # Errors
+/// Returns an error if:
+/// - `input` is empty
+/// - `input` doesn't start with a number
+/// - the first number is not followed by a unit
+/// - the duration doesn't fit into a [`Duration`]
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use strtools::parse;
+///
+/// assert_eq!(
+///     parse::duration_front("1h30m15s left"),
+///     Ok((Duration::from_secs(5415), " left"))
+/// );
+/// assert_eq!(parse::duration_front("1.5h"), Ok((Duration::from_secs(5400), "")));
+/// assert_eq!(parse::duration_front("250ms"), Ok((Duration::from_millis(250), "")));
+/// ```
+pub fn duration_front(input: &str) -> Result<(Duration, &str), ParseDurationError> {
+    if input.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+
+    let (mut nanos, mut rest) = component_front(input)?;
+    while let Ok((next, after)) = component_front(rest) {
+        nanos = nanos
+            .checked_add(next)
+            .ok_or(ParseDurationError::Overflow)?;
+        rest = after;
+    }
+
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).map_err(|_| ParseDurationError::Overflow)?;
+
+    // the remainder is always less than a second
+    Ok((Duration::new(secs, (nanos % NANOS_PER_SEC) as u32), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single() {
+        assert_eq!(duration_front("90s"), Ok((Duration::from_secs(90), "")));
+        assert_eq!(duration_front("2d"), Ok((Duration::from_secs(172_800), "")));
+        assert_eq!(duration_front("5m"), Ok((Duration::from_secs(300), "")));
+        assert_eq!(duration_front("5ms"), Ok((Duration::from_millis(5), "")));
+        assert_eq!(duration_front("7µs"), Ok((Duration::from_micros(7), "")));
+        assert_eq!(duration_front("7ns"), Ok((Duration::from_nanos(7), "")));
+    }
+
+    #[test]
+    fn combined() {
+        assert_eq!(duration_front("1h30m"), Ok((Duration::from_secs(5400), "")));
+        assert_eq!(
+            duration_front("1s500ms!"),
+            Ok((Duration::from_millis(1500), "!"))
+        );
+        assert_eq!(
+            duration_front("1h30"),
+            Ok((Duration::from_secs(3600), "30"))
+        );
+    }
+
+    #[test]
+    fn fractional() {
+        assert_eq!(duration_front("0.1s"), Ok((Duration::from_millis(100), "")));
+        assert_eq!(duration_front("1.s"), Err(ParseDurationError::MissingUnit));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(duration_front(""), Err(ParseDurationError::Empty));
+        assert_eq!(duration_front("h"), Err(ParseDurationError::Invalid));
+        assert_eq!(duration_front("90"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(
+            duration_front("99999999999999999999s"),
+            Err(ParseDurationError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use std::convert::Infallible;
+
+/// A classification of partial parse errors, see [`PartialParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// The input ended before a complete representation was found, for example if it was empty or
+    /// a quoted literal was not terminated.
+    Insufficient,
+
+    /// The represented value did not fit into the type.
+    Overflow,
+
+    /// The input contained invalid tokens.
+    Invalid,
+
+    /// A value was parsed, but the input was not completely consumed.
+    Incomplete,
+}
+
+/// A common trait for all partial parse errors of this crate, which allows classifying failures in
+/// generic code over [`FromStrFront`][0]/[`FromStrBack`][1] without downcasting.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{FromStrFront, ParseErrorKind, PartialParseError};
+///
+/// fn is_too_large<T>(input: &str) -> bool
+/// where
+///     T: FromStrFront,
+///     T::Error: PartialParseError,
+/// {
+///     matches!(T::from_str_front(input), Err(err) if err.kind() == ParseErrorKind::Overflow)
+/// }
+///
+/// assert!(is_too_large::<u8>("256"));
+/// assert!(!is_too_large::<u16>("256"));
+/// assert!(!is_too_large::<f32>("abc"));
+/// ```
+///
+/// [0]: super::FromStrFront
+/// [1]: super::FromStrBack
+pub trait PartialParseError {
+    /// Returns the [`ParseErrorKind`] of this error.
+    fn kind(&self) -> ParseErrorKind;
+}
+
+impl PartialParseError for Infallible {
+    fn kind(&self) -> ParseErrorKind {
+        match *self {}
+    }
+}
@@ -0,0 +1,718 @@
+use super::{
+    from_str_back_via_front,
+    num::{scan_digits_front, scan_grouped_digits_front},
+    FromStrBack, FromStrFront, FromStrPartialFormatExt, NumberFormat, ParseErrorKind,
+    PartialParseError,
+};
+use std::{borrow::Cow, str::FromStr};
+
+/// An [`Error`][0] for [`FromStrFront`]/[`FromStrBack`] implementations of floats.
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseFloatPartialError {
+    /// The input contained invalid tokens.
+    #[error(
+        "invalid input, expected: `['+' | '-']? (['0' - '9']* ['.' ['0' - '9']+]? [('e' | 'E') ['+' | '-']? ['0' - '9']+]? | 'inf' | 'infinity' | 'nan')`"
+    )]
+    Invalid,
+
+    /// The input was empty.
+    #[error(
+        "empty input, expected: `['+' | '-']? (['0' - '9']* ['.' ['0' - '9']+]? [('e' | 'E') ['+' | '-']? ['0' - '9']+]? | 'inf' | 'infinity' | 'nan')`"
+    )]
+    Empty,
+}
+
+impl PartialParseError for ParseFloatPartialError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Invalid => ParseErrorKind::Invalid,
+            Self::Empty => ParseErrorKind::Insufficient,
+        }
+    }
+}
+
+/// An extension for all floats that adds `from_str_radix` equivalents of the [`FromStrFront`]
+/// function, see it's documentation for more info.
+pub trait FromStrPartialFloatRadixExt: crate::util::sealed::Sealed + FromStrFront {
+    /// Behaves like [`FromStrFront::from_str_front`] for the given radix. For radix 10 this is
+    /// equivalent to [`FromStrFront::from_str_front`], for radix 16 an optional `0x` prefix and a
+    /// binary exponent like `p3` or `P-2` are accepted like in C's hex floats. Other radices don't
+    /// accept any exponent. Special values are only accepted for radix 10.
+    ///
+    /// The result is rounded to the nearest float with ties rounding to even. For radices that are
+    /// not a power of two this is only guaranteed if the significant digits fit into a [`u64`],
+    /// otherwise the result may be off by one unit in the last place.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not in the range from 2 to 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromStrPartialFloatRadixExt;
+    ///
+    /// assert_eq!(f64::from_str_radix_front("0x1.8p3 rest", 16), Ok((12.0, " rest")));
+    /// assert_eq!(f64::from_str_radix_front("-ff.8", 16), Ok((-255.5, "")));
+    /// assert_eq!(f32::from_str_radix_front("10.1", 2), Ok((2.5, "")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_radix_front(
+        input: &str,
+        radix: u32,
+    ) -> Result<(Self, &str), <Self as FromStrFront>::Error>;
+}
+
+/// A float in a radix other than 10 as read from the input, its value is
+/// `mantissa * radix^scale * 2^exp`.
+struct RadixFloat {
+    is_neg: bool,
+    mantissa: u64,
+    // true if non zero digits were dropped because they didn't fit into the mantissa
+    inexact: bool,
+    radix: u32,
+    scale: i64,
+    exp: i64,
+}
+
+impl RadixFloat {
+    /// Returns the bits of the float nearest to this value, with ties rounding to even, for a
+    /// float type with the given `precision` and `max_exp` like [`f64::MANTISSA_DIGITS`] and
+    /// [`f64::MAX_EXP`].
+    fn to_bits(&self, precision: u32, max_exp: i32) -> u64 {
+        let exp_bits = max_exp.trailing_zeros() + 1;
+        let sign = u64::from(self.is_neg) << (precision - 1 + exp_bits);
+
+        if self.mantissa == 0 {
+            return sign;
+        }
+
+        let round =
+            |mantissa, inexact, exp| round_binary(mantissa, inexact, exp, precision, max_exp);
+        let bits = if self.radix.is_power_of_two() {
+            // the exponents are combined before scaling, so no intermediate value can overflow
+            let exp = self.exp.saturating_add(
+                self.scale
+                    .saturating_mul(self.radix.trailing_zeros().into()),
+            );
+            round(self.mantissa, self.inexact, exp)
+        } else {
+            // values this far out of range are infinite or zero, this bounds the size of `Big`
+            let limit = 64 + i64::from(max_exp) + i64::from(precision);
+            let magnitude = self.scale.saturating_mul(self.radix.ilog2().into());
+            let mut value = Big::new(self.mantissa);
+
+            if magnitude > limit {
+                return sign | round(1, false, i64::MAX);
+            } else if magnitude < -limit {
+                return sign;
+            } else if self.scale >= 0 {
+                (0..self.scale).for_each(|_| value.mul_small(self.radix));
+
+                let shift = value.bit_len().saturating_sub(64);
+                let (top, rest) = value.bits_from(shift);
+                round(top, rest || self.inexact, shift as i64)
+            } else {
+                let mut divisor = Big::new(1);
+                (self.scale..0).for_each(|_| divisor.mul_small(self.radix));
+
+                // the quotient of `value << shift` and `divisor` has exactly 63 or 64 bits
+                let shift = divisor.bit_len() + 63 - value.bit_len();
+                let mut rem = value.shl(shift);
+                let mut quotient = 0;
+                for bit in (0..64).rev() {
+                    let sub = divisor.shl(bit);
+                    if rem >= sub {
+                        rem.sub_assign(&sub);
+                        quotient |= 1 << bit;
+                    }
+                }
+
+                round(quotient, !rem.is_zero() || self.inexact, -(shift as i64))
+            }
+        };
+
+        sign | bits
+    }
+}
+
+/// Returns the bits without the sign of the float nearest to `mantissa * 2^exp`, `inexact`
+/// indicates that the value is slightly larger than that, see [`RadixFloat::to_bits`].
+fn round_binary(mantissa: u64, inexact: bool, exp: i64, precision: u32, max_exp: i32) -> u64 {
+    let inf = ((1 << (max_exp.trailing_zeros() + 1)) - 1) << (precision - 1);
+    let min_exp = 2 - i64::from(max_exp);
+
+    // normalize so the value is `1.xxx * 2^exp`
+    let zeros = mantissa.leading_zeros();
+    let mantissa = mantissa << zeros;
+    let exp = exp.saturating_add(63 - i64::from(zeros));
+
+    if exp >= i64::from(max_exp) {
+        return inf;
+    }
+
+    // subnormals have fewer bits of precision
+    let drop = u64::from(64 - precision) + u64::try_from(min_exp.saturating_sub(exp)).unwrap_or(0);
+    let (kept, half, rest) = match drop {
+        ..=63 => (
+            mantissa >> drop,
+            mantissa >> (drop - 1) & 1 == 1,
+            mantissa & ((1 << (drop - 1)) - 1) != 0,
+        ),
+        64 => (0, true, mantissa << 1 != 0),
+        _ => return 0,
+    };
+
+    let kept = kept + u64::from(half && (rest || inexact || kept & 1 == 1));
+    let biased = u64::try_from(exp - min_exp).unwrap_or(0);
+
+    // a mantissa that was rounded up to the next power of two carries into the exponent
+    ((biased << (precision - 1)) + kept).min(inf)
+}
+
+/// A minimal unsigned integer of arbitrary size, used for converting floats of radices that are
+/// not a power of two exactly, the limbs are stored least significant first without trailing
+/// zero limbs.
+#[derive(PartialEq, Eq)]
+struct Big(Vec<u32>);
+
+impl Big {
+    fn new(value: u64) -> Self {
+        Self(vec![value as u32, (value >> 32) as u32]).normalized()
+    }
+
+    fn normalized(mut self) -> Self {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        self
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn bit_len(&self) -> usize {
+        self.0
+            .last()
+            .map_or(0, |last| self.0.len() * 32 - last.leading_zeros() as usize)
+    }
+
+    fn bit(&self, idx: usize) -> bool {
+        self.0
+            .get(idx / 32)
+            .is_some_and(|limb| limb >> (idx % 32) & 1 == 1)
+    }
+
+    /// Returns the 64 bits starting at `start` and whether any bit below `start` is set.
+    fn bits_from(&self, start: usize) -> (u64, bool) {
+        let bits = (0..64).fold(0, |acc, idx| acc | u64::from(self.bit(start + idx)) << idx);
+        (bits, (0..start).any(|idx| self.bit(idx)))
+    }
+
+    fn mul_small(&mut self, factor: u32) {
+        let mut carry = 0;
+        for limb in &mut self.0 {
+            let product = u64::from(*limb) * u64::from(factor) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+
+        if carry != 0 {
+            self.0.push(carry as u32);
+        }
+    }
+
+    fn shl(&self, bits: usize) -> Self {
+        let (limbs, bits) = (bits / 32, bits % 32);
+        let mut result = vec![0; limbs];
+        let mut carry = 0;
+        for &limb in &self.0 {
+            result.push(limb << bits | carry);
+            carry = if bits == 0 { 0 } else { limb >> (32 - bits) };
+        }
+
+        result.push(carry);
+        Self(result).normalized()
+    }
+
+    /// Subtracts `other` from this, `other` must not be larger than this.
+    fn sub_assign(&mut self, other: &Self) {
+        let mut borrow = false;
+        for (idx, limb) in self.0.iter_mut().enumerate() {
+            let (diff, first) = limb.overflowing_sub(other.0.get(idx).copied().unwrap_or(0));
+            let (diff, second) = diff.overflowing_sub(u32::from(borrow));
+            *limb = diff;
+            borrow = first || second;
+        }
+
+        let normalized = std::mem::take(&mut self.0);
+        *self = Self(normalized).normalized();
+    }
+}
+
+impl PartialOrd for Big {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Big {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+/// Parses a float in the given `radix` other than 10 from the start of `input`.
+fn from_str_radix_front(
+    input: &str,
+    radix: u32,
+) -> Result<(RadixFloat, &str), ParseFloatPartialError> {
+    assert!(
+        matches!(radix, 2..=36),
+        "radix must be in `[2, 36]` - found {}",
+        radix
+    );
+
+    let (is_neg, mut rest) = match input.as_bytes() {
+        [b'-', ..] => (true, &input[1..]),
+        [b'+', ..] => (false, &input[1..]),
+        _ => (false, input),
+    };
+
+    if rest.is_empty() {
+        return Err(ParseFloatPartialError::Empty);
+    }
+
+    if radix == 16
+        && let Some(after) = rest.strip_prefix("0x").or_else(|| rest.strip_prefix("0X"))
+        && scan_digits_front(after.strip_prefix('.').unwrap_or(after), 16, None) != 0
+    {
+        rest = after;
+    }
+
+    let int_len = scan_digits_front(rest, radix, None);
+    let int = &rest[..int_len];
+    rest = &rest[int_len..];
+
+    // the radix point is only part of the float if it's followed by digits
+    let mut frac = "";
+    if let Some(after) = rest.strip_prefix('.') {
+        let frac_len = scan_digits_front(after, radix, None);
+        if frac_len != 0 {
+            frac = &after[..frac_len];
+            rest = &after[frac_len..];
+        }
+    }
+
+    if int.is_empty() && frac.is_empty() {
+        return Err(ParseFloatPartialError::Invalid);
+    }
+
+    // digits that don't fit into the mantissa only scale the integral part
+    let mut float = RadixFloat {
+        is_neg,
+        mantissa: 0,
+        inexact: false,
+        radix,
+        scale: 0,
+        exp: 0,
+    };
+    let mut push = |digit: u32, is_frac: bool| match float
+        .mantissa
+        .checked_mul(radix.into())
+        .and_then(|mantissa| mantissa.checked_add(digit.into()))
+    {
+        Some(next) => {
+            float.mantissa = next;
+            float.scale -= i64::from(is_frac);
+        }
+        None => {
+            float.inexact |= digit != 0;
+            float.scale += i64::from(!is_frac);
+        }
+    };
+
+    int.chars()
+        .filter_map(|ch| ch.to_digit(radix))
+        .for_each(|digit| push(digit, false));
+    frac.chars()
+        .filter_map(|ch| ch.to_digit(radix))
+        .for_each(|digit| push(digit, true));
+
+    // the binary exponent is only part of the float if it contains digits
+    if radix == 16
+        && let Some(after) = rest.strip_prefix(['p', 'P'])
+    {
+        let (exp_neg, digits) = match after.as_bytes() {
+            [b'-', ..] => (true, &after[1..]),
+            [b'+', ..] => (false, &after[1..]),
+            _ => (false, after),
+        };
+
+        let exp_len = scan_digits_front(digits, 10, None);
+        if exp_len != 0 {
+            // exponents this large are infinite or zero anyway, combining them saturates
+            float.exp = digits[..exp_len].parse().unwrap_or(i64::MAX);
+            if exp_neg {
+                float.exp = -float.exp;
+            }
+            rest = &digits[exp_len..];
+        }
+    }
+
+    Ok((float, rest))
+}
+
+/// Returns the length of the float at the start of `input` and it's representation understood by
+/// [`FromStr`], the representation is only allocated if it differs from the input.
+fn scan_front<'s>(
+    input: &'s str,
+    format: &NumberFormat,
+) -> Result<(usize, Cow<'s, str>), ParseFloatPartialError> {
+    let sign = match input.as_bytes() {
+        [b'-' | b'+', ..] => 1,
+        _ => 0,
+    };
+
+    let rest = &input[sign..];
+    if rest.is_empty() {
+        return Err(ParseFloatPartialError::Empty);
+    }
+
+    if format.special_values {
+        // longest first, so `infinity` isn't parsed as `inf`
+        for special in ["infinity", "inf", "nan"] {
+            if let Some(prefix) = rest.get(..special.len())
+                && prefix.eq_ignore_ascii_case(special)
+            {
+                let end = sign + special.len();
+                return Ok((end, Cow::Borrowed(&input[..end])));
+            }
+        }
+    }
+
+    let int = match format.group_separator {
+        Some(sep) => scan_grouped_digits_front(rest, 10, sep),
+        None => scan_digits_front(rest, 10, None),
+    };
+
+    // the decimal mark is only part of the float if it's followed by digits
+    let frac = match rest[int..].strip_prefix(format.decimal_mark) {
+        Some(frac) => match scan_digits_front(frac, 10, None) {
+            0 => 0,
+            len => format.decimal_mark.len_utf8() + len,
+        },
+        None => 0,
+    };
+
+    if int == 0 && frac == 0 {
+        return Err(ParseFloatPartialError::Invalid);
+    }
+
+    // the exponent is only part of the float if it contains digits
+    let exp = match rest[int + frac..].strip_prefix(['e', 'E']) {
+        Some(exp) => {
+            let exp_sign = match exp.as_bytes() {
+                [b'-' | b'+', ..] => 1,
+                _ => 0,
+            };
+
+            match scan_digits_front(&exp[exp_sign..], 10, None) {
+                0 => 0,
+                len => 1 + exp_sign + len,
+            }
+        }
+        None => 0,
+    };
+
+    let end = sign + int + frac + exp;
+    let repr = &input[..end];
+
+    if format.decimal_mark == '.'
+        && !repr[sign..sign + int].contains(|ch: char| !ch.is_ascii_digit())
+    {
+        Ok((end, Cow::Borrowed(repr)))
+    } else {
+        let mut owned = String::with_capacity(end);
+        owned.push_str(&input[..sign]);
+        owned.extend(rest[..int].chars().filter(char::is_ascii_digit));
+        if frac != 0 {
+            owned.push('.');
+            owned.push_str(&rest[int + format.decimal_mark.len_utf8()..int + frac]);
+        }
+        owned.push_str(&rest[int + frac..int + frac + exp]);
+
+        Ok((end, Cow::Owned(owned)))
+    }
+}
+
+/// Returns true if `ch` may be part of a float with the default format.
+fn is_float_part(ch: char) -> bool {
+    ch.is_ascii_digit()
+        || matches!(ch, '.' | '+' | '-' | 'e' | 'E')
+        || matches!(ch.to_ascii_lowercase(), 'i' | 'n' | 'f' | 't' | 'y' | 'a')
+}
+
+macro_rules! float_impl {
+    ($float:ty) => {
+        impl FromStrPartialFormatExt for $float {
+            fn from_str_front_formatted<'s>(
+                input: &'s str,
+                format: &NumberFormat,
+            ) -> Result<(Self, &'s str), <Self as FromStrFront>::Error> {
+                let (end, repr) = scan_front(input, format)?;
+
+                // the representation is always valid for FromStr
+                <$float>::from_str(&repr)
+                    .map(|float| (float, &input[end..]))
+                    .map_err(|_| ParseFloatPartialError::Invalid)
+            }
+
+            fn from_str_back_formatted<'s>(
+                input: &'s str,
+                format: &NumberFormat,
+            ) -> Result<(Self, &'s str), <Self as FromStrBack>::Error> {
+                from_str_back_via_front(
+                    input,
+                    |ch| {
+                        is_float_part(ch)
+                            || ch == format.decimal_mark
+                            || Some(ch) == format.group_separator
+                    },
+                    |input| Self::from_str_front_formatted(input, format),
+                    || ParseFloatPartialError::Empty,
+                    || ParseFloatPartialError::Invalid,
+                )
+            }
+        }
+
+        impl FromStrPartialFloatRadixExt for $float {
+            fn from_str_radix_front(
+                input: &str,
+                radix: u32,
+            ) -> Result<(Self, &str), <Self as FromStrFront>::Error> {
+                if radix == 10 {
+                    Self::from_str_front(input)
+                } else {
+                    from_str_radix_front(input, radix).map(|(float, rest)| {
+                        let bits = float.to_bits(<$float>::MANTISSA_DIGITS, <$float>::MAX_EXP);
+                        (<$float>::from_bits(bits as _), rest)
+                    })
+                }
+            }
+        }
+
+        impl FromStrFront for $float {
+            type Error = ParseFloatPartialError;
+
+            fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+                Self::from_str_front_formatted(input, &NumberFormat::DEFAULT)
+            }
+        }
+
+        impl FromStrBack for $float {
+            type Error = ParseFloatPartialError;
+
+            fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+                Self::from_str_back_formatted(input, &NumberFormat::DEFAULT)
+            }
+        }
+    };
+}
+
+float_impl!(f32);
+float_impl!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn front() {
+        assert_eq!(f64::from_str_front("1.5 apples"), Ok((1.5, " apples")));
+        assert_eq!(f64::from_str_front("-.5"), Ok((-0.5, "")));
+        assert_eq!(f64::from_str_front("+3"), Ok((3.0, "")));
+        assert_eq!(f64::from_str_front("3. Next"), Ok((3.0, ". Next")));
+        assert_eq!(
+            f64::from_str_front("abc"),
+            Err(ParseFloatPartialError::Invalid)
+        );
+        assert_eq!(f64::from_str_front("-"), Err(ParseFloatPartialError::Empty));
+        assert_eq!(f64::from_str_front(""), Err(ParseFloatPartialError::Empty));
+    }
+
+    #[test]
+    fn front_exponent() {
+        assert_eq!(f64::from_str_front("1e9"), Ok((1e9, "")));
+        assert_eq!(f64::from_str_front("-2.5E-3 m"), Ok((-2.5e-3, " m")));
+        assert_eq!(f64::from_str_front("3e+2"), Ok((300.0, "")));
+        assert_eq!(f64::from_str_front("3e"), Ok((3.0, "e")));
+        assert_eq!(f64::from_str_front("3e-x"), Ok((3.0, "e-x")));
+        assert_eq!(f64::from_str_front("3 e2"), Ok((3.0, " e2")));
+    }
+
+    #[test]
+    fn front_special() {
+        assert_eq!(f64::from_str_front("inf"), Ok((f64::INFINITY, "")));
+        assert_eq!(f64::from_str_front("-INF!"), Ok((f64::NEG_INFINITY, "!")));
+        assert_eq!(
+            f64::from_str_front("+Infinity and beyond"),
+            Ok((f64::INFINITY, " and beyond"))
+        );
+        assert!(f64::from_str_front("NaN").unwrap().0.is_nan());
+        assert_eq!(
+            f64::from_str_front("in"),
+            Err(ParseFloatPartialError::Invalid)
+        );
+
+        let strict = NumberFormat::DEFAULT.without_special_values();
+        assert_eq!(
+            f64::from_str_front_formatted("nan", &strict),
+            Err(ParseFloatPartialError::Invalid)
+        );
+        assert_eq!(f64::from_str_front_formatted("1e3", &strict), Ok((1e3, "")));
+    }
+
+    #[test]
+    fn front_radix() {
+        assert_eq!(f64::from_str_radix_front("0x1p-2", 16), Ok((0.25, "")));
+        assert_eq!(f64::from_str_radix_front("1P4", 16), Ok((16.0, "")));
+        assert_eq!(f64::from_str_radix_front("0x.8", 16), Ok((0.5, "")));
+        assert_eq!(f64::from_str_radix_front("0x", 16), Ok((0.0, "x")));
+        assert_eq!(f64::from_str_radix_front("1p", 16), Ok((1.0, "p")));
+        assert_eq!(f64::from_str_radix_front("1e3", 10), Ok((1e3, "")));
+        assert_eq!(f64::from_str_radix_front("7.4", 8), Ok((7.5, "")));
+        assert_eq!(
+            f64::from_str_radix_front("ffffffffffffffffff", 16),
+            Ok((2f64.powi(72), ""))
+        );
+        assert_eq!(f64::from_str_radix_front("0x", 10), Ok((0.0, "x")));
+        assert_eq!(
+            f64::from_str_radix_front("g", 16),
+            Err(ParseFloatPartialError::Invalid)
+        );
+        assert_eq!(
+            f64::from_str_radix_front("-", 16),
+            Err(ParseFloatPartialError::Empty)
+        );
+    }
+
+    #[test]
+    fn front_radix_edges() {
+        assert_eq!(f64::from_str_radix_front("0x1p-1074", 16), Ok((5e-324, "")));
+        assert_eq!(f64::from_str_radix_front("0x1p-1075", 16), Ok((0.0, "")));
+        assert_eq!(
+            f64::from_str_radix_front("0x1.8p-1074", 16),
+            Ok((1e-323, ""))
+        );
+        assert_eq!(f64::from_str_radix_front("0x0p2000", 16), Ok((0.0, "")));
+        assert_eq!(
+            f64::from_str_radix_front("0x0.0001p1030", 16),
+            Ok((2f64.powi(1014), ""))
+        );
+        assert_eq!(
+            f64::from_str_radix_front("0x1.fffffffffffffp1023", 16),
+            Ok((f64::MAX, ""))
+        );
+        assert_eq!(
+            f64::from_str_radix_front("0x1.fffffffffffff8p1023", 16),
+            Ok((f64::INFINITY, ""))
+        );
+        assert_eq!(
+            f64::from_str_radix_front("-0x1p99999999999999999999", 16),
+            Ok((f64::NEG_INFINITY, ""))
+        );
+        assert_eq!(
+            f64::from_str_radix_front("0x1p-99999999999999999999", 16),
+            Ok((0.0, ""))
+        );
+    }
+
+    #[test]
+    fn front_radix_f32() {
+        assert_eq!(f32::from_str_radix_front("0x1p-149", 16), Ok((1e-45, "")));
+        assert_eq!(
+            f32::from_str_radix_front("0x1p128", 16),
+            Ok((f32::INFINITY, ""))
+        );
+
+        // rounding through f64 first would round to 1 + 2^-24 and then to even
+        assert_eq!(
+            f32::from_str_radix_front("0x1.000001000000001p0", 16),
+            Ok((1.0 + f32::EPSILON, ""))
+        );
+        assert_eq!(f32::from_str_radix_front("0x1.000001p0", 16), Ok((1.0, "")));
+    }
+
+    #[test]
+    fn front_radix_non_power_of_two() {
+        assert_eq!(f64::from_str_radix_front("0.1", 3), Ok((1.0 / 3.0, "")));
+        assert_eq!(f32::from_str_radix_front("0.1", 3), Ok((1.0 / 3.0, "")));
+        assert_eq!(f64::from_str_radix_front("z.i", 36), Ok((35.5, "")));
+
+        let huge = format!("1{}", "0".repeat(700));
+        assert_eq!(f64::from_str_radix_front(&huge, 3), Ok((f64::INFINITY, "")));
+
+        let tiny = format!("0.{}1", "0".repeat(700));
+        assert_eq!(f64::from_str_radix_front(&tiny, 3), Ok((0.0, "")));
+
+        let small = format!("0.{}1", "0".repeat(99));
+        assert_eq!(
+            f64::from_str_radix_front(&small, 7),
+            Ok((3.091690408090221e-85, ""))
+        );
+    }
+
+    #[test]
+    fn front_formatted() {
+        let german = NumberFormat::new(',', Some('.'));
+        assert_eq!(
+            f64::from_str_front_formatted("1.234.567,89 EUR", &german),
+            Ok((1_234_567.89, " EUR"))
+        );
+        assert_eq!(
+            f64::from_str_front_formatted("-2,5", &german),
+            Ok((-2.5, ""))
+        );
+        assert_eq!(
+            f64::from_str_front_formatted("1.23,5", &german),
+            Ok((1.0, ".23,5"))
+        );
+    }
+
+    #[test]
+    fn back_formatted() {
+        let german = NumberFormat::new(',', Some('.'));
+        assert_eq!(
+            f64::from_str_back_formatted("Preis: 1.234,5", &german),
+            Ok((1_234.5, "Preis: "))
+        );
+        assert_eq!(
+            f64::from_str_back_formatted("x=2,75", &NumberFormat::new(',', None)),
+            Ok((2.75, "x="))
+        );
+        assert_eq!(
+            f64::from_str_back_formatted("3,14,", &german),
+            Err(ParseFloatPartialError::Invalid)
+        );
+    }
+
+    #[test]
+    fn back() {
+        assert_eq!(f64::from_str_back("apples 1.5"), Ok((1.5, "apples ")));
+        assert_eq!(f64::from_str_back("x-2.25"), Ok((-2.25, "x")));
+        assert_eq!(f64::from_str_back("1-5"), Ok((-5.0, "1")));
+        assert_eq!(
+            f64::from_str_back("5."),
+            Err(ParseFloatPartialError::Invalid)
+        );
+        assert_eq!(f64::from_str_back(""), Err(ParseFloatPartialError::Empty));
+        assert_eq!(f64::from_str_back("size 1e-3"), Ok((1e-3, "size ")));
+        assert_eq!(f64::from_str_back("x=-inf"), Ok((f64::NEG_INFINITY, "x=")));
+        assert_eq!(f64::from_str_back("size5"), Ok((5.0, "size")));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    parse::{FromStrBack, FromStrFront},
+    util,
+};
+
+/// A configuration for parsing numbers with group separators and a custom decimal mark, no locale
+/// is ever guessed, both have to be given explicitly.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{FromStrPartialFormatExt, NumberFormat};
+///
+/// let english = NumberFormat::new('.', Some(','));
+/// let german = NumberFormat::new(',', Some('.'));
+///
+/// assert_eq!(f64::from_str_front_formatted("1,234,567.89", &english), Ok((1_234_567.89, "")));
+/// assert_eq!(f64::from_str_front_formatted("1.234.567,89", &german), Ok((1_234_567.89, "")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    /// The char separating the integral part from the fractional part.
+    pub decimal_mark: char,
+
+    /// The char separating groups of three digits in the integral part if any, the first group may
+    /// contain one to three digits.
+    pub group_separator: Option<char>,
+
+    /// Whether floats accept the special values `inf`, `infinity` and `nan`, ignoring case.
+    pub special_values: bool,
+}
+
+impl NumberFormat {
+    /// The format used by [`FromStrFront`] implementations, `.` as decimal mark, no group
+    /// separator and special values enabled.
+    pub const DEFAULT: Self = Self::new('.', None);
+
+    /// Creates a new [`NumberFormat`] from the given `decimal_mark` and `group_separator`, special
+    /// values are enabled.
+    ///
+    /// # Panics
+    /// Panics if `group_separator == Some(decimal_mark)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::NumberFormat;
+    ///
+    /// let format = NumberFormat::new(',', Some('\u{2009}'));
+    /// assert_eq!(format.decimal_mark, ',');
+    /// ```
+    pub const fn new(decimal_mark: char, group_separator: Option<char>) -> Self {
+        if let Some(sep) = group_separator {
+            assert!(
+                sep != decimal_mark,
+                "the group separator cannot be the decimal mark"
+            );
+        }
+
+        Self {
+            decimal_mark,
+            group_separator,
+            special_values: true,
+        }
+    }
+
+    /// Disables the special values `inf`, `infinity` and `nan` for stricter input validation.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::{FromStrPartialFormatExt, NumberFormat, ParseFloatPartialError};
+    ///
+    /// let lenient = NumberFormat::DEFAULT;
+    /// let strict = lenient.without_special_values();
+    ///
+    /// assert_eq!(f64::from_str_front_formatted("-inf", &lenient), Ok((f64::NEG_INFINITY, "")));
+    /// assert_eq!(f64::from_str_front_formatted("-inf", &strict), Err(ParseFloatPartialError::Invalid));
+    /// ```
+    pub const fn without_special_values(self) -> Self {
+        Self {
+            special_values: false,
+            ..self
+        }
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// An extension for all numbers that adds [`NumberFormat`] aware equivalents of the
+/// [`FromStrFront`] & [`FromStrBack`] functions, see it's documentation for more info.
+pub trait FromStrPartialFormatExt: util::sealed::Sealed + FromStrFront + FromStrBack {
+    /// Behaves like [`FromStrFront::from_str_front`] for the given format. Integers don't consume
+    /// the decimal mark.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::{FromStrPartialFormatExt, NumberFormat};
+    ///
+    /// let format = NumberFormat::new('.', Some(','));
+    /// assert_eq!(u32::from_str_front_formatted("1,234.5", &format), Ok((1_234, ".5")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_front_formatted<'s>(
+        input: &'s str,
+        format: &NumberFormat,
+    ) -> Result<(Self, &'s str), <Self as FromStrFront>::Error>;
+
+    /// Behaves like [`FromStrBack::from_str_back`] for the given format.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::{FromStrPartialFormatExt, NumberFormat};
+    ///
+    /// let format = NumberFormat::new(',', None);
+    /// assert_eq!(f64::from_str_back_formatted("costs 2,75", &format), Ok((2.75, "costs ")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_back_formatted<'s>(
+        input: &'s str,
+        format: &NumberFormat,
+    ) -> Result<(Self, &'s str), <Self as FromStrBack>::Error>;
+}
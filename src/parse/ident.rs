@@ -0,0 +1,125 @@
+use super::{ParseErrorKind, PartialParseError};
+
+/// An [`Error`][0] for [`ident_front`] and [`ident_with`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseIdentError {
+    /// The input was empty.
+    #[error("empty input, expected an identifier")]
+    Empty,
+
+    /// The input did not start with a valid start of an identifier.
+    #[error("invalid input, {0:?} cannot start an identifier")]
+    Invalid(char),
+}
+
+impl PartialParseError for ParseIdentError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Empty => ParseErrorKind::Insufficient,
+            Self::Invalid(_) => ParseErrorKind::Invalid,
+        }
+    }
+}
+
+/// Parses an identifier matching `[A-Za-z_][A-Za-z0-9_]*` from the start of `input`, returns the
+/// identifier and the rest. See [`ident_with`] for custom identifiers like Unicode ones.
+///
+/// # Errors
+/// Returns an error if:
+/// - `input` is empty
+/// - `input` doesn't start with an ASCII letter or `_`
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{self, ParseIdentError};
+///
+/// assert_eq!(parse::ident_front("foo_bar2 = 3"), Ok(("foo_bar2", " = 3")));
+/// assert_eq!(parse::ident_front("_"), Ok(("_", "")));
+/// assert_eq!(parse::ident_front("2x"), Err(ParseIdentError::Invalid('2')));
+/// ```
+pub fn ident_front(input: &str) -> Result<(&str, &str), ParseIdentError> {
+    ident_with(
+        input,
+        |ch| ch.is_ascii_alphabetic() || ch == '_',
+        |ch| ch.is_ascii_alphanumeric() || ch == '_',
+    )
+}
+
+/// Parses an identifier from the start of `input` which starts with a char matching `start` and
+/// continues with chars matching `cont`, returns the identifier and the rest.
+///
+/// # Errors
+/// Returns an error if:
+/// - `input` is empty
+/// - the first char of `input` doesn't match `start`
+///
+/// # Examples
+/// ```
+/// use strtools::parse;
+///
+/// // unicode identifiers
+/// assert_eq!(
+///     parse::ident_with("größe: 3", char::is_alphabetic, char::is_alphanumeric),
+///     Ok(("größe", ": 3"))
+/// );
+///
+/// // kebab-case identifiers
+/// assert_eq!(
+///     parse::ident_with("max-width: 3", |ch| ch.is_ascii_lowercase(), |ch| {
+///         ch.is_ascii_lowercase() || ch == '-'
+///     }),
+///     Ok(("max-width", ": 3"))
+/// );
+/// ```
+pub fn ident_with(
+    input: &str,
+    start: impl FnOnce(char) -> bool,
+    mut cont: impl FnMut(char) -> bool,
+) -> Result<(&str, &str), ParseIdentError> {
+    let mut chars = input.chars();
+    let first = chars.next().ok_or(ParseIdentError::Empty)?;
+
+    if !start(first) {
+        return Err(ParseIdentError::Invalid(first));
+    }
+
+    let end = chars
+        .as_str()
+        .find(|ch| !cont(ch))
+        .map_or(input.len(), |idx| first.len_utf8() + idx);
+
+    Ok(input.split_at(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii() {
+        assert_eq!(ident_front("a"), Ok(("a", "")));
+        assert_eq!(ident_front("A_1.b"), Ok(("A_1", ".b")));
+        assert_eq!(ident_front("_1 "), Ok(("_1", " ")));
+        assert_eq!(ident_front("äb"), Err(ParseIdentError::Invalid('ä')));
+        assert_eq!(ident_front("ab€"), Ok(("ab", "€")));
+        assert_eq!(ident_front(""), Err(ParseIdentError::Empty));
+    }
+
+    #[test]
+    fn custom() {
+        assert_eq!(
+            ident_with("$var1+", |ch| ch == '$', char::is_alphanumeric),
+            Ok(("$var1", "+"))
+        );
+        assert_eq!(
+            ident_with("éa", char::is_alphabetic, char::is_alphanumeric),
+            Ok(("éa", ""))
+        );
+        assert_eq!(
+            ident_with("var", |ch| ch == '$', char::is_alphanumeric),
+            Err(ParseIdentError::Invalid('v'))
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use super::{FromStrFront, ParseErrorKind, PartialParseError};
+use std::{
+    ops::{Bound, RangeBounds},
+    str::FromStr,
+};
+
+/// An [`Error`][0] for [`FromStrFront`] on [`Interval`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseIntervalError<E> {
+    /// The start could not be parsed.
+    #[error("the start could not be parsed: {0}")]
+    Start(E),
+
+    /// The start was not followed by `..` or `..=`.
+    #[error("expected `..` or `..=` after the start")]
+    MissingDots,
+
+    /// The end could not be parsed, for exclusive ranges this is only returned if parsing the end
+    /// failed for another reason than it being empty or invalid.
+    #[error("the end could not be parsed: {0}")]
+    End(E),
+
+    /// The range was parsed, but the input was not completely consumed, only returned by
+    /// [`FromStr`].
+    #[error("the input was not completely consumed")]
+    Incomplete,
+}
+
+impl<E: PartialParseError> PartialParseError for ParseIntervalError<E> {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Start(err) | Self::End(err) => err.kind(),
+            Self::MissingDots => ParseErrorKind::Invalid,
+            Self::Incomplete => ParseErrorKind::Incomplete,
+        }
+    }
+}
+
+/// A range in rust syntax, covering `..`, `a..`, `..b`, `..=b`, `a..b` and `a..=b`. This is needed
+/// because [`FromStr`] cannot be implemented for the [`std::ops`] ranges directly, [`RangeBounds`]
+/// is implemented instead. An exclusive range without an end is parsed if the input after `..`
+/// is empty or not a valid `T`, any other error of the end, like an overflow, is returned.
+///
+/// # Examples
+/// ```
+/// use std::ops::RangeBounds;
+/// use strtools::parse::{FromStrFront, Interval};
+///
+/// assert_eq!(Interval::<u8>::from_str_front("1..5"), Ok((Interval::Range(1, 5), "")));
+/// assert_eq!(Interval::<u8>::from_str_front("3..=9,"), Ok((Interval::Inclusive(3, 9), ",")));
+/// assert_eq!(Interval::<i8>::from_str_front("-2.."), Ok((Interval::From(-2), "")));
+///
+/// let (range, _) = Interval::<u8>::from_str_front("..=9")?;
+/// assert!(range.contains(&9));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval<T> {
+    /// `..`, like [`RangeFull`][0].
+    ///
+    /// [0]: std::ops::RangeFull
+    Full,
+
+    /// `a..`, like [`RangeFrom`][0].
+    ///
+    /// [0]: std::ops::RangeFrom
+    From(T),
+
+    /// `..b`, like [`RangeTo`][0].
+    ///
+    /// [0]: std::ops::RangeTo
+    To(T),
+
+    /// `..=b`, like [`RangeToInclusive`][0].
+    ///
+    /// [0]: std::ops::RangeToInclusive
+    ToInclusive(T),
+
+    /// `a..b`, like [`Range`][0].
+    ///
+    /// [0]: std::ops::Range
+    Range(T, T),
+
+    /// `a..=b`, like [`RangeInclusive`][0].
+    ///
+    /// [0]: std::ops::RangeInclusive
+    Inclusive(T, T),
+}
+
+impl<T> RangeBounds<T> for Interval<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        match self {
+            Self::From(start) | Self::Range(start, _) | Self::Inclusive(start, _) => {
+                Bound::Included(start)
+            }
+            Self::Full | Self::To(_) | Self::ToInclusive(_) => Bound::Unbounded,
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        match self {
+            Self::To(end) | Self::Range(_, end) => Bound::Excluded(end),
+            Self::ToInclusive(end) | Self::Inclusive(_, end) => Bound::Included(end),
+            Self::Full | Self::From(_) => Bound::Unbounded,
+        }
+    }
+}
+
+impl<T: FromStrFront> FromStr for Interval<T>
+where
+    <T as FromStrFront>::Error: PartialParseError,
+{
+    type Err = ParseIntervalError<<T as FromStrFront>::Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match Self::from_str_front(input)? {
+            (interval, "") => Ok(interval),
+            _ => Err(ParseIntervalError::Incomplete),
+        }
+    }
+}
+
+impl<T: FromStrFront> FromStrFront for Interval<T>
+where
+    <T as FromStrFront>::Error: PartialParseError,
+{
+    type Error = ParseIntervalError<<T as FromStrFront>::Error>;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        let (start, rest) = if input.starts_with("..") {
+            (None, input)
+        } else {
+            let (start, rest) = T::from_str_front(input).map_err(ParseIntervalError::Start)?;
+            (Some(start), rest)
+        };
+
+        let rest = rest
+            .strip_prefix("..")
+            .ok_or(ParseIntervalError::MissingDots)?;
+
+        // the end is required for inclusive ranges and optional otherwise
+        if let Some(rest) = rest.strip_prefix('=') {
+            let (end, rest) = T::from_str_front(rest).map_err(ParseIntervalError::End)?;
+            return Ok((
+                match start {
+                    Some(start) => Self::Inclusive(start, end),
+                    None => Self::ToInclusive(end),
+                },
+                rest,
+            ));
+        }
+
+        let end = match T::from_str_front(rest) {
+            Ok(end) => Some(end),
+            Err(err) => match err.kind() {
+                // there is no end
+                ParseErrorKind::Insufficient | ParseErrorKind::Invalid => None,
+                _ => return Err(ParseIntervalError::End(err)),
+            },
+        };
+
+        Ok(match (start, end) {
+            (Some(start), Some((end, rest))) => (Self::Range(start, end), rest),
+            (None, Some((end, rest))) => (Self::To(end), rest),
+            (Some(start), None) => (Self::From(start), rest),
+            (None, None) => (Self::Full, rest),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseIntPartialError;
+
+    #[test]
+    fn front() {
+        assert_eq!(
+            Interval::<u8>::from_str_front(".."),
+            Ok((Interval::Full, ""))
+        );
+        assert_eq!(
+            Interval::<u8>::from_str_front("1.. "),
+            Ok((Interval::From(1), " "))
+        );
+        assert_eq!(
+            Interval::<u8>::from_str_front("..5"),
+            Ok((Interval::To(5), ""))
+        );
+        assert_eq!(
+            Interval::<u8>::from_str_front("..=5"),
+            Ok((Interval::ToInclusive(5), ""))
+        );
+        assert_eq!(
+            Interval::<u8>::from_str_front("1..5..7"),
+            Ok((Interval::Range(1, 5), "..7"))
+        );
+        assert_eq!(
+            Interval::<i8>::from_str_front("-5..=-1"),
+            Ok((Interval::Inclusive(-5, -1), ""))
+        );
+        assert_eq!(
+            Interval::<f32>::from_str_front("0.5..1.5"),
+            Ok((Interval::Range(0.5, 1.5), ""))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Interval::<u8>::from_str_front("x..5"),
+            Err(ParseIntervalError::Start(ParseIntPartialError::Invalid))
+        );
+        assert_eq!(
+            Interval::<u8>::from_str_front("1.5"),
+            Err(ParseIntervalError::MissingDots)
+        );
+        assert_eq!(
+            Interval::<u8>::from_str_front("1..=x"),
+            Err(ParseIntervalError::End(ParseIntPartialError::Invalid))
+        );
+        assert_eq!(
+            "1..5!".parse::<Interval<u8>>(),
+            Err(ParseIntervalError::Incomplete)
+        );
+    }
+
+    #[test]
+    fn end_overflow() {
+        assert_eq!(
+            Interval::<u8>::from_str_front("1..300"),
+            Err(ParseIntervalError::End(ParseIntPartialError::Overflow))
+        );
+        assert_eq!(
+            Interval::<u8>::from_str_front("..300"),
+            Err(ParseIntervalError::End(ParseIntPartialError::Overflow))
+        );
+        assert_eq!(
+            "1..300".parse::<Interval<u8>>(),
+            Err(ParseIntervalError::End(ParseIntPartialError::Overflow))
+        );
+        assert_eq!(
+            Interval::<u8>::from_str_front("1..x"),
+            Ok((Interval::From(1), "x"))
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let (range, _) = Interval::<u8>::from_str_front("2..4").unwrap();
+        assert!(!range.contains(&1));
+        assert!(range.contains(&2));
+        assert!(!range.contains(&4));
+        assert!(Interval::<u8>::Full.contains(&255));
+    }
+}
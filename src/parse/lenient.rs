@@ -0,0 +1,103 @@
+use super::{FromStrBack, FromStrFront};
+use std::{ops::Deref, str::FromStr};
+
+/// A wrapper that skips leading whitespace before parsing `T` from the front and trailing
+/// whitespace before parsing `T` from the back. The whitespace on the other side of the parsed
+/// value is left untouched.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{FromStrFront, Lenient};
+///
+/// let mut input = "1 -2\t 3";
+///
+/// // no need to trim the input between the calls
+/// assert_eq!(Lenient::<u8>::yield_front(&mut input).map(Lenient::into_inner), Ok(1));
+/// assert_eq!(Lenient::<i8>::yield_front(&mut input).map(Lenient::into_inner), Ok(-2));
+/// assert_eq!(Lenient::<u8>::yield_front(&mut input).map(Lenient::into_inner), Ok(3));
+/// assert_eq!(input, "");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Lenient<T>(pub T);
+
+impl<T> Lenient<T> {
+    /// Returns the wrapped value.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::Lenient;
+    ///
+    /// assert_eq!(Lenient(3).into_inner(), 3);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Lenient<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: FromStr> FromStr for Lenient<T> {
+    type Err = T::Err;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input.trim().parse().map(Self)
+    }
+}
+
+impl<T: FromStrFront> FromStrFront for Lenient<T> {
+    type Error = <T as FromStrFront>::Error;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        T::from_str_front(input.trim_start()).map(|(value, rest)| (Self(value), rest))
+    }
+}
+
+impl<T: FromStrBack> FromStrBack for Lenient<T> {
+    type Error = <T as FromStrBack>::Error;
+
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+        T::from_str_back(input.trim_end()).map(|(value, rest)| (Self(value), rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseIntPartialError;
+
+    #[test]
+    fn front() {
+        assert_eq!(
+            Lenient::<u8>::from_str_front(" \t12 "),
+            Ok((Lenient(12), " "))
+        );
+        assert_eq!(
+            Lenient::<u8>::from_str_front("   "),
+            Err(ParseIntPartialError::Empty)
+        );
+    }
+
+    #[test]
+    fn back() {
+        assert_eq!(
+            Lenient::<u8>::from_str_back(" 12 \n"),
+            Ok((Lenient(12), " "))
+        );
+        assert_eq!(
+            Lenient::<u8>::from_str_back("a "),
+            Err(ParseIntPartialError::Invalid)
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(" 12\n".parse::<Lenient<u8>>(), Ok(Lenient(12)));
+    }
+}
@@ -0,0 +1,118 @@
+use super::{ParseErrorKind, PartialParseError};
+use crate::split::{self, NonEscapedError};
+use std::str::FromStr;
+
+/// An [`Error`][0] for [`list`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ListError<E> {
+    /// The escape and delimiter were the same.
+    #[error(transparent)]
+    Split(#[from] NonEscapedError),
+
+    /// An element could not be parsed.
+    #[error("the element {index} at offset {offset} could not be parsed: {error}")]
+    Element {
+        /// The index of the element in the list.
+        index: usize,
+
+        /// The byte offset of the element in the input.
+        offset: usize,
+
+        /// The error of the element.
+        #[source]
+        error: E,
+    },
+}
+
+impl<E: PartialParseError> PartialParseError for ListError<E> {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Split(_) => ParseErrorKind::Invalid,
+            Self::Element { error, .. } => error.kind(),
+        }
+    }
+}
+
+/// Splits `input` by `delim` unless it is escaped by `esc`, sanitizes the parts like
+/// [`split::non_escaped_sanitize`] and parses each of them into `T`.
+///
+/// # Errors
+/// Returns an error if:
+/// - `esc == delim`
+/// - any element could not be parsed, the error contains the index and byte offset of the first
+///   failing element
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string, ignoring the
+/// parsing of the elements.
+///
+/// # Allocation
+/// The [`Vec`] is allocated, parts containing escapes are allocated before parsing.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{self, ListError};
+///
+/// assert_eq!(parse::list::<u8>("1,2,3", '\\', ','), Ok(vec![1, 2, 3]));
+/// assert_eq!(
+///     parse::list::<String>(r"a\,b,c", '\\', ','),
+///     Ok(vec!["a,b".to_owned(), "c".to_owned()])
+/// );
+///
+/// let Err(ListError::Element { index, offset, .. }) = parse::list::<u8>("1,2,x", '\\', ',') else {
+///     panic!("the last element is invalid");
+/// };
+/// assert_eq!((index, offset), (2, 4));
+/// ```
+pub fn list<T: FromStr>(input: &str, esc: char, delim: char) -> Result<Vec<T>, ListError<T::Err>> {
+    split::non_escaped(input, esc, delim.into())?
+        .enumerate()
+        .map(|(index, part)| {
+            // parts don't contain any unescaped delimiters, sanitizing them yields exactly one part
+            let sanitized = split::non_escaped_sanitize(part, esc, delim.into())?
+                .next()
+                .unwrap_or_default();
+
+            sanitized.parse().map_err(|error| ListError::Element {
+                index,
+                offset: part.as_ptr() as usize - input.as_ptr() as usize,
+                error,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        assert_eq!(list::<u8>("1", '\\', ','), Ok(vec![1]));
+        assert_eq!(list::<i8>("1;-2;3", '\\', ';'), Ok(vec![1, -2, 3]));
+        assert_eq!(
+            list::<String>(r"a\\,b\c", '\\', ','),
+            Ok(vec![r"a\".to_owned(), r"b\c".to_owned()])
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            list::<u8>("1,,3", '\\', ','),
+            Err(ListError::Element {
+                index: 1,
+                offset: 2,
+                error: "".parse::<u8>().unwrap_err()
+            })
+        );
+        assert_eq!(
+            list::<u8>("1", ',', ','),
+            Err(ListError::Split(NonEscapedError::EscapeContainsDelimiter(
+                ','
+            )))
+        );
+    }
+}
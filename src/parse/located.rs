@@ -0,0 +1,184 @@
+use super::{FromStrBack, FromStrFront, ParseErrorKind, PartialParseError};
+use crate::util::Span;
+
+/// A parse error together with the [`Span`] of the offending region relative to the original
+/// input, useful for pointing at the error in diagnostics.
+///
+/// # Examples
+/// ```
+/// use strtools::{parse::{self, ParseIntPartialError}, util::Span};
+///
+/// let input = "1 2 300 4";
+/// let mut rest = input;
+///
+/// let err = loop {
+///     match parse::yield_front_located::<u8>(input, &mut rest) {
+///         Ok(_) => rest = rest.trim_start(),
+///         Err(err) => break err,
+///     }
+/// };
+///
+/// assert_eq!(err.error, ParseIntPartialError::Overflow);
+/// assert_eq!(err.span, Span::new(4, 7));
+/// assert_eq!(&input[err.span], "300");
+/// ```
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{error} at {span}")]
+pub struct Located<E> {
+    /// The error that occurred.
+    #[source]
+    pub error: E,
+
+    /// The span of the offending region in the original input.
+    pub span: Span,
+}
+
+impl<E: PartialParseError> PartialParseError for Located<E> {
+    fn kind(&self) -> ParseErrorKind {
+        self.error.kind()
+    }
+}
+
+/// Returns the byte offset of `part` in `input`.
+///
+/// # Panics
+/// Panics if `part` is not a sub slice of `input`.
+fn offset_in(input: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    assert!(
+        offset <= input.len() && offset + part.len() <= input.len(),
+        "`rest` must be a sub slice of `input`"
+    );
+    offset
+}
+
+/// Behaves like [`FromStrFront::yield_front`] on `rest`, but on failure returns the error with the
+/// span of the offending region in `input`. The offending region is the run of non whitespace
+/// chars at the start of `rest`.
+///
+/// # Errors
+/// Returns an error if `T` could not be parsed from the front of `rest`.
+///
+/// # Panics
+/// Panics if `rest` is not a sub slice of `input`.
+///
+/// # Examples
+/// ```
+/// use strtools::{parse::{self, ParseBoolError}, util::Span};
+///
+/// let input = "true maybe";
+/// let mut rest = input;
+///
+/// assert_eq!(parse::yield_front_located::<bool>(input, &mut rest), Ok(true));
+/// rest = rest.trim_start();
+///
+/// let err = parse::yield_front_located::<bool>(input, &mut rest).unwrap_err();
+/// assert_eq!(err.error, ParseBoolError);
+/// assert_eq!(err.span, Span::new(5, 10));
+/// ```
+pub fn yield_front_located<T: FromStrFront>(
+    input: &str,
+    rest: &mut &str,
+) -> Result<T, Located<T::Error>> {
+    let start = offset_in(input, rest);
+
+    T::yield_front(rest).map_err(|error| {
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+        Located {
+            error,
+            span: Span::new(start, start + len),
+        }
+    })
+}
+
+/// Behaves like [`FromStrBack::yield_back`] on `rest`, but on failure returns the error with the
+/// span of the offending region in `input`. The offending region is the run of non whitespace
+/// chars at the end of `rest`.
+///
+/// # Errors
+/// Returns an error if `T` could not be parsed from the back of `rest`.
+///
+/// # Panics
+/// Panics if `rest` is not a sub slice of `input`.
+///
+/// # Examples
+/// ```
+/// use strtools::{parse, util::Span};
+///
+/// let input = "total: 12a";
+/// let mut rest = input;
+///
+/// let err = parse::yield_back_located::<u8>(input, &mut rest).unwrap_err();
+/// assert_eq!(err.span, Span::new(7, 10));
+/// ```
+pub fn yield_back_located<T: FromStrBack>(
+    input: &str,
+    rest: &mut &str,
+) -> Result<T, Located<T::Error>> {
+    let end = offset_in(input, rest) + rest.len();
+
+    T::yield_back(rest).map_err(|error| {
+        let start = rest.rfind(char::is_whitespace).map_or(0, |idx| {
+            idx + rest[idx..].chars().next().map_or(0, char::len_utf8)
+        });
+
+        Located {
+            error,
+            span: Span::new(end - (rest.len() - start), end),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseIntPartialError;
+
+    #[test]
+    fn front() {
+        let input = "12 x3";
+        let mut rest = input;
+
+        assert_eq!(yield_front_located::<u8>(input, &mut rest), Ok(12));
+        rest = &rest[1..];
+        assert_eq!(
+            yield_front_located::<u8>(input, &mut rest),
+            Err(Located {
+                error: ParseIntPartialError::Invalid,
+                span: Span::new(3, 5)
+            })
+        );
+        assert_eq!(rest, "x3");
+
+        let mut rest = &input[5..];
+        assert_eq!(
+            yield_front_located::<u8>(input, &mut rest),
+            Err(Located {
+                error: ParseIntPartialError::Empty,
+                span: Span::empty(5)
+            })
+        );
+    }
+
+    #[test]
+    fn back() {
+        let input = "a\u{3000}300";
+        let mut rest = input;
+
+        assert_eq!(
+            yield_back_located::<u8>(input, &mut rest),
+            Err(Located {
+                error: ParseIntPartialError::Overflow,
+                span: Span::new(4, 7)
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn foreign_rest() {
+        let mut rest = "12";
+        let _ = yield_front_located::<u8>("other", &mut rest);
+    }
+}
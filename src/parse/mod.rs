@@ -4,11 +4,80 @@
 
 use std::str::FromStr;
 
-// TODO: floats and other notable types
+// TODO: other notable types
+
+mod byte_size;
+pub use byte_size::{ByteSize, ParseByteSizeError};
+
+mod bytes;
+pub use bytes::{FromBytesBack, FromBytesFront};
+
+mod color;
+pub use color::{Color, ParseColorError};
+
+pub mod combinator;
+
+pub mod datetime;
+
+#[cfg(feature = "derive")]
+mod derive;
+#[cfg(feature = "derive")]
+pub use derive::{DeriveError, FromStrBack, FromStrFront};
+
+mod duration;
+pub use duration::{duration_front, ParseDurationError};
+
+mod error;
+pub use error::{ParseErrorKind, PartialParseError};
+
+mod float;
+pub use float::{FromStrPartialFloatRadixExt, ParseFloatPartialError};
+
+mod format;
+pub use format::{FromStrPartialFormatExt, NumberFormat};
+
+mod ident;
+pub use ident::{ident_front, ident_with, ParseIdentError};
+
+mod interval;
+pub use interval::{Interval, ParseIntervalError};
+
+mod lenient;
+pub use lenient::Lenient;
+
+mod list;
+pub use list::{list, ListError};
+
+mod located;
+pub use located::{yield_back_located, yield_front_located, Located};
+
+mod net;
+pub use net::ParseAddrPartialError;
 
 mod num;
 pub use num::{FromStrPartialRadixExt, ParseIntPartialError};
 
+mod optional;
+pub use optional::Optional;
+
+mod percent;
+pub use percent::{ParsePercentError, Percent};
+
+mod quoted;
+pub use quoted::{quoted_front, ParseQuotedError};
+
+mod ratio;
+pub use ratio::{ParseRatioError, Ratio};
+
+mod search;
+pub use search::{find_parse, iter, Iter};
+
+mod tuple;
+pub use tuple::{ParseTupleError, Tuple};
+
+mod version;
+pub use version::{ParseVersionError, Version};
+
 /// Types that may try parsing from the beginning of a [`str`]. While [`FromStr`] generally requires
 /// the whole input to be a valid representation of `Self`, this trait tries to parse until it
 /// encounters unknown input and ignores it. Since it is trivial to [`FromStr`] if [`FromStrFront`]
@@ -260,10 +329,10 @@ pub trait FromStrBack: Sized + FromStr {
 /// ```
 pub macro forward {
     (front for $type:ty) => {
-        forward!(front for $type; |_, _| ::std::default::Default::default())
+        forward!(front for $type; |_, _| ::std::default::Default::default());
     },
     (back for $type:ty) => {
-        forward!(back for $type; |_, _| ::std::default::Default::default())
+        forward!(back for $type; |_, _| ::std::default::Default::default());
     },
     (front for $type:ty; |$value:pat_param, $rest:pat_param| $rest_err:expr) => {
         impl ::std::str::FromStr for $type {
@@ -302,6 +371,12 @@ pub macro forward {
 #[error("invalid input, expected: `'true' | 'false'`")]
 pub struct ParseBoolError;
 
+impl PartialParseError for ParseBoolError {
+    fn kind(&self) -> ParseErrorKind {
+        ParseErrorKind::Invalid
+    }
+}
+
 impl FromStrFront for bool {
     type Error = ParseBoolError;
 
@@ -351,3 +426,33 @@ pub fn yield_literal_back(input: &mut &str, literal: &str) -> bool {
         false
     }
 }
+
+/// Implements back parsing for types that are easier to parse from the front, the longest suffix of
+/// chars for which `is_part` returns true is tried from the longest to the shortest candidate until
+/// `parse_front` consumes a candidate completely.
+pub(crate) fn from_str_back_via_front<T, E>(
+    input: &str,
+    is_part: impl Fn(char) -> bool,
+    parse_front: impl Fn(&str) -> Result<(T, &str), E>,
+    empty: impl FnOnce() -> E,
+    invalid: impl FnOnce() -> E,
+) -> Result<(T, &str), E> {
+    if input.is_empty() {
+        return Err(empty());
+    }
+
+    let start = input
+        .char_indices()
+        .rev()
+        .take_while(|&(_, ch)| is_part(ch))
+        .last()
+        .map_or(input.len(), |(idx, _)| idx);
+
+    for (idx, _) in input[start..].char_indices() {
+        if let Ok((value, "")) = parse_front(&input[start + idx..]) {
+            return Ok((value, &input[..start + idx]));
+        }
+    }
+
+    Err(invalid())
+}
@@ -0,0 +1,266 @@
+use super::{
+    from_str_back_via_front, FromStrBack, FromStrFront, ParseErrorKind, PartialParseError,
+};
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    str::FromStr,
+};
+
+/// An [`Error`][0] for [`FromStrFront`]/[`FromStrBack`] implementations of [`IpAddr`],
+/// [`Ipv4Addr`], [`Ipv6Addr`] and [`SocketAddr`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseAddrPartialError {
+    /// The input did not contain a valid address.
+    #[error("invalid input, expected an ip or socket address")]
+    Invalid,
+
+    /// The input was empty.
+    #[error("empty input, expected an ip or socket address")]
+    Empty,
+}
+
+impl PartialParseError for ParseAddrPartialError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Invalid => ParseErrorKind::Invalid,
+            Self::Empty => ParseErrorKind::Insufficient,
+        }
+    }
+}
+
+/// Parses the longest prefix of `input` of at most `max_len` chars for which `is_part` returns
+/// true, an address that is directly followed by a char for which `continues` returns true is
+/// rejected as it was cut off in the middle of a group.
+fn longest_front<T: FromStr>(
+    input: &str,
+    max_len: usize,
+    is_part: impl Fn(u8) -> bool,
+    continues: impl Fn(&T, char) -> bool,
+) -> Result<(T, &str), ParseAddrPartialError> {
+    if input.is_empty() {
+        return Err(ParseAddrPartialError::Empty);
+    }
+
+    // all chars of addresses are ascii, so byte offsets are char offsets
+    let run = input
+        .bytes()
+        .take(max_len)
+        .take_while(|&byte| is_part(byte))
+        .count();
+
+    for len in (1..=run).rev() {
+        if let Ok(addr) = input[..len].parse::<T>() {
+            let rest = &input[len..];
+            return match rest.chars().next() {
+                Some(next) if continues(&addr, next) => Err(ParseAddrPartialError::Invalid),
+                _ => Ok((addr, rest)),
+            };
+        }
+    }
+
+    Err(ParseAddrPartialError::Invalid)
+}
+
+/// Parses an address from the back of `input`, an address that is directly preceded by a char
+/// for which `continues` returns true is rejected as it was cut off in the middle of a group.
+fn longest_back<T: FromStrFront<Error = ParseAddrPartialError>>(
+    input: &str,
+    is_part: impl Fn(u8) -> bool,
+    continues: impl Fn(&T, char) -> bool,
+) -> Result<(T, &str), ParseAddrPartialError> {
+    let (addr, rest) = from_str_back_via_front(
+        input,
+        |ch| ch.is_ascii() && is_part(ch as u8),
+        T::from_str_front,
+        || ParseAddrPartialError::Empty,
+        || ParseAddrPartialError::Invalid,
+    )?;
+
+    match rest.chars().next_back() {
+        Some(prev) if continues(&addr, prev) => Err(ParseAddrPartialError::Invalid),
+        _ => Ok((addr, rest)),
+    }
+}
+
+fn is_v4_part(byte: u8) -> bool {
+    byte.is_ascii_digit() || byte == b'.'
+}
+
+fn is_v6_part(byte: u8) -> bool {
+    byte.is_ascii_hexdigit() || matches!(byte, b':' | b'.')
+}
+
+fn is_socket_part(byte: u8) -> bool {
+    is_v6_part(byte) || matches!(byte, b'[' | b']')
+}
+
+fn continues_ip(addr: &IpAddr, ch: char) -> bool {
+    match addr {
+        IpAddr::V4(_) => ch.is_ascii_digit(),
+        IpAddr::V6(_) => ch.is_ascii_hexdigit(),
+    }
+}
+
+// the longest textual representations, `ffff:ffff:ffff:ffff:ffff:ffff:255.255.255.255` for ipv6
+// and `[ffff:ffff:ffff:ffff:ffff:ffff:255.255.255.255]:65535` for socket addresses
+const V4_MAX_LEN: usize = 15;
+const V6_MAX_LEN: usize = 45;
+const SOCKET_MAX_LEN: usize = V6_MAX_LEN + 8;
+
+impl FromStrFront for Ipv4Addr {
+    type Error = ParseAddrPartialError;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        longest_front(input, V4_MAX_LEN, is_v4_part, |_, ch| ch.is_ascii_digit())
+    }
+}
+
+impl FromStrBack for Ipv4Addr {
+    type Error = ParseAddrPartialError;
+
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+        longest_back(input, is_v4_part, |_: &Self, ch| ch.is_ascii_digit())
+    }
+}
+
+impl FromStrFront for Ipv6Addr {
+    type Error = ParseAddrPartialError;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        longest_front(input, V6_MAX_LEN, is_v6_part, |_, ch| {
+            ch.is_ascii_hexdigit()
+        })
+    }
+}
+
+impl FromStrBack for Ipv6Addr {
+    type Error = ParseAddrPartialError;
+
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+        longest_back(input, is_v6_part, |_: &Self, ch| ch.is_ascii_hexdigit())
+    }
+}
+
+impl FromStrFront for IpAddr {
+    type Error = ParseAddrPartialError;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        longest_front(input, V6_MAX_LEN, is_v6_part, continues_ip)
+    }
+}
+
+impl FromStrBack for IpAddr {
+    type Error = ParseAddrPartialError;
+
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+        longest_back(input, is_v6_part, continues_ip)
+    }
+}
+
+impl FromStrFront for SocketAddr {
+    type Error = ParseAddrPartialError;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        longest_front(input, SOCKET_MAX_LEN, is_socket_part, |_, ch| {
+            ch.is_ascii_digit()
+        })
+    }
+}
+
+impl FromStrBack for SocketAddr {
+    type Error = ParseAddrPartialError;
+
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+        longest_back(input, is_socket_part, |addr: &Self, ch| match addr {
+            SocketAddr::V4(_) => ch.is_ascii_digit(),
+            SocketAddr::V6(_) => false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ipv4() {
+        assert_eq!(
+            Ipv4Addr::from_str_front("10.0.0.1:8080"),
+            Ok((Ipv4Addr::new(10, 0, 0, 1), ":8080"))
+        );
+        assert_eq!(
+            Ipv4Addr::from_str_front("1.2.3.4.5"),
+            Ok((Ipv4Addr::new(1, 2, 3, 4), ".5"))
+        );
+        assert_eq!(
+            Ipv4Addr::from_str_front("1.2.3.456"),
+            Err(ParseAddrPartialError::Invalid)
+        );
+        assert_eq!(
+            Ipv4Addr::from_str_back("from 10.0.0.1"),
+            Ok((Ipv4Addr::new(10, 0, 0, 1), "from "))
+        );
+        assert_eq!(
+            Ipv4Addr::from_str_back("999.1.2.3"),
+            Err(ParseAddrPartialError::Invalid)
+        );
+        assert_eq!(
+            Ipv4Addr::from_str_front(""),
+            Err(ParseAddrPartialError::Empty)
+        );
+    }
+
+    #[test]
+    fn ipv6() {
+        assert_eq!(
+            Ipv6Addr::from_str_front("::1 is localhost"),
+            Ok((Ipv6Addr::LOCALHOST, " is localhost"))
+        );
+        assert_eq!(
+            Ipv6Addr::from_str_back("localhost is ::1"),
+            Ok((Ipv6Addr::LOCALHOST, "localhost is "))
+        );
+        assert_eq!(
+            Ipv6Addr::from_str_front("1::23456"),
+            Err(ParseAddrPartialError::Invalid)
+        );
+    }
+
+    #[test]
+    fn ip() {
+        assert_eq!(
+            IpAddr::from_str_front("1.2.3.4abc"),
+            Ok((IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)), "abc"))
+        );
+        assert_eq!(
+            IpAddr::from_str_front("::1]"),
+            Ok((IpAddr::V6(Ipv6Addr::LOCALHOST), "]"))
+        );
+    }
+
+    #[test]
+    fn socket() {
+        assert_eq!(
+            SocketAddr::from_str_front("10.0.0.1:8080 at noon"),
+            Ok(("10.0.0.1:8080".parse().unwrap(), " at noon"))
+        );
+        assert_eq!(
+            SocketAddr::from_str_front("[::1]:443, ..."),
+            Ok(("[::1]:443".parse().unwrap(), ", ..."))
+        );
+        assert_eq!(
+            SocketAddr::from_str_back("from 10.0.0.1:8080"),
+            Ok(("10.0.0.1:8080".parse().unwrap(), "from "))
+        );
+        assert_eq!(
+            SocketAddr::from_str_front("10.0.0.1:65536"),
+            Err(ParseAddrPartialError::Invalid)
+        );
+        assert_eq!(
+            SocketAddr::from_str_front("10.0.0.1"),
+            Err(ParseAddrPartialError::Invalid)
+        );
+    }
+}
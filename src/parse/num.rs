@@ -1,5 +1,8 @@
 use crate::{
-    parse::{FromStrBack, FromStrFront},
+    parse::{
+        from_str_back_via_front, FromStrBack, FromStrFront, FromStrPartialFormatExt, NumberFormat,
+        ParseErrorKind, PartialParseError,
+    },
     util,
 };
 use std::fmt::Debug;
@@ -30,6 +33,16 @@ pub enum ParseIntPartialError {
     Empty,
 }
 
+impl PartialParseError for ParseIntPartialError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Overflow | Self::Underflow => ParseErrorKind::Overflow,
+            Self::Invalid => ParseErrorKind::Invalid,
+            Self::Empty => ParseErrorKind::Insufficient,
+        }
+    }
+}
+
 /// An extension for all integers that adds `from_str_radix` equivalents of the [`FromStrFront`] &
 /// [`FromStrBack`] functions, see it's documentation for more info.
 pub trait FromStrPartialRadixExt: util::sealed::Sealed + FromStrFront + FromStrBack {
@@ -46,6 +59,100 @@ pub trait FromStrPartialRadixExt: util::sealed::Sealed + FromStrFront + FromStrB
         input: &str,
         radix: u32,
     ) -> Result<(Self, &str), <Self as FromStrBack>::Error>;
+
+    /// Behaves like [`FromStrPartialRadixExt::from_str_radix_front`], but skips `_` between digits
+    /// like in rust integer literals. Leading and trailing underscores are not part of the number.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromStrPartialRadixExt;
+    ///
+    /// assert_eq!(u32::from_str_radix_front_underscored("1_000_000", 10), Ok((1_000_000, "")));
+    /// assert_eq!(u32::from_str_radix_front_underscored("1_000_ km", 10), Ok((1_000, "_ km")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_radix_front_underscored(
+        input: &str,
+        radix: u32,
+    ) -> Result<(Self, &str), <Self as FromStrFront>::Error>;
+
+    /// Behaves like [`FromStrPartialRadixExt::from_str_radix_back`], but skips `_` between digits
+    /// like in rust integer literals. Leading and trailing underscores are not part of the number.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromStrPartialRadixExt;
+    ///
+    /// assert_eq!(i32::from_str_radix_back_underscored("-1_000_000", 10), Ok((-1_000_000, "")));
+    /// assert_eq!(u32::from_str_radix_back_underscored("id_1_000", 10), Ok((1_000, "id_")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_radix_back_underscored(
+        input: &str,
+        radix: u32,
+    ) -> Result<(Self, &str), <Self as FromStrBack>::Error>;
+
+    /// Behaves like [`FromStrPartialRadixExt::from_str_radix_front`], but clamps to the minimum or
+    /// maximum value of the type instead of returning an error on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromStrPartialRadixExt;
+    ///
+    /// assert_eq!(u8::from_str_front_saturating("1000 items", 10), Ok((255, " items")));
+    /// assert_eq!(i8::from_str_front_saturating("-1000", 10), Ok((-128, "")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_front_saturating(
+        input: &str,
+        radix: u32,
+    ) -> Result<(Self, &str), <Self as FromStrFront>::Error>;
+
+    /// Behaves like [`FromStrPartialRadixExt::from_str_radix_back`], but clamps to the minimum or
+    /// maximum value of the type instead of returning an error on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromStrPartialRadixExt;
+    ///
+    /// assert_eq!(u8::from_str_back_saturating("items: 1000", 10), Ok((255, "items: ")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_back_saturating(
+        input: &str,
+        radix: u32,
+    ) -> Result<(Self, &str), <Self as FromStrBack>::Error>;
+
+    /// Behaves like [`FromStrPartialRadixExt::from_str_radix_front`], but wraps around at the
+    /// boundaries of the type instead of returning an error on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromStrPartialRadixExt;
+    ///
+    /// assert_eq!(u8::from_str_front_wrapping("257 items", 10), Ok((1, " items")));
+    /// assert_eq!(i8::from_str_front_wrapping("-129", 10), Ok((127, "")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_front_wrapping(
+        input: &str,
+        radix: u32,
+    ) -> Result<(Self, &str), <Self as FromStrFront>::Error>;
+
+    /// Behaves like [`FromStrPartialRadixExt::from_str_radix_back`], but wraps around at the
+    /// boundaries of the type instead of returning an error on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::FromStrPartialRadixExt;
+    ///
+    /// assert_eq!(u8::from_str_back_wrapping("items: 257", 10), Ok((1, "items: ")));
+    /// ```
+    #[allow(clippy::missing_errors_doc)]
+    fn from_str_back_wrapping(
+        input: &str,
+        radix: u32,
+    ) -> Result<(Self, &str), <Self as FromStrBack>::Error>;
 }
 
 // Most of the implementations details match those form `std::str::FromStr` for integers with the
@@ -55,16 +162,35 @@ pub trait FromStrPartialRadixExt: util::sealed::Sealed + FromStrFront + FromStrB
 trait FromStrRadixHelper: Copy {
     const IS_SIGNED: bool;
     const ZERO: Self;
+    const MIN: Self;
+    const MAX: Self;
 
-    fn checked_neg(self) -> Option<Self>;
     fn checked_mul(self, other: u32) -> Option<Self>;
     fn checked_sub(self, other: u32) -> Option<Self>;
     fn checked_add(self, other: u32) -> Option<Self>;
+    fn wrapping_mul(self, other: u32) -> Self;
+    fn wrapping_sub(self, other: u32) -> Self;
+    fn wrapping_add(self, other: u32) -> Self;
+}
+
+/// How overflow is handled when accumulating digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Overflow {
+    /// Return [`ParseIntPartialError::Overflow`] or [`ParseIntPartialError::Underflow`].
+    Error,
+
+    /// Clamp to the minimum or maximum value.
+    Saturate,
+
+    /// Wrap around at the boundaries of the type.
+    Wrap,
 }
 
 fn from_str_radix_front<T: FromStrRadixHelper>(
     input: &str,
     radix: u32,
+    overflow: Overflow,
+    scan: impl FnOnce(&str) -> usize,
 ) -> Result<(T, &str), ParseIntPartialError> {
     assert!(
         matches!(radix, 2..=36),
@@ -88,67 +214,22 @@ fn from_str_radix_front<T: FromStrRadixHelper>(
         return Err(ParseIntPartialError::Empty);
     }
 
-    let iter = rest
-        .as_bytes()
-        .iter()
-        .enumerate()
-        .map(|(idx, &byte)| (idx, (byte as char).to_digit(radix)));
-
-    let mut num = false;
-    let mut buf = T::ZERO;
-    let mut rest_start = 0;
-    if is_neg {
-        for (idx, maybe_digit) in iter {
-            let sub = match maybe_digit {
-                Some(val) => {
-                    rest_start = idx + 1;
-                    val
-                }
-                None => {
-                    rest_start = idx;
-                    break;
-                }
-            };
-
-            num = true;
-            buf = buf
-                .checked_mul(radix)
-                .ok_or(ParseIntPartialError::Underflow)?;
-            buf = buf
-                .checked_sub(sub)
-                .ok_or(ParseIntPartialError::Underflow)?;
-        }
-    } else {
-        for (idx, maybe_digit) in iter {
-            let add = match maybe_digit {
-                Some(val) => {
-                    rest_start = idx + 1;
-                    val
-                }
-                None => {
-                    rest_start = idx;
-                    break;
-                }
-            };
-
-            num = true;
-            buf = buf
-                .checked_mul(radix)
-                .ok_or(ParseIntPartialError::Overflow)?;
-            buf = buf.checked_add(add).ok_or(ParseIntPartialError::Overflow)?;
-        }
+    let end = scan(rest);
+    if end == 0 {
+        return Err(ParseIntPartialError::Invalid);
     }
 
-    if num {
-        Ok((buf, &rest[rest_start..]))
-    } else {
-        Err(ParseIntPartialError::Invalid)
-    }
+    Ok((
+        accumulate(&rest[..end], radix, is_neg, overflow)?,
+        &rest[end..],
+    ))
 }
 
 fn from_str_radix_back<T: FromStrRadixHelper>(
     input: &str,
     radix: u32,
+    overflow: Overflow,
+    sep: Option<char>,
 ) -> Result<(T, &str), ParseIntPartialError> {
     assert!(
         matches!(radix, 2..=36),
@@ -160,80 +241,109 @@ fn from_str_radix_back<T: FromStrRadixHelper>(
         return Err(ParseIntPartialError::Empty);
     }
 
-    let mut num = false;
-    let mut buf = T::ZERO;
-    let mut len = 0;
-    let mut factor = Some(1);
-    let iter = input.as_bytes().iter().rev();
-
-    if T::IS_SIGNED {
-        let mut is_neg = false;
-        for &byte in iter {
-            let sub = match (byte as char).to_digit(radix) {
-                Some(val) => val,
-                None => {
-                    match byte {
-                        b'-' => {
-                            len += 1;
-                            is_neg = true;
-                        }
-                        b'+' => len += 1,
-                        _ => {}
-                    }
-
-                    break;
-                }
-            };
+    let start = scan_digits_back(input, radix, sep);
+    if start == input.len() {
+        return Err(ParseIntPartialError::Invalid);
+    }
 
-            len += 1;
-            num = true;
+    let (is_neg, rest_end) = match input.as_bytes()[..start] {
+        [.., b'-'] if T::IS_SIGNED => (true, start - 1),
+        [.., b'+'] => (false, start - 1),
+        _ => (false, start),
+    };
 
-            let fac = factor.ok_or(ParseIntPartialError::Underflow)?;
-            buf = fac
-                .checked_mul(sub)
-                .and_then(|s| buf.checked_sub(s))
-                .ok_or(ParseIntPartialError::Underflow)?;
-            factor = fac.checked_mul(radix);
-        }
+    Ok((
+        accumulate(&input[start..], radix, is_neg, overflow)?,
+        &input[..rest_end],
+    ))
+}
+
+/// Returns the length of the leading digits in `input`, a separator is only included if it is
+/// preceded and followed by digits.
+pub(super) fn scan_digits_front(input: &str, radix: u32, sep: Option<char>) -> usize {
+    let mut end = 0;
 
-        // we're using a neg buffer to fit the lower most value if it occurs, then if it's not neg
-        // we invert it returning none if it's too large to fit the positive equivalent
-        // allows parsing `-128..127` for u8
-        if !is_neg {
-            buf = buf.checked_neg().ok_or(ParseIntPartialError::Overflow)?;
+    for (idx, ch) in input.char_indices() {
+        if ch.is_digit(radix) {
+            end = idx + ch.len_utf8();
+        } else if end == 0 || Some(ch) != sep {
+            break;
         }
-    } else {
-        for &byte in iter {
-            let add = match (byte as char).to_digit(radix) {
-                Some(val) => val,
-                None => {
-                    if byte == b'+' {
-                        len += 1;
-                    }
-
-                    break;
-                }
-            };
+    }
+
+    end
+}
+
+/// Returns the length of the leading digits in `input`, `sep` is only included if it separates
+/// groups of exactly three digits after a leading group of one to three digits.
+pub(super) fn scan_grouped_digits_front(input: &str, radix: u32, sep: char) -> usize {
+    let count_digits = |input: &str| input.chars().take_while(|ch| ch.is_digit(radix)).count();
+
+    // digits are always single byte chars
+    let mut end = count_digits(input);
+    if !(1..=3).contains(&end) {
+        return end;
+    }
 
-            len += 1;
-            num = true;
+    while let Some(group) = input[end..].strip_prefix(sep)
+        && count_digits(group) == 3
+    {
+        end += sep.len_utf8() + 3;
+    }
+
+    end
+}
 
-            let fac = factor.ok_or(ParseIntPartialError::Overflow)?;
-            buf = fac
-                .checked_mul(add)
-                .and_then(|a| buf.checked_add(a))
-                .ok_or(ParseIntPartialError::Overflow)?;
+/// Returns the start of the trailing digits in `input`, a separator is only included if it is
+/// preceded and followed by digits.
+fn scan_digits_back(input: &str, radix: u32, sep: Option<char>) -> usize {
+    let mut start = input.len();
 
-            // return error next time
-            factor = fac.checked_mul(radix);
+    for (idx, ch) in input.char_indices().rev() {
+        if ch.is_digit(radix) {
+            start = idx;
+        } else if start == input.len() || Some(ch) != sep {
+            break;
         }
     }
 
-    if num {
-        Ok((buf, &input[..input.len() - len]))
-    } else {
-        Err(ParseIntPartialError::Invalid)
+    start
+}
+
+/// Accumulates the given `digits` into `T`, skipping any chars that are not digits in `radix`. A
+/// negative buffer is used for negative numbers to fit the lower most value of signed types.
+fn accumulate<T: FromStrRadixHelper>(
+    digits: &str,
+    radix: u32,
+    is_neg: bool,
+    overflow: Overflow,
+) -> Result<T, ParseIntPartialError> {
+    let mut buf = T::ZERO;
+
+    for digit in digits.chars().filter_map(|ch| ch.to_digit(radix)) {
+        buf = match (overflow, is_neg) {
+            (Overflow::Wrap, true) => buf.wrapping_mul(radix).wrapping_sub(digit),
+            (Overflow::Wrap, false) => buf.wrapping_mul(radix).wrapping_add(digit),
+            (_, true) => match buf
+                .checked_mul(radix)
+                .and_then(|buf| buf.checked_sub(digit))
+            {
+                Some(buf) => buf,
+                None if overflow == Overflow::Saturate => return Ok(T::MIN),
+                None => return Err(ParseIntPartialError::Underflow),
+            },
+            (_, false) => match buf
+                .checked_mul(radix)
+                .and_then(|buf| buf.checked_add(digit))
+            {
+                Some(buf) => buf,
+                None if overflow == Overflow::Saturate => return Ok(T::MAX),
+                None => return Err(ParseIntPartialError::Overflow),
+            },
+        };
     }
+
+    Ok(buf)
 }
 
 // currently we wouldn't be able to parse `-2^size` because it would overflow before being flipped
@@ -243,11 +353,8 @@ macro_rules! int_impl {
         impl FromStrRadixHelper for $int {
             const IS_SIGNED: bool = true;
             const ZERO: Self = 0;
-
-            #[inline]
-            fn checked_neg(self) -> Option<Self> {
-                self.checked_neg()
-            }
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
 
             #[inline]
             fn checked_mul(self, other: u32) -> Option<Self> {
@@ -263,6 +370,21 @@ macro_rules! int_impl {
             fn checked_add(self, other: u32) -> Option<Self> {
                 Self::checked_add(self, other as Self)
             }
+
+            #[inline]
+            fn wrapping_mul(self, other: u32) -> Self {
+                Self::wrapping_mul(self, other as Self)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, other: u32) -> Self {
+                Self::wrapping_sub(self, other as Self)
+            }
+
+            #[inline]
+            fn wrapping_add(self, other: u32) -> Self {
+                Self::wrapping_add(self, other as Self)
+            }
         }
 
         int_impl!($int);
@@ -271,11 +393,8 @@ macro_rules! int_impl {
         impl FromStrRadixHelper for $int {
             const IS_SIGNED: bool = false;
             const ZERO: Self = 0;
-
-            #[inline]
-            fn checked_neg(self) -> Option<Self> {
-                Some(self)
-            }
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
 
             #[inline]
             fn checked_mul(self, other: u32) -> Option<Self> {
@@ -291,11 +410,58 @@ macro_rules! int_impl {
             fn checked_add(self, other: u32) -> Option<Self> {
                 Self::checked_add(self, other as Self)
             }
+
+            #[inline]
+            fn wrapping_mul(self, other: u32) -> Self {
+                Self::wrapping_mul(self, other as Self)
+            }
+
+            #[inline]
+            fn wrapping_sub(self, other: u32) -> Self {
+                Self::wrapping_sub(self, other as Self)
+            }
+
+            #[inline]
+            fn wrapping_add(self, other: u32) -> Self {
+                Self::wrapping_add(self, other as Self)
+            }
         }
 
         int_impl!($int);
     };
     ($int:ty) => {
+        impl FromStrPartialFormatExt for $int {
+            fn from_str_front_formatted<'s>(
+                input: &'s str,
+                format: &NumberFormat,
+            ) -> Result<(Self, &'s str), <Self as FromStrFront>::Error> {
+                match format.group_separator {
+                    Some(sep) => from_str_radix_front(input, 10, Overflow::Error, |rest| {
+                        scan_grouped_digits_front(rest, 10, sep)
+                    }),
+                    None => from_str_radix_front(input, 10, Overflow::Error, |rest| {
+                        scan_digits_front(rest, 10, None)
+                    }),
+                }
+            }
+
+            fn from_str_back_formatted<'s>(
+                input: &'s str,
+                format: &NumberFormat,
+            ) -> Result<(Self, &'s str), <Self as FromStrBack>::Error> {
+                match format.group_separator {
+                    Some(sep) => from_str_back_via_front(
+                        input,
+                        |ch| ch.is_ascii_digit() || matches!(ch, '+' | '-') || ch == sep,
+                        |input| Self::from_str_front_formatted(input, format),
+                        || ParseIntPartialError::Empty,
+                        || ParseIntPartialError::Invalid,
+                    ),
+                    None => Self::from_str_back(input),
+                }
+            }
+        }
+
         impl FromStrFront for $int {
             type Error = ParseIntPartialError;
 
@@ -317,14 +483,64 @@ macro_rules! int_impl {
                 input: &str,
                 radix: u32,
             ) -> Result<(Self, &str), <Self as FromStrFront>::Error> {
-                from_str_radix_front(input, radix)
+                from_str_radix_front(input, radix, Overflow::Error, |rest| {
+                    scan_digits_front(rest, radix, None)
+                })
             }
 
             fn from_str_radix_back(
                 input: &str,
                 radix: u32,
             ) -> Result<(Self, &str), <Self as FromStrBack>::Error> {
-                from_str_radix_back(input, radix)
+                from_str_radix_back(input, radix, Overflow::Error, None)
+            }
+
+            fn from_str_radix_front_underscored(
+                input: &str,
+                radix: u32,
+            ) -> Result<(Self, &str), <Self as FromStrFront>::Error> {
+                from_str_radix_front(input, radix, Overflow::Error, |rest| {
+                    scan_digits_front(rest, radix, Some('_'))
+                })
+            }
+
+            fn from_str_radix_back_underscored(
+                input: &str,
+                radix: u32,
+            ) -> Result<(Self, &str), <Self as FromStrBack>::Error> {
+                from_str_radix_back(input, radix, Overflow::Error, Some('_'))
+            }
+
+            fn from_str_front_saturating(
+                input: &str,
+                radix: u32,
+            ) -> Result<(Self, &str), <Self as FromStrFront>::Error> {
+                from_str_radix_front(input, radix, Overflow::Saturate, |rest| {
+                    scan_digits_front(rest, radix, None)
+                })
+            }
+
+            fn from_str_back_saturating(
+                input: &str,
+                radix: u32,
+            ) -> Result<(Self, &str), <Self as FromStrBack>::Error> {
+                from_str_radix_back(input, radix, Overflow::Saturate, None)
+            }
+
+            fn from_str_front_wrapping(
+                input: &str,
+                radix: u32,
+            ) -> Result<(Self, &str), <Self as FromStrFront>::Error> {
+                from_str_radix_front(input, radix, Overflow::Wrap, |rest| {
+                    scan_digits_front(rest, radix, None)
+                })
+            }
+
+            fn from_str_back_wrapping(
+                input: &str,
+                radix: u32,
+            ) -> Result<(Self, &str), <Self as FromStrBack>::Error> {
+                from_str_radix_back(input, radix, Overflow::Wrap, None)
             }
         }
     };
@@ -390,6 +606,81 @@ mod tests {
                 Err(ParseIntPartialError::Overflow)
             );
         }
+
+        #[test]
+        fn saturating() {
+            assert_eq!(u8::from_str_front_saturating("2550!", 10), Ok((255, "!")));
+            assert_eq!(i8::from_str_front_saturating("-129", 10), Ok((-128, "")));
+            assert_eq!(i8::from_str_front_saturating("-12", 10), Ok((-12, "")));
+            assert_eq!(
+                u8::from_str_front_saturating("-1", 10),
+                Err(ParseIntPartialError::Invalid)
+            );
+        }
+
+        #[test]
+        fn wrapping() {
+            assert_eq!(u8::from_str_front_wrapping("256!", 10), Ok((0, "!")));
+            assert_eq!(i8::from_str_front_wrapping("128", 10), Ok((-128, "")));
+            assert_eq!(u16::from_str_front_wrapping("1ffff", 16), Ok((0xffff, "")));
+        }
+
+        #[test]
+        fn underscored() {
+            assert_eq!(
+                u32::from_str_radix_front_underscored("1_000_000", 10),
+                Ok((1_000_000, ""))
+            );
+            assert_eq!(
+                i32::from_str_radix_front_underscored("-1__0", 10),
+                Ok((-10, ""))
+            );
+            assert_eq!(
+                u32::from_str_radix_front_underscored("1_000_", 10),
+                Ok((1_000, "_"))
+            );
+            assert_eq!(
+                u32::from_str_radix_front_underscored("_1", 10),
+                Err(ParseIntPartialError::Invalid)
+            );
+            assert_eq!(
+                u8::from_str_radix_front_underscored("2_56", 10),
+                Err(ParseIntPartialError::Overflow)
+            );
+            assert_eq!(u32::from_str_radix_front("1_000", 10), Ok((1, "_000")));
+        }
+
+        #[test]
+        fn grouped() {
+            let format = NumberFormat::new('.', Some(','));
+            assert_eq!(
+                u32::from_str_front_formatted("1,234,567.89", &format),
+                Ok((1_234_567, ".89"))
+            );
+            assert_eq!(
+                i32::from_str_front_formatted("-123,456", &format),
+                Ok((-123_456, ""))
+            );
+            assert_eq!(
+                u32::from_str_front_formatted("1,23", &format),
+                Ok((1, ",23"))
+            );
+            assert_eq!(
+                u32::from_str_front_formatted("1,2345", &format),
+                Ok((1, ",2345"))
+            );
+            assert_eq!(
+                u32::from_str_front_formatted("1234,567", &format),
+                Ok((1234, ",567"))
+            );
+            assert_eq!(
+                u32::from_str_front_formatted(
+                    "1\u{2009}234",
+                    &NumberFormat::new(',', Some('\u{2009}'))
+                ),
+                Ok((1234, ""))
+            );
+        }
     }
 
     mod back {
@@ -434,5 +725,59 @@ mod tests {
                 Err(ParseIntPartialError::Overflow)
             );
         }
+
+        #[test]
+        fn saturating() {
+            assert_eq!(u8::from_str_back_saturating("!2550", 10), Ok((255, "!")));
+            assert_eq!(i8::from_str_back_saturating("-129", 10), Ok((-128, "")));
+        }
+
+        #[test]
+        fn wrapping() {
+            assert_eq!(u8::from_str_back_wrapping("!256", 10), Ok((0, "!")));
+            assert_eq!(i8::from_str_back_wrapping("-129", 10), Ok((127, "")));
+        }
+
+        #[test]
+        fn underscored() {
+            assert_eq!(
+                u32::from_str_radix_back_underscored("1_000_000", 10),
+                Ok((1_000_000, ""))
+            );
+            assert_eq!(
+                i32::from_str_radix_back_underscored("-1__0", 10),
+                Ok((-10, ""))
+            );
+            assert_eq!(
+                u32::from_str_radix_back_underscored("_1_000", 10),
+                Ok((1_000, "_"))
+            );
+            assert_eq!(
+                u32::from_str_radix_back_underscored("1_", 10),
+                Err(ParseIntPartialError::Invalid)
+            );
+            assert_eq!(
+                u8::from_str_radix_back_underscored("2_56", 10),
+                Err(ParseIntPartialError::Overflow)
+            );
+            assert_eq!(u32::from_str_radix_back("1_000", 10), Ok((0, "1_")));
+        }
+
+        #[test]
+        fn grouped() {
+            let format = NumberFormat::new('.', Some(','));
+            assert_eq!(
+                u32::from_str_back_formatted("total 1,234,567", &format),
+                Ok((1_234_567, "total "))
+            );
+            assert_eq!(
+                i32::from_str_back_formatted("x-1,000", &format),
+                Ok((-1_000, "x"))
+            );
+            assert_eq!(
+                u32::from_str_back_formatted("12,34", &format),
+                Ok((34, "12,"))
+            );
+        }
     }
 }
@@ -0,0 +1,116 @@
+use super::{FromStrBack, FromStrFront};
+use std::{convert::Infallible, ops::Deref, str::FromStr};
+
+/// A wrapper around [`Option<T>`] which never fails to parse, if `T` could not be parsed it
+/// contains [`None`] and the input is left untouched. This is needed because [`FromStr`] cannot be
+/// implemented for [`Option`] directly.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{FromStrFront, Optional};
+///
+/// let mut input = "-12";
+///
+/// // the sign is optional, no need to match on the error
+/// let sign = Optional::<bool>::yield_front(&mut input)?.into_inner();
+/// let number = i8::yield_front(&mut input)?;
+/// assert_eq!((sign, number), (None, -12));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Optional<T>(pub Option<T>);
+
+impl<T> Optional<T> {
+    /// Returns the wrapped [`Option`].
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::Optional;
+    ///
+    /// assert_eq!(Optional(Some(3)).into_inner(), Some(3));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for Optional<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: FromStr> FromStr for Optional<T> {
+    type Err = T::Err;
+
+    /// Parses an empty input as [`None`] and anything else as `T`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.is_empty() {
+            Ok(Self(None))
+        } else {
+            input.parse().map(|value| Self(Some(value)))
+        }
+    }
+}
+
+impl<T: FromStrFront> FromStrFront for Optional<T> {
+    type Error = Infallible;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        Ok(match T::from_str_front(input) {
+            Ok((value, rest)) => (Self(Some(value)), rest),
+            Err(_) => (Self(None), input),
+        })
+    }
+}
+
+impl<T: FromStrBack> FromStrBack for Optional<T> {
+    type Error = Infallible;
+
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+        Ok(match T::from_str_back(input) {
+            Ok((value, rest)) => (Self(Some(value)), rest),
+            Err(_) => (Self(None), input),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn front() {
+        assert_eq!(
+            Optional::<u8>::from_str_front("12a"),
+            Ok((Optional(Some(12)), "a"))
+        );
+        assert_eq!(
+            Optional::<u8>::from_str_front("a12"),
+            Ok((Optional(None), "a12"))
+        );
+        assert_eq!(Optional::<u8>::from_str_front(""), Ok((Optional(None), "")));
+    }
+
+    #[test]
+    fn back() {
+        assert_eq!(
+            Optional::<u8>::from_str_back("a12"),
+            Ok((Optional(Some(12)), "a"))
+        );
+        assert_eq!(
+            Optional::<u8>::from_str_back("12a"),
+            Ok((Optional(None), "12a"))
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("".parse::<Optional<u8>>(), Ok(Optional(None)));
+        assert_eq!("3".parse::<Optional<u8>>(), Ok(Optional(Some(3))));
+        assert!("a".parse::<Optional<u8>>().is_err());
+    }
+}
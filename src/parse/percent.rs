@@ -0,0 +1,131 @@
+use super::{
+    forward, FromStrBack, FromStrFront, ParseErrorKind, ParseFloatPartialError, PartialParseError,
+};
+
+/// An [`Error`][0] for [`FromStrFront`]/[`FromStrBack`] on [`Percent`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParsePercentError {
+    /// The input was empty.
+    #[error("empty input, expected: `<float> [' ']* '%'`")]
+    Empty,
+
+    /// The input did not contain a valid number.
+    #[error("invalid input, expected: `<float> [' ']* '%'`")]
+    Invalid,
+
+    /// The number was not followed by a `%`.
+    #[error("missing `%` after the number")]
+    MissingPercentSign,
+}
+
+impl PartialParseError for ParsePercentError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Empty => ParseErrorKind::Insufficient,
+            Self::Invalid | Self::MissingPercentSign => ParseErrorKind::Invalid,
+        }
+    }
+}
+
+impl From<ParseFloatPartialError> for ParsePercentError {
+    fn from(err: ParseFloatPartialError) -> Self {
+        match err {
+            ParseFloatPartialError::Invalid => Self::Invalid,
+            ParseFloatPartialError::Empty => Self::Empty,
+        }
+    }
+}
+
+/// A percentage like `50%` or `12.5 %`, the contained value is the fraction, `50%` is `0.5`.
+/// Whitespace between the number and the `%` is ignored.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{FromStrBack, FromStrFront, Percent};
+///
+/// assert_eq!(Percent::from_str_front("50% off"), Ok((Percent(0.5), " off")));
+/// assert_eq!(Percent::from_str_back("battery at 12.5 %"), Ok((Percent(0.125), "battery at ")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Percent(pub f64);
+
+impl From<Percent> for f64 {
+    fn from(percent: Percent) -> Self {
+        percent.0
+    }
+}
+
+impl FromStrFront for Percent {
+    type Error = ParsePercentError;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        let (value, rest) = f64::from_str_front(input)?;
+        let rest = rest
+            .trim_start()
+            .strip_prefix('%')
+            .ok_or(ParsePercentError::MissingPercentSign)?;
+
+        Ok((Self(value / 100.0), rest))
+    }
+}
+
+impl FromStrBack for Percent {
+    type Error = ParsePercentError;
+
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+        if input.is_empty() {
+            return Err(ParsePercentError::Empty);
+        }
+
+        let rest = input
+            .strip_suffix('%')
+            .ok_or(ParsePercentError::MissingPercentSign)?;
+        let (value, rest) = f64::from_str_back(rest.trim_end())?;
+
+        Ok((Self(value / 100.0), rest))
+    }
+}
+
+forward!(front for Percent; |_, _| ParsePercentError::Invalid);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn front() {
+        assert_eq!(Percent::from_str_front("100%"), Ok((Percent(1.0), "")));
+        assert_eq!(Percent::from_str_front("-25 %!"), Ok((Percent(-0.25), "!")));
+        assert_eq!(
+            Percent::from_str_front("25"),
+            Err(ParsePercentError::MissingPercentSign)
+        );
+        assert_eq!(
+            Percent::from_str_front("%"),
+            Err(ParsePercentError::Invalid)
+        );
+        assert_eq!(Percent::from_str_front(""), Err(ParsePercentError::Empty));
+    }
+
+    #[test]
+    fn back() {
+        assert_eq!(Percent::from_str_back("up 5%"), Ok((Percent(0.05), "up ")));
+        assert_eq!(Percent::from_str_back("5\t%"), Ok((Percent(0.05), "")));
+        assert_eq!(
+            Percent::from_str_back("5% "),
+            Err(ParsePercentError::MissingPercentSign)
+        );
+        assert_eq!(
+            Percent::from_str_back("x%"),
+            Err(ParsePercentError::Invalid)
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("50%".parse::<Percent>(), Ok(Percent(0.5)));
+        assert_eq!("50%%".parse::<Percent>(), Err(ParsePercentError::Invalid));
+    }
+}
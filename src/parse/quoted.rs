@@ -0,0 +1,153 @@
+use super::{ParseErrorKind, PartialParseError};
+use std::borrow::Cow;
+
+/// An [`Error`][0] for [`quoted_front`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseQuotedError {
+    /// The input was empty.
+    #[error("empty input, expected a quoted literal")]
+    Empty,
+
+    /// The input did not start with the quote.
+    #[error("invalid input, expected the opening quote {0:?}")]
+    MissingQuote(char),
+
+    /// The closing quote was missing.
+    #[error("unterminated quoted literal")]
+    Unterminated,
+}
+
+impl PartialParseError for ParseQuotedError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Empty | Self::Unterminated => ParseErrorKind::Insufficient,
+            Self::MissingQuote(_) => ParseErrorKind::Invalid,
+        }
+    }
+}
+
+/// Parses a quoted literal from the start of `input`, returns it's unescaped contents and the rest
+/// after the closing quote. Escapes before the quote or the escape itself are removed, all other
+/// escapes are kept. If `quote == esc` a doubled quote is an escaped quote like in SQL.
+///
+/// # Errors
+/// Returns an error if:
+/// - `input` is empty
+/// - `input` doesn't start with `quote`
+/// - the literal is not terminated
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the literal.
+///
+/// # Allocation
+/// If no escapes are removed the contents are borrowed, otherwise a [`String`] is allocated.
+///
+/// # Examples
+/// ```
+/// use std::borrow::Cow;
+/// use strtools::parse::{self, ParseQuotedError};
+///
+/// assert_eq!(
+///     parse::quoted_front(r#""say \"hi\"", rest"#, '"', '\\'),
+///     Ok((Cow::Owned(r#"say "hi""#.to_owned()), ", rest"))
+/// );
+/// assert_eq!(parse::quoted_front("'it''s'", '\'', '\''), Ok((Cow::Owned("it's".to_owned()), "")));
+/// assert_eq!(parse::quoted_front("\"open", '"', '\\'), Err(ParseQuotedError::Unterminated));
+/// ```
+pub fn quoted_front(
+    input: &str,
+    quote: char,
+    esc: char,
+) -> Result<(Cow<'_, str>, &str), ParseQuotedError> {
+    let Some(first) = input.chars().next() else {
+        return Err(ParseQuotedError::Empty);
+    };
+
+    if first != quote {
+        return Err(ParseQuotedError::MissingQuote(quote));
+    }
+
+    let start = quote.len_utf8();
+    let mut result = Cow::Borrowed("");
+    let mut done = start;
+    let mut iter = input[start..]
+        .char_indices()
+        .map(|(idx, ch)| (idx + start, ch))
+        .peekable();
+
+    while let Some((idx, ch)) = iter.next() {
+        if ch == esc
+            && let Some(&(next_idx, next)) = iter.peek()
+            && (next == quote || next == esc)
+        {
+            let mutate = result.to_mut();
+            mutate.push_str(&input[done..idx]);
+            mutate.push(next);
+            done = next_idx + next.len_utf8();
+            iter.next();
+        } else if ch == quote {
+            let rest = &input[idx + quote.len_utf8()..];
+            return Ok((
+                match result {
+                    Cow::Borrowed(_) => Cow::Borrowed(&input[done..idx]),
+                    Cow::Owned(mut owned) => {
+                        owned.push_str(&input[done..idx]);
+                        Cow::Owned(owned)
+                    }
+                },
+                rest,
+            ));
+        }
+    }
+
+    Err(ParseQuotedError::Unterminated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrowed() {
+        let (contents, rest) = quoted_front(r#""abc" def"#, '"', '\\').unwrap();
+        assert!(matches!(contents, Cow::Borrowed("abc")));
+        assert_eq!(rest, " def");
+
+        let (contents, rest) = quoted_front(r#""a\bc""#, '"', '\\').unwrap();
+        assert!(matches!(contents, Cow::Borrowed(r"a\bc")));
+        assert_eq!(rest, "");
+
+        assert_eq!(quoted_front("««»", '«', '\\'), Ok((Cow::Borrowed(""), "»")));
+    }
+
+    #[test]
+    fn escaped() {
+        assert_eq!(
+            quoted_front(r#""a\\" b""#, '"', '\\'),
+            Ok((Cow::Owned(r"a\".to_owned()), r#" b""#))
+        );
+        assert_eq!(
+            quoted_front(r"'\'ä\''", '\'', '\\'),
+            Ok((Cow::Owned("'ä'".to_owned()), ""))
+        );
+        assert_eq!(
+            quoted_front("''''", '\'', '\''),
+            Ok((Cow::Owned("'".to_owned()), ""))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(quoted_front("", '"', '\\'), Err(ParseQuotedError::Empty));
+        assert_eq!(
+            quoted_front("a\"", '"', '\\'),
+            Err(ParseQuotedError::MissingQuote('"'))
+        );
+        assert_eq!(
+            quoted_front(r#""a\""#, '"', '\\'),
+            Err(ParseQuotedError::Unterminated)
+        );
+    }
+}
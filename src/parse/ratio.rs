@@ -0,0 +1,188 @@
+use super::{FromStrBack, FromStrFront, ParseErrorKind, PartialParseError};
+use std::str::FromStr;
+
+/// An [`Error`][0] for [`FromStrFront`]/[`FromStrBack`] on [`Ratio`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseRatioError<E> {
+    /// The numerator could not be parsed.
+    #[error("the numerator could not be parsed: {0}")]
+    Numerator(E),
+
+    /// The numerator and denominator were not separated by a `/`.
+    #[error("expected `/` between numerator and denominator")]
+    MissingSlash,
+
+    /// The denominator could not be parsed.
+    #[error("the denominator could not be parsed: {0}")]
+    Denominator(E),
+
+    /// The denominator was zero.
+    #[error("the denominator must not be zero")]
+    ZeroDenominator,
+
+    /// The ratio was parsed, but the input was not completely consumed, only returned by
+    /// [`FromStr`].
+    #[error("the input was not completely consumed")]
+    Incomplete,
+}
+
+impl<E: PartialParseError> PartialParseError for ParseRatioError<E> {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Numerator(err) | Self::Denominator(err) => err.kind(),
+            Self::MissingSlash | Self::ZeroDenominator => ParseErrorKind::Invalid,
+            Self::Incomplete => ParseErrorKind::Incomplete,
+        }
+    }
+}
+
+/// A fraction like `3/4` or `-1 / 2`, whitespace around the `/` is ignored. The sign is parsed by
+/// `T`, zero denominators are rejected.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{FromStrFront, ParseRatioError, Ratio};
+///
+/// assert_eq!(
+///     Ratio::<u32>::from_str_front("3/4 cup"),
+///     Ok((Ratio { numerator: 3, denominator: 4 }, " cup"))
+/// );
+/// assert_eq!(
+///     Ratio::<i32>::from_str_front("-1 / 2"),
+///     Ok((Ratio { numerator: -1, denominator: 2 }, ""))
+/// );
+/// assert_eq!(Ratio::<u32>::from_str_front("1/0"), Err(ParseRatioError::ZeroDenominator));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ratio<T> {
+    /// The number above the line.
+    pub numerator: T,
+
+    /// The number below the line, never zero if parsed.
+    pub denominator: T,
+}
+
+impl<T: FromStrFront + Default + PartialEq> FromStr for Ratio<T> {
+    type Err = ParseRatioError<<T as FromStrFront>::Error>;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match Self::from_str_front(input)? {
+            (ratio, "") => Ok(ratio),
+            _ => Err(ParseRatioError::Incomplete),
+        }
+    }
+}
+
+impl<T: FromStrFront + Default + PartialEq> FromStrFront for Ratio<T> {
+    type Error = ParseRatioError<<T as FromStrFront>::Error>;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        let (numerator, rest) = T::from_str_front(input).map_err(ParseRatioError::Numerator)?;
+        let rest = rest
+            .trim_start()
+            .strip_prefix('/')
+            .ok_or(ParseRatioError::MissingSlash)?;
+        let (denominator, rest) =
+            T::from_str_front(rest.trim_start()).map_err(ParseRatioError::Denominator)?;
+
+        if denominator == T::default() {
+            return Err(ParseRatioError::ZeroDenominator);
+        }
+
+        Ok((
+            Self {
+                numerator,
+                denominator,
+            },
+            rest,
+        ))
+    }
+}
+
+impl<T: FromStrFront + FromStrBack + Default + PartialEq> FromStrBack for Ratio<T> {
+    type Error = ParseRatioError<<T as FromStrBack>::Error>;
+
+    fn from_str_back(input: &str) -> Result<(Self, &str), Self::Error> {
+        let (denominator, rest) = T::from_str_back(input).map_err(ParseRatioError::Denominator)?;
+        let rest = rest
+            .trim_end()
+            .strip_suffix('/')
+            .ok_or(ParseRatioError::MissingSlash)?;
+        let (numerator, rest) =
+            T::from_str_back(rest.trim_end()).map_err(ParseRatioError::Numerator)?;
+
+        if denominator == T::default() {
+            return Err(ParseRatioError::ZeroDenominator);
+        }
+
+        Ok((
+            Self {
+                numerator,
+                denominator,
+            },
+            rest,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseIntPartialError;
+
+    fn ratio<T>(numerator: T, denominator: T) -> Ratio<T> {
+        Ratio {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[test]
+    fn front() {
+        assert_eq!(Ratio::<u8>::from_str_front("3/4"), Ok((ratio(3, 4), "")));
+        assert_eq!(
+            Ratio::<i8>::from_str_front("-3 /\t4 x"),
+            Ok((ratio(-3, 4), " x"))
+        );
+        assert_eq!(
+            Ratio::<u8>::from_str_front("3 4"),
+            Err(ParseRatioError::MissingSlash)
+        );
+        assert_eq!(
+            Ratio::<u8>::from_str_front("/4"),
+            Err(ParseRatioError::Numerator(ParseIntPartialError::Invalid))
+        );
+        assert_eq!(
+            Ratio::<u8>::from_str_front("3/"),
+            Err(ParseRatioError::Denominator(ParseIntPartialError::Empty))
+        );
+        assert_eq!(
+            Ratio::<u8>::from_str_front("3/0"),
+            Err(ParseRatioError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn back() {
+        assert_eq!(
+            Ratio::<u8>::from_str_back("x 3 / 4"),
+            Ok((ratio(3, 4), "x "))
+        );
+        assert_eq!(Ratio::<i8>::from_str_back("=-1/2"), Ok((ratio(-1, 2), "=")));
+        assert_eq!(
+            Ratio::<u8>::from_str_back("3/0"),
+            Err(ParseRatioError::ZeroDenominator)
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("1/2".parse::<Ratio<u8>>(), Ok(ratio(1, 2)));
+        assert_eq!(
+            "1/2!".parse::<Ratio<u8>>(),
+            Err(ParseRatioError::Incomplete)
+        );
+    }
+}
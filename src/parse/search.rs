@@ -0,0 +1,175 @@
+use super::FromStrFront;
+use std::{iter::FusedIterator, marker::PhantomData};
+
+/// Finds the first position in `input` at which `T` can be parsed from the front, returns `T`
+/// together with the input before and after it. If `T` is rejected at the start of a run of
+/// digits or letters, the rest of that run is skipped, so no value is parsed from the middle of a
+/// rejected token like an overflowing number.
+///
+/// # Examples
+/// ```
+/// use strtools::parse;
+///
+/// let line = "[WARN] request took 1532ms";
+/// assert_eq!(
+///     parse::find_parse::<u32>(line),
+///     Some((1532, "[WARN] request took ", "ms"))
+/// );
+/// assert_eq!(parse::find_parse::<u32>("no numbers"), None);
+///
+/// // `300` overflows, `00` after its first digit is not tried
+/// assert_eq!(parse::find_parse::<u8>("port 300"), None);
+/// ```
+pub fn find_parse<T: FromStrFront>(input: &str) -> Option<(T, &str, &str)> {
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        if let Ok((value, rest)) = T::from_str_front(&input[idx..]) {
+            return Some((value, &input[..idx], rest));
+        }
+
+        // skip the rest of the rejected token
+        if ch.is_alphanumeric() {
+            let same_token =
+                |next: char| next.is_alphanumeric() && next.is_numeric() == ch.is_numeric();
+            while chars.next_if(|&(_, next)| same_token(next)).is_some() {}
+        }
+    }
+
+    None
+}
+
+/// Returns an [Iterator] over every `T` that can be parsed from `input` from left to right, chars
+/// between them that don't start a valid `T` are skipped, see [`find_parse`] for more info.
+///
+/// # Complexity
+/// This algorithm requires `O(n * m)` time where `n` is the length of the input string and `m` is
+/// the time `T` needs to reject invalid input.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::parse;
+///
+/// let values: Vec<i32> = parse::iter("x=3, y=42; z=-7").collect();
+/// assert_eq!(values, [3, 42, -7]);
+/// ```
+pub fn iter<T: FromStrFront>(input: &str) -> Iter<'_, T> {
+    Iter {
+        rest: input,
+        _marker: PhantomData,
+    }
+}
+
+/// An [Iterator] that yields every `T` parsed from a [str]. This struct is created by the [`iter`]
+/// method, see it's documentation for more info.
+#[derive(Debug)]
+pub struct Iter<'input, T> {
+    rest: &'input str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Iter<'_, T> {
+    /// Returns the part of the input that was not yet searched.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse;
+    ///
+    /// let mut iter = parse::iter::<u8>("1, 2, 3");
+    /// iter.next();
+    /// assert_eq!(iter.rest(), ", 2, 3");
+    /// ```
+    pub fn rest(&self) -> &str {
+        self.rest
+    }
+}
+
+impl<T: FromStrFront> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, before, rest) = find_parse::<T>(self.rest)?;
+
+        // make progress even if `T` parsed from an empty str
+        self.rest = if before.len() + rest.len() == self.rest.len() {
+            let mut chars = rest.chars();
+            chars.next();
+            chars.as_str()
+        } else {
+            rest
+        };
+
+        Some(value)
+    }
+}
+
+impl<T: FromStrFront> FusedIterator for Iter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn find() {
+        assert_eq!(find_parse::<u8>("12"), Some((12, "", "")));
+        assert_eq!(find_parse::<i8>("x=-7;"), Some((-7, "x=", ";")));
+        assert_eq!(find_parse::<u8>("äö 3"), Some((3, "äö ", "")));
+        assert_eq!(find_parse::<bool>("it is true"), Some((true, "it is ", "")));
+        assert_eq!(find_parse::<u8>(""), None);
+    }
+
+    #[test]
+    fn find_rejected_token() {
+        assert_eq!(find_parse::<u8>("port 300"), None);
+        assert_eq!(
+            find_parse::<u8>("port 300 or 30"),
+            Some((30, "port 300 or ", ""))
+        );
+        assert_eq!(find_parse::<u8>("ab12"), Some((12, "ab", "")));
+        assert_eq!(find_parse::<bool>("untrue"), None);
+    }
+
+    #[test]
+    fn all() {
+        // `333` overflows, `33` after its first digit is not tried
+        assert_eq!(iter::<u8>("1a22b333").collect::<Vec<_>>(), [1, 22]);
+        assert_eq!(iter::<u16>("1a22b333").collect::<Vec<_>>(), [1, 22, 333]);
+        assert_eq!(iter::<f32>("-1.5,+2").collect::<Vec<_>>(), [-1.5, 2.0]);
+        assert_eq!(iter::<bool>("falsetrue").collect::<Vec<_>>(), [false, true]);
+        assert_eq!(iter::<u8>("none").count(), 0);
+    }
+
+    #[test]
+    fn all_rejected_token() {
+        assert_eq!(iter::<u32>("id 12345678901").next(), None);
+        assert_eq!(iter::<u32>("id 12345678901, 7").collect::<Vec<_>>(), [7]);
+    }
+
+    // parses from an empty str, but only in front of a `|`
+    #[derive(Debug, PartialEq)]
+    struct Bar;
+
+    impl FromStr for Bar {
+        type Err = ();
+
+        fn from_str(input: &str) -> Result<Self, Self::Err> {
+            input.is_empty().then_some(Bar).ok_or(())
+        }
+    }
+
+    impl FromStrFront for Bar {
+        fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+            input.starts_with('|').then_some((Bar, input)).ok_or(())
+        }
+    }
+
+    #[test]
+    fn all_empty() {
+        assert_eq!(iter::<Bar>("a|b|").collect::<Vec<_>>(), [Bar, Bar]);
+        assert_eq!(iter::<Bar>("||").count(), 2);
+    }
+}
@@ -0,0 +1,203 @@
+use super::{FromStrFront, ParseErrorKind, PartialParseError};
+use std::{convert::Infallible, ops::Deref, str::FromStr};
+
+/// An [`Error`][0] for parsing a [`Tuple`] from the front, indicates which element failed to
+/// parse. Unused positions default to [`Infallible`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseTupleError<
+    A,
+    B = Infallible,
+    C = Infallible,
+    D = Infallible,
+    E = Infallible,
+    F = Infallible,
+    G = Infallible,
+    H = Infallible,
+> {
+    /// The first element failed to parse.
+    #[error("the first element could not be parsed: {0}")]
+    First(A),
+
+    /// The second element failed to parse.
+    #[error("the second element could not be parsed: {0}")]
+    Second(B),
+
+    /// The third element failed to parse.
+    #[error("the third element could not be parsed: {0}")]
+    Third(C),
+
+    /// The fourth element failed to parse.
+    #[error("the fourth element could not be parsed: {0}")]
+    Fourth(D),
+
+    /// The fifth element failed to parse.
+    #[error("the fifth element could not be parsed: {0}")]
+    Fifth(E),
+
+    /// The sixth element failed to parse.
+    #[error("the sixth element could not be parsed: {0}")]
+    Sixth(F),
+
+    /// The seventh element failed to parse.
+    #[error("the seventh element could not be parsed: {0}")]
+    Seventh(G),
+
+    /// The eighth element failed to parse.
+    #[error("the eighth element could not be parsed: {0}")]
+    Eighth(H),
+
+    /// All elements were parsed, but the input was not completely consumed, only returned by
+    /// [`FromStr`].
+    #[error("the input was not completely consumed")]
+    Incomplete,
+}
+
+impl<A, B, C, D, E, F, G, H> PartialParseError for ParseTupleError<A, B, C, D, E, F, G, H>
+where
+    A: PartialParseError,
+    B: PartialParseError,
+    C: PartialParseError,
+    D: PartialParseError,
+    E: PartialParseError,
+    F: PartialParseError,
+    G: PartialParseError,
+    H: PartialParseError,
+{
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::First(err) => err.kind(),
+            Self::Second(err) => err.kind(),
+            Self::Third(err) => err.kind(),
+            Self::Fourth(err) => err.kind(),
+            Self::Fifth(err) => err.kind(),
+            Self::Sixth(err) => err.kind(),
+            Self::Seventh(err) => err.kind(),
+            Self::Eighth(err) => err.kind(),
+            Self::Incomplete => ParseErrorKind::Incomplete,
+        }
+    }
+}
+
+/// A wrapper for tuples of up to eight elements which parses the elements in sequence from the
+/// front. This is needed because [`FromStr`] cannot be implemented for tuples directly.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{FromStrFront, ParseTupleError, Tuple};
+///
+/// let mut input = "1.5-2true;";
+///
+/// let (x, y, flag) = Tuple::<(f32, i8, bool)>::yield_front(&mut input)?.into_inner();
+/// assert_eq!((x, y, flag, input), (1.5, -2, true, ";"));
+///
+/// assert!(matches!(
+///     Tuple::<(u8, bool)>::from_str_front("1yes"),
+///     Err(ParseTupleError::Second(_))
+/// ));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Tuple<T>(pub T);
+
+impl<T> Tuple<T> {
+    /// Returns the wrapped tuple.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::parse::Tuple;
+    ///
+    /// assert_eq!(Tuple((1, 'a')).into_inner(), (1, 'a'));
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Tuple<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+macro_rules! tuple_impl {
+    ($($type:ident $var:ident $variant:ident),+) => {
+        impl<$($type: FromStrFront),+> FromStr for Tuple<($($type,)+)> {
+            type Err = ParseTupleError<$(<$type as FromStrFront>::Error),+>;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                match Self::from_str_front(input)? {
+                    (value, "") => Ok(value),
+                    _ => Err(ParseTupleError::Incomplete),
+                }
+            }
+        }
+
+        impl<$($type: FromStrFront),+> FromStrFront for Tuple<($($type,)+)> {
+            type Error = ParseTupleError<$(<$type as FromStrFront>::Error),+>;
+
+            fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+                let mut rest = input;
+                $(let $var = $type::yield_front(&mut rest).map_err(ParseTupleError::$variant)?;)+
+                Ok((Self(($($var,)+)), rest))
+            }
+        }
+    };
+}
+
+tuple_impl!(A a First);
+tuple_impl!(A a First, B b Second);
+tuple_impl!(A a First, B b Second, C c Third);
+tuple_impl!(A a First, B b Second, C c Third, D d Fourth);
+tuple_impl!(A a First, B b Second, C c Third, D d Fourth, E e Fifth);
+tuple_impl!(A a First, B b Second, C c Third, D d Fourth, E e Fifth, F f Sixth);
+tuple_impl!(A a First, B b Second, C c Third, D d Fourth, E e Fifth, F f Sixth, G g Seventh);
+tuple_impl!(
+    A a First,
+    B b Second,
+    C c Third,
+    D d Fourth,
+    E e Fifth,
+    F f Sixth,
+    G g Seventh,
+    H h Eighth
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{ParseBoolError, ParseIntPartialError};
+
+    #[test]
+    fn front() {
+        assert_eq!(
+            Tuple::<(u8, i8, bool)>::from_str_front("1-2true rest"),
+            Ok((Tuple((1, -2, true)), " rest"))
+        );
+        assert_eq!(
+            Tuple::<(u8, bool)>::from_str_front("255false"),
+            Ok((Tuple((255, false)), ""))
+        );
+        assert_eq!(
+            Tuple::<(u8, bool)>::from_str_front("x"),
+            Err(ParseTupleError::First(ParseIntPartialError::Invalid))
+        );
+        assert_eq!(
+            Tuple::<(u8, bool)>::from_str_front("1"),
+            Err(ParseTupleError::Second(ParseBoolError))
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("3true".parse::<Tuple<(u8, bool)>>(), Ok(Tuple((3, true))));
+        assert_eq!(
+            "3true!".parse::<Tuple<(u8, bool)>>(),
+            Err(ParseTupleError::Incomplete)
+        );
+    }
+}
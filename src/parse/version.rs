@@ -0,0 +1,250 @@
+use super::{forward, num::scan_digits_front, FromStrFront, ParseErrorKind, PartialParseError};
+use std::fmt::{self, Display};
+
+/// An [`Error`][0] for the [`FromStrFront`] implementation of [`Version`].
+///
+/// [0]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ParseVersionError {
+    /// A numeric component did not fit into a [`u64`].
+    #[error("the given version component would cause overflow")]
+    Overflow,
+
+    /// The input contained invalid tokens.
+    #[error("invalid input, expected: `<major>.<minor>.<patch>['-' <pre>]['+' <build>]`")]
+    Invalid,
+
+    /// The input was empty.
+    #[error("empty input, expected: `<major>.<minor>.<patch>['-' <pre>]['+' <build>]`")]
+    Empty,
+}
+
+impl PartialParseError for ParseVersionError {
+    fn kind(&self) -> ParseErrorKind {
+        match self {
+            Self::Overflow => ParseErrorKind::Overflow,
+            Self::Invalid => ParseErrorKind::Invalid,
+            Self::Empty => ParseErrorKind::Insufficient,
+        }
+    }
+}
+
+/// A semver-style version like `1.2.3-rc.1+build.5`.
+///
+/// # Examples
+/// ```
+/// use strtools::parse::{FromStrFront, Version};
+///
+/// let (version, rest) = Version::from_str_front("1.2.3-rc.1 something")?;
+/// assert_eq!((version.major, version.minor, version.patch), (1, 2, 3));
+/// assert_eq!(version.pre.as_deref(), Some("rc.1"));
+/// assert_eq!(version.build, None);
+/// assert_eq!(rest, " something");
+/// # Ok::<_, strtools::parse::ParseVersionError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Version {
+    /// The major version.
+    pub major: u64,
+
+    /// The minor version.
+    pub minor: u64,
+
+    /// The patch version.
+    pub patch: u64,
+
+    /// The dot separated pre-release identifiers following a `-` if any.
+    pub pre: Option<String>,
+
+    /// The dot separated build metadata identifiers following a `+` if any.
+    pub build: Option<String>,
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if let Some(pre) = &self.pre {
+            write!(f, "-{}", pre)?;
+        }
+
+        if let Some(build) = &self.build {
+            write!(f, "+{}", build)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a numeric component without leading zeros.
+fn numeric(input: &str) -> Result<(u64, &str), ParseVersionError> {
+    let len = scan_digits_front(input, 10, None);
+    match &input[..len] {
+        "" => Err(ParseVersionError::Invalid),
+        digits if digits.len() > 1 && digits.starts_with('0') => Err(ParseVersionError::Invalid),
+        digits => digits
+            .parse()
+            .map(|value| (value, &input[len..]))
+            .map_err(|_| ParseVersionError::Overflow),
+    }
+}
+
+/// Returns the length of the leading dot separated identifiers, a dot is only included if it's
+/// followed by another identifier. Numeric identifiers must not have leading zeros if `numeric`
+/// is true.
+fn identifiers(input: &str, numeric: bool) -> usize {
+    let is_valid = |ident: &str| {
+        !ident.is_empty()
+            && !(numeric
+                && ident.len() > 1
+                && ident.starts_with('0')
+                && ident.bytes().all(|byte| byte.is_ascii_digit()))
+    };
+
+    let mut end = 0;
+    let mut rest = input;
+    loop {
+        let len = rest
+            .bytes()
+            .take_while(|&byte| byte.is_ascii_alphanumeric() || byte == b'-')
+            .count();
+
+        if !is_valid(&rest[..len]) {
+            break;
+        }
+
+        end = input.len() - rest.len() + len;
+        match rest[len..].strip_prefix('.') {
+            Some(next) => rest = next,
+            None => break,
+        }
+    }
+
+    end
+}
+
+/// Parses the identifiers after `prefix` if there are any, otherwise leaves the input untouched.
+fn suffix(input: &str, prefix: char, numeric: bool) -> (Option<String>, &str) {
+    if let Some(rest) = input.strip_prefix(prefix) {
+        let len = identifiers(rest, numeric);
+        if len != 0 {
+            return (Some(rest[..len].to_owned()), &rest[len..]);
+        }
+    }
+
+    (None, input)
+}
+
+impl FromStrFront for Version {
+    type Error = ParseVersionError;
+
+    fn from_str_front(input: &str) -> Result<(Self, &str), Self::Error> {
+        if input.is_empty() {
+            return Err(ParseVersionError::Empty);
+        }
+
+        let (major, rest) = numeric(input)?;
+        let rest = rest.strip_prefix('.').ok_or(ParseVersionError::Invalid)?;
+        let (minor, rest) = numeric(rest)?;
+        let rest = rest.strip_prefix('.').ok_or(ParseVersionError::Invalid)?;
+        let (patch, rest) = numeric(rest)?;
+        let (pre, rest) = suffix(rest, '-', true);
+        let (build, rest) = suffix(rest, '+', false);
+
+        Ok((
+            Self {
+                major,
+                minor,
+                patch,
+                pre,
+                build,
+            },
+            rest,
+        ))
+    }
+}
+
+forward!(front for Version; |_, _| ParseVersionError::Invalid);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(
+        major: u64,
+        minor: u64,
+        patch: u64,
+        pre: Option<&str>,
+        build: Option<&str>,
+    ) -> Version {
+        Version {
+            major,
+            minor,
+            patch,
+            pre: pre.map(str::to_owned),
+            build: build.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn core() {
+        assert_eq!(
+            Version::from_str_front("1.2.3"),
+            Ok((version(1, 2, 3, None, None), ""))
+        );
+        assert_eq!(
+            Version::from_str_front("10.20.30."),
+            Ok((version(10, 20, 30, None, None), "."))
+        );
+    }
+
+    #[test]
+    fn pre_and_build() {
+        assert_eq!(
+            Version::from_str_front("1.2.3-rc.1 something"),
+            Ok((version(1, 2, 3, Some("rc.1"), None), " something"))
+        );
+        assert_eq!(
+            Version::from_str_front("1.0.0-alpha-beta+exp.sha.5114f85"),
+            Ok((
+                version(1, 0, 0, Some("alpha-beta"), Some("exp.sha.5114f85")),
+                ""
+            ))
+        );
+        assert_eq!(
+            Version::from_str_front("1.0.0+001"),
+            Ok((version(1, 0, 0, None, Some("001")), ""))
+        );
+        assert_eq!(
+            Version::from_str_front("1.0.0-rc..1"),
+            Ok((version(1, 0, 0, Some("rc"), None), "..1"))
+        );
+        assert_eq!(
+            Version::from_str_front("1.0.0-01"),
+            Ok((version(1, 0, 0, None, None), "-01"))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(Version::from_str_front(""), Err(ParseVersionError::Empty));
+        assert_eq!(
+            Version::from_str_front("1.2"),
+            Err(ParseVersionError::Invalid)
+        );
+        assert_eq!(
+            Version::from_str_front("01.2.3"),
+            Err(ParseVersionError::Invalid)
+        );
+        assert_eq!(
+            Version::from_str_front("1.2.99999999999999999999"),
+            Err(ParseVersionError::Overflow)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let input = "1.0.0-alpha.1+build.5";
+        assert_eq!(input.parse::<Version>().unwrap().to_string(), input);
+    }
+}
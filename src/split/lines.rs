@@ -0,0 +1,103 @@
+use crate::util::LineEnding;
+use std::iter::FusedIterator;
+
+/// Returns an [Iterator] over the lines of `input` including their line endings, together with
+/// the kind of line ending or [`None`] for a last line without one. Lines end with `'\n'`,
+/// `"\r\n"` or a lone `'\r'`, like in [`StrTools::normalize_newlines`][0]. Concatenating all
+/// lines yields `input` again, which allows rewriting text without losing its line endings.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::{split, util::LineEnding};
+///
+/// let lines: Vec<_> = split::lines_with_endings("a\nb\r\nc\rd").collect();
+/// assert_eq!(
+///     lines,
+///     [
+///         ("a\n", Some(LineEnding::Lf)),
+///         ("b\r\n", Some(LineEnding::CrLf)),
+///         ("c\r", Some(LineEnding::Cr)),
+///         ("d", None),
+///     ]
+/// );
+/// ```
+///
+/// [0]: crate::StrTools::normalize_newlines
+pub fn lines_with_endings(input: &str) -> LinesWithEndings<'_> {
+    LinesWithEndings { rest: input }
+}
+
+/// An [Iterator] that yields the lines of a [str] including their line endings. This struct is
+/// created by the [`lines_with_endings`] method, see it's documentation for more info.
+#[derive(Debug, Clone)]
+pub struct LinesWithEndings<'input> {
+    rest: &'input str,
+}
+
+impl<'s> Iterator for LinesWithEndings<'s> {
+    type Item = (&'s str, Option<LineEnding>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let Some((idx, ending)) = LineEnding::find(self.rest) else {
+            return Some((std::mem::take(&mut self.rest), None));
+        };
+
+        let (line, rest) = self.rest.split_at(idx + ending.as_str().len());
+        self.rest = rest;
+
+        Some((line, Some(ending)))
+    }
+}
+
+impl<'s> FusedIterator for LinesWithEndings<'s> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(lines_with_endings("").next(), None);
+    }
+
+    #[test]
+    fn trailing_ending() {
+        assert_eq!(
+            lines_with_endings("a\n\n").collect::<Vec<_>>(),
+            [("a\n", Some(LineEnding::Lf)), ("\n", Some(LineEnding::Lf))]
+        );
+    }
+
+    #[test]
+    fn lone_carriage_return() {
+        assert_eq!(
+            lines_with_endings("a\rb\r\r\n").collect::<Vec<_>>(),
+            [
+                ("a\r", Some(LineEnding::Cr)),
+                ("b\r", Some(LineEnding::Cr)),
+                ("\r\n", Some(LineEnding::CrLf))
+            ]
+        );
+    }
+
+    #[test]
+    fn lossless() {
+        let input = "a\r\n\nb\r\rc";
+        assert_eq!(
+            lines_with_endings(input)
+                .map(|(line, _)| line)
+                .collect::<String>(),
+            input
+        );
+    }
+}
@@ -5,6 +5,9 @@ use crate::util::Sorted;
 mod char_boundary;
 pub use char_boundary::*;
 
+mod lines;
+pub use lines::*;
+
 mod non_escaped;
 pub use non_escaped::*;
 
@@ -47,6 +50,43 @@ pub fn n_times<'s, const N: usize>(
     (res, unsafe { input.get_unchecked(prev..) })
 }
 
+/// Splits a string into `N + 1` pieces, this is a fallible version of [`n_times`].
+///
+/// # Errors
+/// Returns an error if:
+/// - an index is out of bounds, `index > input.len()`
+/// - an index is not on a UTF-8 sequence boundary
+///
+/// # Examples
+/// ```
+/// # use strtools::split::{self, CharBoundaryError};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let ([a, b], c) = split::try_n_times("aöböc", &[3, 4].try_into()?)?;
+/// assert_eq!((a, b, c), ("aö", "b", "öc"));
+///
+/// // ö is 2 bytes long
+/// let result = split::try_n_times("aöböc", &[2].try_into()?);
+/// assert_eq!(result, Err(CharBoundaryError::NotUTF8Boundary(2)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn try_n_times<'s, const N: usize>(
+    input: &'s str,
+    indices: &Sorted<usize, N>,
+) -> Result<([&'s str; N], &'s str), CharBoundaryError> {
+    for &index in indices.iter() {
+        if index > input.len() {
+            return Err(CharBoundaryError::IndexOutOfRange(index, input.len()));
+        }
+
+        if !input.is_char_boundary(index) {
+            return Err(CharBoundaryError::NotUTF8Boundary(index));
+        }
+    }
+
+    Ok(n_times(input, indices))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,6 +115,22 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn try_n_times_errors() {
+        assert_eq!(
+            try_n_times("abcd", &[2, 5].try_into().unwrap()),
+            Err(CharBoundaryError::IndexOutOfRange(5, 4))
+        );
+        assert_eq!(
+            try_n_times("äbcd", &[1].try_into().unwrap()),
+            Err(CharBoundaryError::NotUTF8Boundary(1))
+        );
+        assert_eq!(
+            try_n_times("abcd", &[0, 4].try_into().unwrap()),
+            Ok((["", "abcd"], ""))
+        );
+    }
+
     #[test]
     pub fn n_times_non_repeating() {
         assert_eq!(
@@ -1,4 +1,4 @@
-use crate::util::Sorted;
+use crate::util::{CharMembership, Sorted};
 
 use super::NonEscapedError;
 use std::{borrow::Cow, iter::Peekable, str::CharIndices};
@@ -55,10 +55,38 @@ pub fn non_escaped_sanitize<const N: usize>(
     esc: char,
     delims: Sorted<char, N>,
 ) -> Result<NonEscapedSanitize<'_, N>, NonEscapedError> {
-    if delims.binary_search(&esc).is_ok() {
+    non_escaped_sanitize_with(input, esc, delims)
+}
+
+/// Behaves like [`non_escaped_sanitize`], but `delims` can be any [`CharMembership`], like a
+/// [`CharSet`][0] or a single char.
+///
+/// # Errors
+/// Returns an error if:
+/// - `delims` contains `esc`
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{split, util::CharSet};
+///
+/// let delims: CharSet = [' ', '→'].into_iter().collect();
+/// let parts: Vec<_> = split::non_escaped_sanitize_with(r"a\ b→c", '\\', &delims)?.collect();
+/// assert_eq!(parts, ["a b", "c"]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [0]: crate::util::CharSet
+pub fn non_escaped_sanitize_with<D: CharMembership>(
+    input: &str,
+    esc: char,
+    delims: D,
+) -> Result<NonEscapedSanitizeWith<'_, D>, NonEscapedError> {
+    if delims.contains_char(esc) {
         Err(NonEscapedError::EscapeContainsDelimiter(esc))
     } else {
-        Ok(NonEscapedSanitize {
+        Ok(NonEscapedSanitizeWith {
             input,
             done: 0,
             esc,
@@ -74,18 +102,23 @@ pub fn non_escaped_sanitize<const N: usize>(
 //       counterpart
 
 /// An [Iterator] that yields parts of a [str] that are separated by a delimiter. This struct is
-/// created by the [`non_escaped_sanitize`] method, see it's documentation for more info.
+/// created by the [`non_escaped_sanitize_with`] method, see it's documentation for more info.
 #[derive(Debug)]
-pub struct NonEscapedSanitize<'input, const DELIMITERS: usize> {
+pub struct NonEscapedSanitizeWith<'input, D> {
     input: &'input str,
     done: usize,
     esc: char,
-    delims: Sorted<char, DELIMITERS>,
+    delims: D,
     iter: Peekable<CharIndices<'input>>,
     curr: Option<Cow<'input, str>>,
 }
 
-impl<'s, const N: usize> Iterator for NonEscapedSanitize<'s, N> {
+/// An [Iterator] that yields parts of a [str] that are separated by a delimiter. This type is
+/// returned by the [`non_escaped_sanitize`] method, see it's documentation for more info.
+pub type NonEscapedSanitize<'input, const DELIMITERS: usize> =
+    NonEscapedSanitizeWith<'input, Sorted<char, DELIMITERS>>;
+
+impl<'s, D: CharMembership> Iterator for NonEscapedSanitizeWith<'s, D> {
     type Item = Cow<'s, str>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -95,7 +128,7 @@ impl<'s, const N: usize> Iterator for NonEscapedSanitize<'s, N> {
                 let (next_idx, escaped) = self.iter.next().unwrap();
 
                 let mutate = self.curr.as_mut().unwrap().to_mut();
-                if escaped != self.esc && self.delims.binary_search(&escaped).is_err() {
+                if escaped != self.esc && !self.delims.contains_char(escaped) {
                     mutate.push(self.esc);
                 }
 
@@ -105,7 +138,7 @@ impl<'s, const N: usize> Iterator for NonEscapedSanitize<'s, N> {
             }
 
             // normal delimiter
-            if self.delims.binary_search(&ch).is_ok() {
+            if self.delims.contains_char(ch) {
                 self.done = idx + ch.len_utf8();
                 return self.curr.replace(Cow::Borrowed(""));
             }
@@ -114,7 +147,7 @@ impl<'s, const N: usize> Iterator for NonEscapedSanitize<'s, N> {
             let mut jump = idx + ch.len_utf8();
 
             while let Some(&(i, ch)) = self.iter.peek()
-                && (ch != self.esc && self.delims.binary_search(&ch).is_err())
+                && (ch != self.esc && !self.delims.contains_char(ch))
             {
                 jump = i + ch.len_utf8();
                 let _ = self.iter.next();
@@ -150,6 +183,7 @@ impl<'s, const N: usize> Iterator for NonEscapedSanitize<'s, N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::CharSet;
 
     macro_rules! test_impl {
         ($split:expr; $from:literal => [$($to:literal),+]) => {
@@ -231,6 +265,20 @@ mod tests {
         assert!(res[3].is_borrowed());
     }
 
+    #[test]
+    fn char_set() {
+        let delims: CharSet = [':', 'ä'].into_iter().collect();
+        let res = non_escaped_sanitize_with(r"a\äb:cäd", '\\', &delims)
+            .expect("delim and escape are not the same")
+            .collect::<Vec<_>>();
+        assert_eq!(res, ["aäb", "c", "d"]);
+
+        assert_eq!(
+            non_escaped_sanitize_with("", '\\', '\\').unwrap_err(),
+            NonEscapedError::EscapeContainsDelimiter('\\')
+        );
+    }
+
     // the tests in ths module are examples of where this was first used in a private program I made
     // that parsed user input's as `<regex-rule>/<regex-replace>/<regex-flags>`, the given input
     // rules must still allow the separator path to be included somehow
@@ -1,5 +1,9 @@
 use super::NonEscapedError;
-use crate::{split, util::Sorted};
+use crate::{
+    find::{self, NonEscapedIndices},
+    split,
+    util::{CharMembership, Sorted},
+};
 use std::iter::FusedIterator;
 
 /// Splits a [str] by the given delimiter unless it is preceded by a given escape. This is a
@@ -50,71 +54,145 @@ pub fn non_escaped<const N: usize>(
     esc: char,
     delims: Sorted<char, N>,
 ) -> Result<NonEscaped<'_, N>, NonEscapedError> {
-    if delims.binary_search(&esc).is_ok() {
-        Err(NonEscapedError::EscapeContainsDelimiter(esc))
-    } else {
-        Ok(NonEscaped {
-            rest: Some(input),
-            esc,
-            delims,
-        })
-    }
+    non_escaped_with(input, esc, delims)
+}
+
+/// Behaves like [`non_escaped`], but `delims` can be any [`CharMembership`], like a
+/// [`CharSet`][0] or a single char.
+///
+/// # Errors
+/// Returns an error if:
+/// - `delims` contains `esc`
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{split, util::CharSet};
+///
+/// let delims: CharSet = [' ', '→'].into_iter().collect();
+/// let parts: Vec<_> = split::non_escaped_with(r"a\ b→c", '\\', &delims)?.collect();
+/// assert_eq!(parts, [r"a\ b", "c"]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [0]: crate::util::CharSet
+pub fn non_escaped_with<D: CharMembership>(
+    input: &str,
+    esc: char,
+    delims: D,
+) -> Result<NonEscapedWith<'_, D>, NonEscapedError> {
+    Ok(NonEscapedWith {
+        rest: Some(input),
+        done: 0,
+        indices: find::non_escaped_indices(input, esc, delims)?,
+    })
 }
 
 /// An [Iterator] that yields parts of a [str] that are separated by a delimiter. This struct is
-/// created by the [`non_escaped`] method, see it's documentation for more info.
+/// created by the [`non_escaped_with`] method, see it's documentation for more info.
 #[derive(Debug)]
-pub struct NonEscaped<'input, const DELIMITERS: usize> {
+pub struct NonEscapedWith<'input, D> {
     rest: Option<&'input str>,
-    esc: char,
-    delims: Sorted<char, DELIMITERS>,
+    done: usize,
+    indices: NonEscapedIndices<'input, D>,
 }
 
-impl<'s, const N: usize> Iterator for NonEscaped<'s, N> {
+/// An [Iterator] that yields parts of a [str] that are separated by a delimiter. This type is
+/// returned by the [`non_escaped`] method, see it's documentation for more info.
+pub type NonEscaped<'input, const DELIMITERS: usize> =
+    NonEscapedWith<'input, Sorted<char, DELIMITERS>>;
+
+impl<'s, D: CharMembership> Iterator for NonEscapedWith<'s, D> {
     type Item = &'s str;
 
     fn next(&mut self) -> Option<Self::Item> {
         let rest = self.rest?;
-        let mut iter = rest.char_indices().peekable();
-        let mut is_escaped = false;
-
-        while let Some((idx, ch)) = iter.next() {
-            // escape
-            if ch == self.esc {
-                is_escaped = !is_escaped;
-
-                // are we escaping? if yes continue to next
-                if is_escaped {
-                    continue;
-                }
-
-                // are we at the end? yield rest
-                if iter.peek().is_none() {
-                    break;
-                }
-            }
 
-            // normal delimiter
-            if !is_escaped && self.delims.binary_search(&ch).is_ok() {
-                // SAFETY: correctness of index relies on str::char_indices
-                let (result, _, rest) = unsafe { split::char_boundary_unchecked(rest, idx) };
+        match self.indices.next() {
+            Some(idx) => {
+                // SAFETY: correctness of index relies on str::char_indices, the indices are
+                // yielded in order, so they are always past `done`
+                let (result, delim, rest) =
+                    unsafe { split::char_boundary_unchecked(rest, idx - self.done) };
+                self.done = idx + delim.len_utf8();
                 self.rest = Some(rest);
-                return Some(result);
+                Some(result)
             }
-
-            is_escaped = false;
+            // no delimiter was found, just yield the rest
+            None => self.rest.take(),
         }
+    }
+}
+
+impl<'s, D: CharMembership> FusedIterator for NonEscapedWith<'s, D> {}
+
+/// Behaves like [`non_escaped_with`], but like [`str::split_terminator`], no trailing empty part is
+/// yielded if `input` ends with a non-escaped delimiter. This is useful for formats in which each
+/// record is terminated rather than separated by a delimiter.
+///
+/// # Errors
+/// Returns an error if:
+/// - `delims` contains `esc`
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{split, util::sorted};
+///
+/// let records: Vec<_> = split::non_escaped_terminator(r"a;b\;c;", '\\', sorted![';'])?.collect();
+/// assert_eq!(records, ["a", r"b\;c"]);
+///
+/// // only a single trailing empty part is skipped
+/// let records: Vec<_> = split::non_escaped_terminator("a;;", '\\', sorted![';'])?.collect();
+/// assert_eq!(records, ["a", ""]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn non_escaped_terminator<D: CharMembership>(
+    input: &str,
+    esc: char,
+    delims: D,
+) -> Result<NonEscapedTerminator<'_, D>, NonEscapedError> {
+    Ok(NonEscapedTerminator {
+        inner: non_escaped_with(input, esc, delims)?,
+    })
+}
+
+/// An [Iterator] that yields parts of a [str] that are terminated by a delimiter. This struct is
+/// created by the [`non_escaped_terminator`] method, see it's documentation for more info.
+#[derive(Debug)]
+pub struct NonEscapedTerminator<'input, D> {
+    inner: NonEscapedWith<'input, D>,
+}
+
+impl<'s, D: CharMembership> Iterator for NonEscapedTerminator<'s, D> {
+    type Item = &'s str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let part = self.inner.next()?;
 
-        // no delimiter was found, just yield the rest
-        self.rest.take()
+        // the last part is empty if the input ended with a delimiter
+        if part.is_empty() && self.inner.rest.is_none() {
+            None
+        } else {
+            Some(part)
+        }
     }
 }
 
-impl<'s, const N: usize> FusedIterator for NonEscaped<'s, N> {}
+impl<'s, D: CharMembership> FusedIterator for NonEscapedTerminator<'s, D> {}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::util::{CharSet, Sorted};
 
     macro_rules! test_impl {
         ($split:expr; $from:literal => [$($to:literal),+]) => {
@@ -146,6 +224,17 @@ mod tests {
         test_impl!([':']; r"aaaaa:bbbbb" => ["aaaaa", "bbbbb"]);
     }
 
+    #[test]
+    fn char_set() {
+        let delims: CharSet = [':', '→'].into_iter().collect();
+        assert_eq!(
+            non_escaped_with(r"aa:b\→b→cc", '\\', &delims)
+                .expect("delim and escape are not the same")
+                .collect::<Vec<_>>(),
+            ["aa", r"b\→b", "cc"]
+        );
+    }
+
     #[test]
     fn single_escape() {
         test_impl!([':']; r"aa\:aa:bbbb" => [r"aa\:aa", "bbbb"]);
@@ -164,6 +253,22 @@ mod tests {
         test_impl!([':']; r"aaaa\\\\\:bbbb" => [r"aaaa\\\\\:bbbb"]);
     }
 
+    #[test]
+    fn terminator() {
+        let parts = |input| {
+            non_escaped_terminator(input, '\\', Sorted::new([':']).unwrap())
+                .expect("delim and escape are not the same")
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(parts(""), Vec::<&str>::new());
+        assert_eq!(parts(":"), [""]);
+        assert_eq!(parts("aa:bb"), ["aa", "bb"]);
+        assert_eq!(parts("aa:bb:"), ["aa", "bb"]);
+        assert_eq!(parts(r"aa:bb\:"), ["aa", r"bb\:"]);
+        assert_eq!(parts(r"aa:bb\\:"), ["aa", r"bb\\"]);
+    }
+
     #[test]
     fn ignore_other_escapes() {
         test_impl!([':']; r"aa\.aa:bbbbb" => [r"aa\.aa", "bbbbb"]);
@@ -0,0 +1,168 @@
+use super::CharMembership;
+use std::ops::RangeInclusive;
+
+/// A set of chars that is stored as sorted and coalesced [`RangeInclusive<char>`] entries, this is
+/// useful for large char classes that would be too big for a [`SortedSlice`][0] or
+/// [`CharSet`][1]. Membership checks require `O(log r)` time, where `r` is the amount of ranges.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{split, util::CharRanges};
+///
+/// let cjk: CharRanges = ['\u{4e00}'..='\u{9fff}', '\u{3400}'..='\u{4dbf}'].into_iter().collect();
+/// let parts: Vec<_> = split::non_escaped_with("a中b文c", '\\', &cjk)?.collect();
+/// assert_eq!(parts, ["a", "b", "c"]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [0]: super::SortedSlice
+/// [1]: super::CharSet
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CharRanges(Vec<RangeInclusive<char>>);
+
+impl CharRanges {
+    /// Creates a new empty [`CharRanges`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharRanges;
+    /// let ranges = CharRanges::new();
+    /// assert!(ranges.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Inserts `range` into the set, coalescing it with all overlapping or adjacent ranges. Empty
+    /// ranges are ignored.
+    ///
+    /// # Complexity
+    /// This function runs in `O(r)` time, where `r` is the amount of ranges.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharRanges;
+    /// let mut ranges = CharRanges::new();
+    /// ranges.insert('a'..='c');
+    /// ranges.insert('x'..='z');
+    /// ranges.insert('d'..='f');
+    /// assert_eq!(ranges.ranges(), &['a'..='f', 'x'..='z']);
+    ///
+    /// // there are no chars between these
+    /// ranges.insert('\u{d000}'..='\u{d7ff}');
+    /// ranges.insert('\u{e000}'..='\u{efff}');
+    /// assert_eq!(ranges.ranges().last(), Some(&('\u{d000}'..='\u{efff}')));
+    /// ```
+    pub fn insert(&mut self, range: RangeInclusive<char>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // all ranges in `first..last` overlap or touch the new range
+        let first = self.0.partition_point(|r| !touches(*r.end(), start));
+        let last = self.0.partition_point(|r| touches(end, *r.start()));
+        let last = last.max(first);
+
+        if first < last {
+            start = start.min(*self.0[first].start());
+            end = end.max(*self.0[last - 1].end());
+        }
+
+        self.0.splice(first..last, [start..=end]);
+    }
+
+    /// Returns true if `ch` is part of any range in this set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharRanges;
+    /// let ranges: CharRanges = ['a'..='c', 'x'..='z'].into_iter().collect();
+    /// assert!(ranges.contains('b'));
+    /// assert!(!ranges.contains('d'));
+    /// ```
+    #[inline]
+    pub fn contains(&self, ch: char) -> bool {
+        match self.0.partition_point(|r| *r.start() <= ch) {
+            0 => false,
+            idx => *self.0[idx - 1].end() >= ch,
+        }
+    }
+
+    /// Returns the sorted and coalesced ranges of this set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharRanges;
+    /// let ranges: CharRanges = ['x'..='z', 'b'..='d', 'a'..='c'].into_iter().collect();
+    /// assert_eq!(ranges.ranges(), &['a'..='d', 'x'..='z']);
+    /// ```
+    #[inline]
+    pub fn ranges(&self) -> &[RangeInclusive<char>] {
+        &self.0
+    }
+
+    /// Returns the amount of chars in this set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharRanges;
+    /// let ranges: CharRanges = ['a'..='c', 'x'..='z'].into_iter().collect();
+    /// assert_eq!(ranges.len(), 6);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|r| r.clone().count()).sum()
+    }
+
+    /// Returns true if this set contains no chars.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharRanges;
+    /// assert!(CharRanges::new().is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Returns true if `b` directly follows `a` or comes before it, the surrogate gap between
+/// `'\u{d7ff}'` and `'\u{e000}'` is considered adjacent.
+fn touches(a: char, b: char) -> bool {
+    b <= a || char::from_u32(a as u32 + 1).map_or(a == '\u{d7ff}' && b == '\u{e000}', |n| n == b)
+}
+
+impl CharMembership for CharRanges {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        self.contains(ch)
+    }
+}
+
+impl Extend<RangeInclusive<char>> for CharRanges {
+    fn extend<I: IntoIterator<Item = RangeInclusive<char>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl FromIterator<RangeInclusive<char>> for CharRanges {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<char>>>(iter: I) -> Self {
+        let mut ranges = Self::new();
+        ranges.extend(iter);
+        ranges
+    }
+}
+
+impl From<RangeInclusive<char>> for CharRanges {
+    fn from(value: RangeInclusive<char>) -> Self {
+        let mut ranges = Self::new();
+        ranges.insert(value);
+        ranges
+    }
+}
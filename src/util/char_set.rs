@@ -0,0 +1,206 @@
+use super::{Sorted, SortedSlice, SortedVec};
+
+/// A set of chars that can be queried for membership, this is accepted by functions that match
+/// against a set of chars like [`split::non_escaped_with`][0] or [`escape::charset_with`][1].
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::util::{CharMembership, CharSet, Sorted};
+///
+/// let sorted: Sorted<char, 2> = Sorted::new([',', ';'])?;
+/// let set: CharSet = [',', ';'].into_iter().collect();
+///
+/// assert!(sorted.contains_char(';') && set.contains_char(';'));
+/// assert!(!sorted.contains_char(':') && !set.contains_char(':'));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [0]: crate::split::non_escaped_with
+/// [1]: crate::escape::charset_with
+pub trait CharMembership {
+    /// Returns true if `ch` is part of this set.
+    fn contains_char(&self, ch: char) -> bool;
+}
+
+impl<T: CharMembership + ?Sized> CharMembership for &T {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        (**self).contains_char(ch)
+    }
+}
+
+impl CharMembership for char {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        *self == ch
+    }
+}
+
+impl CharMembership for SortedSlice<char> {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        self.contains(&ch)
+    }
+}
+
+impl<const N: usize> CharMembership for Sorted<char, N> {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        self.contains(&ch)
+    }
+}
+
+impl CharMembership for SortedVec<char> {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        self.contains(&ch)
+    }
+}
+
+/// A set of chars that stores ASCII membership in a bit mask and all other chars in a sorted
+/// list. This makes membership checks for ASCII chars `O(1)` and `O(log m)` otherwise, where `m`
+/// is the amount of non-ASCII chars in the set.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{split, util::CharSet};
+///
+/// let delims: CharSet = [' ', ':', '→'].into_iter().collect();
+/// let parts: Vec<_> = split::non_escaped_with(r"a b\:c→d", '\\', &delims)?.collect();
+/// assert_eq!(parts, ["a", r"b\:c", "d"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CharSet {
+    ascii: u128,
+    non_ascii: Vec<char>,
+}
+
+impl CharSet {
+    /// Creates a new empty [`CharSet`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharSet;
+    /// let set = CharSet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            ascii: 0,
+            non_ascii: Vec::new(),
+        }
+    }
+
+    /// Inserts `ch` into the set, returns true if it was not yet contained.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharSet;
+    /// let mut set = CharSet::new();
+    /// assert!(set.insert('a'));
+    /// assert!(set.insert('ä'));
+    /// assert!(!set.insert('a'));
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn insert(&mut self, ch: char) -> bool {
+        if ch.is_ascii() {
+            let bit = 1_u128 << ch as u32;
+            let inserted = self.ascii & bit == 0;
+            self.ascii |= bit;
+            inserted
+        } else {
+            match self.non_ascii.binary_search(&ch) {
+                Ok(_) => false,
+                Err(idx) => {
+                    self.non_ascii.insert(idx, ch);
+                    true
+                }
+            }
+        }
+    }
+
+    /// Returns true if `ch` is part of this set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharSet;
+    /// let set: CharSet = "aä".chars().collect();
+    /// assert!(set.contains('a'));
+    /// assert!(set.contains('ä'));
+    /// assert!(!set.contains('b'));
+    /// ```
+    #[inline]
+    pub fn contains(&self, ch: char) -> bool {
+        if ch.is_ascii() {
+            self.ascii & (1_u128 << ch as u32) != 0
+        } else {
+            self.non_ascii.binary_search(&ch).is_ok()
+        }
+    }
+
+    /// Returns the amount of chars in this set.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharSet;
+    /// let set: CharSet = "abcä".chars().collect();
+    /// assert_eq!(set.len(), 4);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ascii.count_ones() as usize + self.non_ascii.len()
+    }
+
+    /// Returns true if this set contains no chars.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::CharSet;
+    /// assert!(CharSet::new().is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ascii == 0 && self.non_ascii.is_empty()
+    }
+}
+
+impl CharMembership for CharSet {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        self.contains(ch)
+    }
+}
+
+impl Extend<char> for CharSet {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for ch in iter {
+            self.insert(ch);
+        }
+    }
+}
+
+impl FromIterator<char> for CharSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl From<&SortedSlice<char>> for CharSet {
+    fn from(value: &SortedSlice<char>) -> Self {
+        value.iter().copied().collect()
+    }
+}
+
+impl<const N: usize> From<Sorted<char, N>> for CharSet {
+    fn from(value: Sorted<char, N>) -> Self {
+        value.iter().copied().collect()
+    }
+}
@@ -0,0 +1,139 @@
+use super::sealed::Sealed;
+use std::borrow::Cow;
+
+/// An extension trait for [`Cow<str>`][Cow] as returned by many functions of this crate,
+/// providing helpers that avoid allocating where possible.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::borrow::Cow;
+/// use strtools::{split, util::{sorted, CowStrExt}};
+///
+/// let parts: Vec<_> = split::non_escaped_sanitize(r" a \:b : c", '\\', sorted![':'])?
+///     .map(CowStrExt::trim_cow)
+///     .collect();
+///
+/// assert_eq!(parts, ["a :b", "c"]);
+/// assert!(matches!(parts[1], Cow::Borrowed(_)));
+/// # Ok(())
+/// # }
+/// ```
+pub trait CowStrExt<'a>: Sealed {
+    /// Appends `other` to this, if this is empty `other` is borrowed instead of allocating a
+    /// [`String`].
+    ///
+    /// # Allocation
+    /// If neither this nor `other` is empty and this is borrowed, a [`String`] is allocated and
+    /// both are copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use strtools::util::CowStrExt;
+    ///
+    /// let mut cow = Cow::Borrowed("");
+    /// cow.push_str_cow("abc");
+    /// assert!(matches!(cow, Cow::Borrowed("abc")));
+    ///
+    /// cow.push_str_cow("def");
+    /// assert!(matches!(cow, Cow::Owned(_)));
+    /// assert_eq!(cow, "abcdef");
+    /// ```
+    fn push_str_cow(&mut self, other: &'a str);
+
+    /// Maps the owned value with `f`, a borrowed value is left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use strtools::util::CowStrExt;
+    ///
+    /// let borrowed = Cow::Borrowed("abc").map_owned(|owned| owned.to_uppercase());
+    /// assert_eq!(borrowed, "abc");
+    ///
+    /// let owned: Cow<'_, str> = Cow::Owned("abc".to_owned());
+    /// assert_eq!(owned.map_owned(|owned| owned.to_uppercase()), "ABC");
+    /// ```
+    fn map_owned<F: FnOnce(String) -> String>(self, f: F) -> Cow<'a, str>;
+
+    /// Removes leading and trailing whitespace like [`str::trim`]. A borrowed value stays
+    /// borrowed and an owned value stays owned.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string, any
+    /// leading whitespace of an owned value requires moving the remaining bytes.
+    ///
+    /// # Allocation
+    /// No allocations are done, an owned value is trimmed in place.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use strtools::util::CowStrExt;
+    ///
+    /// assert!(matches!(Cow::Borrowed(" abc ").trim_cow(), Cow::Borrowed("abc")));
+    ///
+    /// let owned: Cow<'_, str> = Cow::Owned(" abc ".to_owned());
+    /// let trimmed = owned.trim_cow();
+    /// assert!(matches!(trimmed, Cow::Owned(_)));
+    /// assert_eq!(trimmed, "abc");
+    /// ```
+    fn trim_cow(self) -> Cow<'a, str>;
+
+    /// Turns this into an owned value if `cond` is true, otherwise this is returned unchanged.
+    ///
+    /// # Allocation
+    /// If `cond` is true and this is borrowed, a [`String`] is allocated and the borrowed value is
+    /// copied over.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use strtools::util::CowStrExt;
+    ///
+    /// assert!(matches!(Cow::Borrowed("abc").into_owned_if(false), Cow::Borrowed("abc")));
+    /// assert!(matches!(Cow::Borrowed("abc").into_owned_if(true), Cow::Owned(_)));
+    /// ```
+    fn into_owned_if(self, cond: bool) -> Cow<'a, str>;
+}
+
+impl<'a> CowStrExt<'a> for Cow<'a, str> {
+    fn push_str_cow(&mut self, other: &'a str) {
+        if self.is_empty() {
+            *self = Cow::Borrowed(other);
+        } else if !other.is_empty() {
+            self.to_mut().push_str(other);
+        }
+    }
+
+    fn map_owned<F: FnOnce(String) -> String>(self, f: F) -> Cow<'a, str> {
+        match self {
+            Cow::Borrowed(borrowed) => Cow::Borrowed(borrowed),
+            Cow::Owned(owned) => Cow::Owned(f(owned)),
+        }
+    }
+
+    fn trim_cow(self) -> Cow<'a, str> {
+        match self {
+            Cow::Borrowed(borrowed) => Cow::Borrowed(borrowed.trim()),
+            Cow::Owned(mut owned) => {
+                let end = owned.trim_end().len();
+                owned.truncate(end);
+
+                let start = end - owned.trim_start().len();
+                owned.drain(..start);
+
+                Cow::Owned(owned)
+            }
+        }
+    }
+
+    fn into_owned_if(self, cond: bool) -> Cow<'a, str> {
+        if cond {
+            Cow::Owned(self.into_owned())
+        } else {
+            self
+        }
+    }
+}
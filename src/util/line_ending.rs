@@ -0,0 +1,92 @@
+use std::fmt::{self, Display, Write};
+
+/// The kinds of line endings found in text, see [`StrTools::normalize_newlines_to`][0] and
+/// [`split::lines_with_endings`][1].
+///
+/// [0]: crate::StrTools::normalize_newlines_to
+/// [1]: crate::split::lines_with_endings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// A line feed `"\n"` as used on Unix-like systems.
+    #[default]
+    Lf,
+
+    /// A carriage return followed by a line feed `"\r\n"` as used on Windows and in many network
+    /// protocols.
+    CrLf,
+
+    /// A lone carriage return `"\r"` as used on classic Mac OS.
+    Cr,
+}
+
+impl LineEnding {
+    /// Returns the line ending as a [`str`].
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::CrLf.as_str(), "\r\n");
+    /// ```
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+            Self::Cr => "\r",
+        }
+    }
+
+    /// Writes `input` to `writer` with all line endings replaced by this one, `"\r\n"` is a single
+    /// line ending.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - writing to `writer` failed
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// No allocations are done.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use strtools::util::LineEnding;
+    ///
+    /// let mut output = String::new();
+    /// LineEnding::CrLf.write_normalized("a\nb\r\nc\rd", &mut output)?;
+    /// assert_eq!(output, "a\r\nb\r\nc\r\nd");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_normalized<W: Write + ?Sized>(self, input: &str, writer: &mut W) -> fmt::Result {
+        let mut rest = input;
+        while let Some((idx, ending)) = Self::find(rest) {
+            writer.write_str(&rest[..idx])?;
+            writer.write_str(self.as_str())?;
+            rest = &rest[idx + ending.as_str().len()..];
+        }
+
+        writer.write_str(rest)
+    }
+
+    /// Returns the byte offset and kind of the first line ending in `input`.
+    pub(crate) fn find(input: &str) -> Option<(usize, Self)> {
+        let idx = input.find(['\r', '\n'])?;
+        let ending = match &input.as_bytes()[idx..] {
+            [b'\r', b'\n', ..] => Self::CrLf,
+            [b'\r', ..] => Self::Cr,
+            _ => Self::Lf,
+        };
+
+        Some((idx, ending))
+    }
+}
+
+impl Display for LineEnding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,132 @@
+use std::ops::Range;
+
+/// An index over the line starts of a [`str`] that converts byte offsets to zero based
+/// `(line, column)` pairs and back, columns are byte offsets into their line. Lines are separated
+/// by `'\n'`, a preceding `'\r'` is treated as part of the line.
+///
+/// # Examples
+/// ```
+/// use strtools::{find, util::LineIndex};
+///
+/// let input = "f(a,\n  b))";
+/// let error = find::validate_balanced(input, &[('(', ')')], &[], '\\').unwrap_err();
+///
+/// // render the error location as a human-friendly one based location
+/// let (line, column) = LineIndex::new(input).line_col(error.span.start).unwrap();
+/// assert_eq!((line + 1, column + 1), (2, 5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LineIndex {
+    starts: Vec<usize>,
+    len: usize,
+}
+
+impl LineIndex {
+    /// Creates a new [`LineIndex`] for `input`.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(n)` time where `n` is the length of the input string.
+    ///
+    /// # Allocation
+    /// A [`Vec`] of the line starts is allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::LineIndex;
+    ///
+    /// let index = LineIndex::new("a\nb\n");
+    /// assert_eq!(index.line_count(), 3);
+    /// ```
+    pub fn new(input: &str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(input.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+
+        Self {
+            starts,
+            len: input.len(),
+        }
+    }
+
+    /// Returns the amount of lines, this is always at least one, a trailing `'\n'` starts a new
+    /// empty line.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::LineIndex;
+    ///
+    /// assert_eq!(LineIndex::new("").line_count(), 1);
+    /// assert_eq!(LineIndex::new("a\nb").line_count(), 2);
+    /// ```
+    #[inline]
+    pub fn line_count(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Returns the zero based line and byte column of `offset`, or [`None`] if `offset` lies past
+    /// the end of the input. The end of the input itself is a valid offset.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(log m)` time where `m` is the amount of lines.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::LineIndex;
+    ///
+    /// let index = LineIndex::new("ab\ncd");
+    /// assert_eq!(index.line_col(0), Some((0, 0)));
+    /// assert_eq!(index.line_col(2), Some((0, 2)));
+    /// assert_eq!(index.line_col(4), Some((1, 1)));
+    /// assert_eq!(index.line_col(5), Some((1, 2)));
+    /// assert_eq!(index.line_col(6), None);
+    /// ```
+    pub fn line_col(&self, offset: usize) -> Option<(usize, usize)> {
+        if offset > self.len {
+            return None;
+        }
+
+        let line = self.starts.partition_point(|&start| start <= offset) - 1;
+        Some((line, offset - self.starts[line]))
+    }
+
+    /// Returns the byte offset of the zero based `line` and byte `column`, or [`None`] if the
+    /// line does not exist or is shorter than `column`. The column right after the last char of a
+    /// line is valid.
+    ///
+    /// # Complexity
+    /// This algorithm requires `O(1)` time.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::LineIndex;
+    ///
+    /// let index = LineIndex::new("ab\ncd");
+    /// assert_eq!(index.offset(1, 1), Some(4));
+    /// assert_eq!(index.offset(0, 2), Some(2));
+    /// assert_eq!(index.offset(0, 3), None);
+    /// assert_eq!(index.offset(2, 0), None);
+    /// ```
+    pub fn offset(&self, line: usize, column: usize) -> Option<usize> {
+        let range = self.line(line)?;
+        (column <= range.len()).then_some(range.start + column)
+    }
+
+    /// Returns the byte range of the zero based `line` without its trailing `'\n'`, or [`None`]
+    /// if the line does not exist.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::LineIndex;
+    ///
+    /// let input = "ab\ncd";
+    /// let index = LineIndex::new(input);
+    /// assert_eq!(index.line(1).map(|range| &input[range]), Some("cd"));
+    /// assert_eq!(index.line(2), None);
+    /// ```
+    pub fn line(&self, line: usize) -> Option<Range<usize>> {
+        let start = *self.starts.get(line)?;
+        let end = self.starts.get(line + 1).map_or(self.len, |&next| next - 1);
+
+        Some(start..end)
+    }
+}
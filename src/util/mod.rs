@@ -9,8 +9,47 @@ pub use sorted_slice::SortedSlice;
 mod sorted;
 pub use sorted::Sorted;
 
+mod sorted_vec;
+pub use sorted_vec::SortedVec;
+
+mod sorted_by;
+pub use sorted_by::{CaseInsensitive, Comparator, SortedBy, SortedSliceBy};
+
+mod char_set;
+pub use char_set::{CharMembership, CharSet};
+
+mod char_ranges;
+pub use char_ranges::CharRanges;
+
+mod pattern;
+pub use pattern::SortedCharSearcher;
+
+mod non_empty_str;
+pub use non_empty_str::{EmptyStrError, NonEmptyStr};
+
+mod line_index;
+pub use line_index::LineIndex;
+
+mod span;
+pub use span::Span;
+
+mod cow;
+pub use cow::CowStrExt;
+
+mod line_ending;
+pub use line_ending::LineEnding;
+
+#[cfg(feature = "unicode-width")]
+mod width;
+#[cfg(feature = "unicode-width")]
+pub use width::*;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
 /// An [Error][e] indicating that a `[T]`/`[T; N]` could not be turned into a
-/// [`SortedSlice`]/[`Sorted`] because it was not sorted according to [`T: PartialOrd`][pord].
+/// [`SortedSlice`]/[`Sorted`] because it was not sorted according to [`T: PartialOrd`][pord], or
+/// that a [`str`] could not be turned into a [`Sorted`] because it did not contain `N` chars.
 ///
 /// [e]: std::error::Error
 /// [pord]: PartialOrd
@@ -19,6 +58,129 @@ pub enum SortedError {
     /// Indicates that a slice/array was not sorted.
     #[error("the slice/array was not sorted")]
     NotSorted,
+
+    /// Indicates that the input did not contain the expected amount of items.
+    #[error("expected {expected} items, found {found}")]
+    Length {
+        /// The amount of items that was expected.
+        expected: usize,
+
+        /// The amount of items that was found.
+        found: usize,
+    },
+}
+
+/// Creates a [`Sorted`] from a list of char or integer constants, verifying at compile time that
+/// they are sorted. This avoids the runtime check and the error handling of [`Sorted::new`].
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{split, util::{sorted, Sorted}};
+///
+/// let parts: Vec<_> = split::non_escaped("a b:c", '\\', sorted![' ', ':'])?.collect();
+/// assert_eq!(parts, ["a", "b", "c"]);
+///
+/// let bytes: Sorted<u8, 3> = sorted![1, 20, 255];
+/// assert_eq!(bytes.into_array(), [1, 20, 255]);
+///
+/// let signed: Sorted<i8, 3> = sorted![-128, -1, 127];
+/// assert_eq!(signed.into_array(), [-128, -1, 127]);
+/// # Ok(())
+/// # }
+/// ```
+/// This will fail to compile:
+/// ```compile_fail
+/// use strtools::{util::{sorted, Sorted}};
+///
+/// // this is not sorted
+/// let sorted: Sorted<char, 3> = sorted!['a', 'c', 'b'];
+/// ```
+/// And so will this, only chars and integers are supported:
+/// ```compile_fail
+/// use strtools::util::sorted;
+///
+/// let sorted = sorted![1.5, 1.2];
+/// ```
+pub macro sorted($($item:expr),* $(,)?) {{
+    let array = const {
+        let array = [$($item),*];
+        ::std::assert!(
+            $crate::util::is_sorted_keys(&array),
+            "the items of sorted! must be sorted"
+        );
+        array
+    };
+
+    // SAFETY: the items have been checked to be sorted at compile time
+    unsafe { $crate::util::Sorted::new_unchecked(array) }
+}}
+
+/// Types that can be checked by [`sorted!`] at compile time, their values are compared by their
+/// bytes interpreted as an integer.
+///
+/// # Safety
+/// The type must have no padding bytes and its [`PartialOrd`] implementation must agree with the
+/// order of its bytes interpreted as a native endian integer of its size, which is signed if
+/// `SIGNED` is true.
+#[doc(hidden)]
+pub unsafe trait SortedKey: sealed::Sealed + Copy {
+    const SIGNED: bool;
+}
+
+macro_rules! sorted_key_impl {
+    ($signed:literal; $($t:ty),+) => {
+        // SAFETY: chars and integers have no padding and are ordered by their value
+        $(unsafe impl SortedKey for $t {
+            const SIGNED: bool = $signed;
+        })+
+    };
+}
+
+sorted_key_impl!(false; char, u8, u16, u32, u64, u128, usize);
+sorted_key_impl!(true; i8, i16, i32, i64, i128, isize);
+
+/// Returns a key of `item` that preserves the order of all values of `T` as an [u128].
+const fn sorted_key<T: SortedKey>(item: &T) -> u128 {
+    let size = std::mem::size_of::<T>();
+    let ptr = (item as *const T).cast::<u8>();
+
+    let mut key = 0;
+    let mut idx = 0;
+    while idx < size {
+        // SAFETY: SortedKey types have no padding, so all bytes of item are initialized
+        let byte = unsafe { *ptr.add(idx) };
+        let shift = if cfg!(target_endian = "little") {
+            idx
+        } else {
+            size - 1 - idx
+        };
+
+        key |= (byte as u128) << (shift * 8);
+        idx += 1;
+    }
+
+    if T::SIGNED {
+        // sign extend and flip the sign bit, so negative values are ordered before positive ones
+        let unused = 128 - size * 8;
+        key = (((key << unused) as i128 >> unused) as u128) ^ (1 << 127);
+    }
+
+    key
+}
+
+/// Returns true if the given items are sorted, this is used by [`sorted!`] to check its items at
+/// compile time.
+#[doc(hidden)]
+pub const fn is_sorted_keys<T: SortedKey>(items: &[T]) -> bool {
+    let mut idx = 1;
+    while idx < items.len() {
+        if sorted_key(&items[idx - 1]) > sorted_key(&items[idx]) {
+            return false;
+        }
+        idx += 1;
+    }
+    true
 }
 
 pub(crate) mod sealed {
@@ -30,9 +192,12 @@ pub(crate) mod sealed {
         };
     }
 
-    impl_trivial!(str);
+    impl_trivial!(str, String, char);
     impl_trivial!(u8, u16, u32, u64, u128, usize);
     impl_trivial!(i8, i16, i32, i64, i128, isize);
+    impl_trivial!(f32, f64);
+
+    impl Sealed for std::borrow::Cow<'_, str> {}
 }
 
 pub(crate) fn slice_from_single<T>(item: &T) -> &[T] {
@@ -0,0 +1,192 @@
+use std::{borrow::Borrow, fmt::Display, num::NonZeroUsize, ops::Deref};
+
+/// An [Error][e] indicating that a [`str`] could not be turned into a [`NonEmptyStr`] because it
+/// was empty.
+///
+/// [e]: std::error::Error
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("the str was empty")]
+pub struct EmptyStrError;
+
+/// Represents a [`str`] that is guaranteed to be non-empty. This is a [DST][dst], therefore
+/// constructors only return references.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use strtools::util::NonEmptyStr;
+/// let non_empty: &NonEmptyStr = NonEmptyStr::new("abc")?;
+/// assert_eq!(non_empty.first(), 'a');
+///
+/// // this is empty
+/// assert!(NonEmptyStr::new("").is_err());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [dst]: https://doc.rust-lang.org/book/ch19-04-advanced-types.html#dynamically-sized-types-and-the-sized-trait
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyStr(str);
+
+impl NonEmptyStr {
+    /// Creates a new [`NonEmptyStr`] from the given `input` if it was not empty.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `input` was empty
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::NonEmptyStr;
+    /// let non_empty: &NonEmptyStr = NonEmptyStr::new("abc")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This will return an error:
+    /// ```should_panic
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::NonEmptyStr;
+    /// // this is empty
+    /// let non_empty: &NonEmptyStr = NonEmptyStr::new("")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn new(input: &str) -> Result<&Self, EmptyStrError> {
+        if input.is_empty() {
+            Err(EmptyStrError)
+        } else {
+            // SAFETY: the str is not empty
+            Ok(unsafe { Self::new_unchecked(input) })
+        }
+    }
+
+    /// Creates a new [`NonEmptyStr`] from the given `input`, assuming it was not empty.
+    ///
+    /// # Safety
+    /// The caller must ensure that:
+    /// - `input` is not empty
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::NonEmptyStr;
+    /// let non_empty: &NonEmptyStr = unsafe { NonEmptyStr::new_unchecked("abc") };
+    /// ```
+    #[inline]
+    pub const unsafe fn new_unchecked(input: &str) -> &Self {
+        // SAFETY:
+        // - the caller must ensure that the str is not empty
+        // - #[repr(transparent)] ensures layout compatibility of &str and &Self
+        // - the lifetime of &Self is the same as `input`
+        unsafe { std::mem::transmute(input) }
+    }
+
+    /// Borrows this as a [`str`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::NonEmptyStr;
+    /// let non_empty: &NonEmptyStr = NonEmptyStr::new("abc")?;
+    /// let str: &str = non_empty.as_str();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the length of the [`str`] in bytes, which is never zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::NonEmptyStr;
+    /// let non_empty: &NonEmptyStr = NonEmptyStr::new("abc")?;
+    /// assert_eq!(non_empty.len().get(), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> NonZeroUsize {
+        // SAFETY: the str is not empty
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    /// Returns the first char of the [`str`], which always exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::NonEmptyStr;
+    /// let non_empty: &NonEmptyStr = NonEmptyStr::new("ähm")?;
+    /// assert_eq!(non_empty.first(), 'ä');
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn first(&self) -> char {
+        // SAFETY: the str is not empty
+        unsafe { self.0.chars().next().unwrap_unchecked() }
+    }
+
+    /// Returns the last char of the [`str`], which always exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::NonEmptyStr;
+    /// let non_empty: &NonEmptyStr = NonEmptyStr::new("ähm")?;
+    /// assert_eq!(non_empty.last(), 'm');
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn last(&self) -> char {
+        // SAFETY: the str is not empty
+        unsafe { self.0.chars().next_back().unwrap_unchecked() }
+    }
+}
+
+impl Display for NonEmptyStr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Deref for NonEmptyStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for NonEmptyStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for NonEmptyStr {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'s> TryFrom<&'s str> for &'s NonEmptyStr {
+    type Error = EmptyStrError;
+
+    fn try_from(value: &'s str) -> Result<Self, Self::Error> {
+        NonEmptyStr::new(value)
+    }
+}
+
+impl<'s> From<&'s NonEmptyStr> for &'s str {
+    fn from(value: &'s NonEmptyStr) -> Self {
+        value.as_str()
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Sorted, SortedSlice, SortedVec};
+use std::str::{
+    pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, SearchStep, Searcher},
+    CharIndices,
+};
+
+/// A [`Searcher`] for a [`SortedSlice<char>`], matching any char it contains. This struct is
+/// created by the [`Pattern`] implementations of the sorted char containers, unlike the
+/// [`Pattern`] of `&[char]` this uses a binary search instead of a linear scan for every char.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use strtools::util::Sorted;
+/// let delims: Sorted<_, 2> = Sorted::new([',', ';'])?;
+///
+/// assert_eq!("a;b,c".find(&delims), Some(1));
+/// assert_eq!("a;b,c".split(&delims).collect::<Vec<_>>(), ["a", "b", "c"]);
+/// assert_eq!(";,a,;".trim_matches(&delims), "a");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SortedCharSearcher<'a, 'b> {
+    haystack: &'a str,
+    chars: CharIndices<'a>,
+    set: &'b SortedSlice<char>,
+}
+
+impl<'a, 'b> SortedCharSearcher<'a, 'b> {
+    fn new(haystack: &'a str, set: &'b SortedSlice<char>) -> Self {
+        Self {
+            haystack,
+            chars: haystack.char_indices(),
+            set,
+        }
+    }
+
+    fn step(&self, found: Option<(usize, char)>) -> SearchStep {
+        match found {
+            Some((idx, ch)) if self.set.contains(&ch) => {
+                SearchStep::Match(idx, idx + ch.len_utf8())
+            }
+            Some((idx, ch)) => SearchStep::Reject(idx, idx + ch.len_utf8()),
+            None => SearchStep::Done,
+        }
+    }
+}
+
+// SAFETY: the steps are yielded from `CharIndices` of the haystack, they are therefore adjacent,
+// non-overlapping and lie on char boundaries
+unsafe impl<'a, 'b> Searcher<'a> for SortedCharSearcher<'a, 'b> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+
+    #[inline]
+    fn next(&mut self) -> SearchStep {
+        let found = self.chars.next();
+        self.step(found)
+    }
+}
+
+// SAFETY: see the implementation of `Searcher`
+unsafe impl<'a, 'b> ReverseSearcher<'a> for SortedCharSearcher<'a, 'b> {
+    #[inline]
+    fn next_back(&mut self) -> SearchStep {
+        let found = self.chars.next_back();
+        self.step(found)
+    }
+}
+
+impl<'a, 'b> DoubleEndedSearcher<'a> for SortedCharSearcher<'a, 'b> {}
+
+impl<'b> Pattern for &'b SortedSlice<char> {
+    type Searcher<'a> = SortedCharSearcher<'a, 'b>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &str) -> Self::Searcher<'_> {
+        SortedCharSearcher::new(haystack, self)
+    }
+}
+
+impl<'b, const N: usize> Pattern for &'b Sorted<char, N> {
+    type Searcher<'a> = SortedCharSearcher<'a, 'b>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &str) -> Self::Searcher<'_> {
+        SortedCharSearcher::new(haystack, self.as_sorted_slice())
+    }
+}
+
+impl<'b> Pattern for &'b SortedVec<char> {
+    type Searcher<'a> = SortedCharSearcher<'a, 'b>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &str) -> Self::Searcher<'_> {
+        SortedCharSearcher::new(haystack, self.as_sorted_slice())
+    }
+}
@@ -0,0 +1,143 @@
+//! Implementations of [`Serialize`] and [`Deserialize`] for the sorted types, these are only
+//! available with the `serde` feature. The sorted types are serialized as sequences, deserializing
+//! them rejects unsorted data, see [`sorted`] for deserializing them by sorting the data instead.
+//!
+//! # Examples
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use serde::Deserialize;
+//! use strtools::util::{Sorted, SortedVec};
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     // rejects unsorted delimiters
+//!     delimiters: Sorted<char, 2>,
+//!
+//!     // sorts the given whitespace chars
+//!     #[serde(deserialize_with = "strtools::util::serde::sorted")]
+//!     whitespace: SortedVec<char>,
+//! }
+//!
+//! let config: Config =
+//!     serde_json::from_str(r#"{ "delimiters": [",", ";"], "whitespace": [" ", "\n"] }"#)?;
+//! assert_eq!(config.whitespace.as_slice(), &['\n', ' ']);
+//!
+//! let config = serde_json::from_str::<Config>(r#"{ "delimiters": [";", ","], "whitespace": [] }"#);
+//! assert!(config.is_err());
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{Sorted, SortedSlice, SortedVec};
+use ::serde::{
+    de::{Error, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{fmt, marker::PhantomData};
+
+impl<T: PartialOrd + Serialize> Serialize for SortedSlice<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<T: PartialOrd + Serialize, const N: usize> Serialize for Sorted<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_sorted_slice().serialize(serializer)
+    }
+}
+
+impl<T: PartialOrd + Serialize> Serialize for SortedVec<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_sorted_slice().serialize(serializer)
+    }
+}
+
+impl<'de, T: PartialOrd + Deserialize<'de>> Deserialize<'de> for SortedVec<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SortedVec::new(Vec::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+impl<'de, T: PartialOrd + Deserialize<'de>, const N: usize> Deserialize<'de> for Sorted<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let array = deserializer.deserialize_seq(ArrayVisitor(PhantomData))?;
+        Sorted::new(array).map_err(D::Error::custom)
+    }
+}
+
+/// A sorted type that can be deserialized from unsorted data by sorting it, see [`sorted`] for
+/// more info.
+pub trait DeserializeSorted<'de>: Sized {
+    /// Deserializes `Self` and sorts the data if it was not sorted.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the data could not be deserialized
+    fn deserialize_sorted<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl<'de, T: Ord + Deserialize<'de>> DeserializeSorted<'de> for SortedVec<T> {
+    fn deserialize_sorted<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(SortedVec::new_sorted(Vec::deserialize(deserializer)?))
+    }
+}
+
+impl<'de, T: Ord + Deserialize<'de>, const N: usize> DeserializeSorted<'de> for Sorted<T, N> {
+    fn deserialize_sorted<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let array = deserializer.deserialize_seq(ArrayVisitor(PhantomData))?;
+        Ok(Sorted::new_sorted(array))
+    }
+}
+
+/// Deserializes a [`Sorted`] or [`SortedVec`] by sorting the data instead of rejecting it if it
+/// was not sorted, this is intended to be used with `#[serde(deserialize_with = "...")]`.
+///
+/// # Errors
+/// Returns an error if:
+/// - the data could not be deserialized
+///
+/// # Examples
+/// ```
+/// use serde::Deserialize;
+/// use strtools::util::Sorted;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "strtools::util::serde::sorted")]
+///     delimiters: Sorted<char, 3>,
+/// }
+/// ```
+#[inline]
+pub fn sorted<'de, D: Deserializer<'de>, S: DeserializeSorted<'de>>(
+    deserializer: D,
+) -> Result<S, D::Error> {
+    S::deserialize_sorted(deserializer)
+}
+
+/// A [`Visitor`] for `[T; N]` of any `N`, serde only implements [`Deserialize`] for arrays of up
+/// to 32 items.
+struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a sequence of {N} items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items: Vec<T> = Vec::with_capacity(N);
+
+        while let Some(item) = seq.next_element()? {
+            if items.len() == N {
+                return Err(A::Error::invalid_length(N + 1, &self));
+            }
+            items.push(item);
+        }
+
+        items
+            .try_into()
+            .map_err(|items: Vec<_>| A::Error::invalid_length(items.len(), &self))
+    }
+}
@@ -1,5 +1,9 @@
 use super::{SortedError, SortedSlice};
-use std::{borrow::Borrow, fmt::Debug, ops::Deref};
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    ops::{Deref, RangeBounds},
+};
 
 /// Represents a `[T; N]` that is guaranteed to be sorted by [`T: PartialOrd`][pord]. Unlike
 /// [Sorted][sorted] this is not a [DST][dst] and thus has a slightly different API.
@@ -116,6 +120,22 @@ impl<T: PartialOrd, const N: usize> Sorted<T, N> {
         &self.0
     }
 
+    /// Returns the inner array `[T; N]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let sorted: Sorted<_, 3> = Sorted::new(['a', 'b', 'c'])?;
+    /// let array: [char; 3] = sorted.into_array();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn into_array(self) -> [T; N] {
+        self.0
+    }
+
     /// Borrows this as a mutable reference to an array `&mut [T; N]`.
     ///
     /// # Safety
@@ -172,8 +192,216 @@ impl<T: PartialOrd, const N: usize> Sorted<T, N> {
         // SAFETY: the array is sorted
         unsafe { SortedSlice::new_unchecked_mut(&mut self.0) }
     }
+
+    /// Returns true if the array contains `item`, see [`SortedSlice::contains`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let sorted: Sorted<_, 3> = Sorted::new(['a', 'b', 'c'])?;
+    /// assert!(sorted.contains(&'b'));
+    /// assert!(!sorted.contains(&'d'));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: Ord,
+    {
+        self.as_sorted_slice().contains(item)
+    }
+
+    /// Returns the index of `item` in the array if it contains it, see
+    /// [`SortedSlice::position`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let sorted: Sorted<_, 3> = Sorted::new(['a', 'b', 'c'])?;
+    /// assert_eq!(sorted.position(&'c'), Some(2));
+    /// assert_eq!(sorted.position(&'d'), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn position(&self, item: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.as_sorted_slice().position(item)
+    }
+
+    /// Returns the contiguous sub slice of all items that lie within `bounds`, see
+    /// [`SortedSlice::range`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let sorted: Sorted<_, 4> = Sorted::new(['a', 'b', 'x', 'y'])?;
+    /// assert_eq!(sorted.range('a'..='m').as_slice(), &['a', 'b']);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn range<R: RangeBounds<T>>(&self, bounds: R) -> &SortedSlice<T>
+    where
+        T: Ord,
+    {
+        self.as_sorted_slice().range(bounds)
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`, see
+    /// [`SortedSlice::windows`].
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let sorted: Sorted<_, 3> = Sorted::new([1, 2, 4])?;
+    /// assert_eq!(sorted.windows(2).count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn windows(
+        &self,
+        size: usize,
+    ) -> impl DoubleEndedIterator<Item = &SortedSlice<T>> + ExactSizeIterator {
+        self.as_sorted_slice().windows(size)
+    }
+
+    /// Returns an iterator over non overlapping chunks of length `size`, see
+    /// [`SortedSlice::chunks`].
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let sorted: Sorted<_, 3> = Sorted::new([1, 2, 4])?;
+    /// assert_eq!(sorted.chunks(2).count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn chunks(
+        &self,
+        size: usize,
+    ) -> impl DoubleEndedIterator<Item = &SortedSlice<T>> + ExactSizeIterator {
+        self.as_sorted_slice().chunks(size)
+    }
+
+    /// Returns true if all items of the array are contained in `other`, see
+    /// [`SortedSlice::is_subset`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let delims: Sorted<_, 2> = Sorted::new([',', ';'])?;
+    /// let charset: Sorted<_, 3> = Sorted::new([' ', ',', ';'])?;
+    /// assert!(delims.is_subset(charset.as_sorted_slice()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_subset(&self, other: &SortedSlice<T>) -> bool
+    where
+        T: Ord,
+    {
+        self.as_sorted_slice().is_subset(other)
+    }
+
+    /// Returns true if all items of `other` are contained in the array, see
+    /// [`SortedSlice::is_superset`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let delims: Sorted<_, 2> = Sorted::new([',', ';'])?;
+    /// let charset: Sorted<_, 3> = Sorted::new([' ', ',', ';'])?;
+    /// assert!(charset.is_superset(delims.as_sorted_slice()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_superset(&self, other: &SortedSlice<T>) -> bool
+    where
+        T: Ord,
+    {
+        self.as_sorted_slice().is_superset(other)
+    }
+
+    /// Returns true if the array and `other` have no items in common, see
+    /// [`SortedSlice::is_disjoint`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let delims: Sorted<_, 2> = Sorted::new([',', ';'])?;
+    /// let escapes: Sorted<_, 1> = Sorted::new(['\\'])?;
+    /// assert!(delims.is_disjoint(escapes.as_sorted_slice()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_disjoint(&self, other: &SortedSlice<T>) -> bool
+    where
+        T: Ord,
+    {
+        self.as_sorted_slice().is_disjoint(other)
+    }
 }
 
+macro_rules! impl_new_const {
+    ($($t:ty),+) => {
+        $(
+            impl<const N: usize> Sorted<$t, N> {
+                /// Creates a new [`Sorted`] from the given `array`, checking that it is sorted.
+                /// Unlike [`Sorted::new`] this is a `const fn`, so it can be used to create
+                /// `static` and `const` sorted arrays, which are checked at compile time.
+                ///
+                /// # Panics
+                /// Panics if `array` is not sorted, in `const` contexts this is a compile
+                /// error.
+                ///
+                /// # Examples
+                /// ```
+                /// # use strtools::util::Sorted;
+                /// static DELIMS: Sorted<char, 3> = Sorted::<char, 3>::new_const([' ', ',', ';']);
+                /// const DIGITS: Sorted<u8, 3> = Sorted::<u8, 3>::new_const([1, 2, 3]);
+                /// ```
+                #[inline]
+                pub const fn new_const(array: [$t; N]) -> Self {
+                    let mut idx = 1;
+                    while idx < N {
+                        assert!(array[idx - 1] <= array[idx], "the array must be sorted");
+                        idx += 1;
+                    }
+
+                    // SAFETY: the array is sorted
+                    unsafe { Self::new_unchecked(array) }
+                }
+            }
+        )+
+    };
+}
+
+impl_new_const!(char);
+impl_new_const!(u8, u16, u32, u64, u128, usize);
+impl_new_const!(i8, i16, i32, i64, i128, isize);
+
 impl<T: PartialOrd + Debug, const N: usize> Debug for Sorted<T, N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt(f)
@@ -224,3 +452,51 @@ impl<T: PartialOrd> From<T> for Sorted<T, 1> {
         unsafe { Sorted::new_unchecked([value]) }
     }
 }
+
+impl<const N: usize> TryFrom<&str> for Sorted<char, N> {
+    type Error = SortedError;
+
+    /// Sorts the chars of `value` and creates a new [`Sorted`] from them.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `value` does not contain exactly `N` chars
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// let sorted: Sorted<char, 3> = "/:,".try_into()?;
+    /// assert_eq!(sorted.as_array_ref(), &[',', '/', ':']);
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This will return an error:
+    /// ```should_panic
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::Sorted;
+    /// // this contains 4 chars
+    /// let sorted: Sorted<char, 3> = "/:,;".try_into()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut array = ['\0'; N];
+        let mut chars = value.chars();
+
+        for (idx, slot) in array.iter_mut().enumerate() {
+            *slot = chars.next().ok_or(SortedError::Length {
+                expected: N,
+                found: idx,
+            })?;
+        }
+
+        match chars.count() {
+            0 => Ok(Self::new_sorted(array)),
+            rest => Err(SortedError::Length {
+                expected: N,
+                found: N + rest,
+            }),
+        }
+    }
+}
@@ -0,0 +1,474 @@
+use super::{CharMembership, SortedError};
+use std::{cmp::Ordering, fmt::Debug, ops::Deref};
+
+/// A total order over `T` used by [`SortedBy`] and [`SortedSliceBy`], this is implemented for
+/// all closures comparing two `&T`.
+///
+/// # Examples
+/// ```
+/// # use strtools::util::{CaseInsensitive, Comparator};
+/// use std::cmp::Ordering;
+///
+/// // order by a key function
+/// let by_len = |a: &&str, b: &&str| a.len().cmp(&b.len());
+/// assert_eq!(by_len.compare(&"ab", &"c"), Ordering::Greater);
+///
+/// assert_eq!(CaseInsensitive.compare(&'a', &'A'), Ordering::Equal);
+/// ```
+pub trait Comparator<T: ?Sized> {
+    /// Compares `a` and `b`, this must be a total order.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T: ?Sized, F: Fn(&T, &T) -> Ordering> Comparator<T> for F {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// A [`Comparator`] for [`char`] and [`str`] that compares their lowercase mappings.
+///
+/// # Examples
+/// ```
+/// # use strtools::util::{CaseInsensitive, Comparator};
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(CaseInsensitive.compare("Straße", "STRASSE"), Ordering::Greater);
+/// assert_eq!(CaseInsensitive.compare("Ab", "aB"), Ordering::Equal);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CaseInsensitive;
+
+impl Comparator<char> for CaseInsensitive {
+    #[inline]
+    fn compare(&self, a: &char, b: &char) -> Ordering {
+        a.to_lowercase().cmp(b.to_lowercase())
+    }
+}
+
+impl Comparator<str> for CaseInsensitive {
+    #[inline]
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        a.chars()
+            .flat_map(char::to_lowercase)
+            .cmp(b.chars().flat_map(char::to_lowercase))
+    }
+}
+
+impl Comparator<&str> for CaseInsensitive {
+    #[inline]
+    fn compare(&self, a: &&str, b: &&str) -> Ordering {
+        Comparator::<str>::compare(self, a, b)
+    }
+}
+
+impl Comparator<String> for CaseInsensitive {
+    #[inline]
+    fn compare(&self, a: &String, b: &String) -> Ordering {
+        Comparator::<str>::compare(self, a, b)
+    }
+}
+
+/// Returns true if `slice` is sorted according to `cmp`.
+fn is_sorted_by<T, F: Comparator<T>>(slice: &[T], cmp: &F) -> bool {
+    slice.is_sorted_by(|a, b| cmp.compare(a, b) != Ordering::Greater)
+}
+
+/// Binary searches `item` in `slice`, which must be sorted according to `cmp`.
+fn position_by<T, F: Comparator<T>>(slice: &[T], cmp: &F, item: &T) -> Option<usize> {
+    slice
+        .binary_search_by(|probe| cmp.compare(probe, item))
+        .ok()
+}
+
+/// Represents a `[T]` that is guaranteed to be sorted by the [`Comparator`] `F`. Unlike
+/// [`SortedSlice`][0] this is not a [DST][dst] as it must carry its comparator and is therefore
+/// constructed by value.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+/// let sorted = SortedSliceBy::new(&['a', 'B', 'c'], CaseInsensitive)?;
+/// assert!(sorted.contains(&'b'));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [0]: super::SortedSlice
+/// [dst]: https://doc.rust-lang.org/book/ch19-04-advanced-types.html#dynamically-sized-types-and-the-sized-trait
+#[derive(Clone, Copy)]
+pub struct SortedSliceBy<'s, T, F> {
+    slice: &'s [T],
+    cmp: F,
+}
+
+impl<'s, T, F: Comparator<T>> SortedSliceBy<'s, T, F> {
+    /// Creates a new [`SortedSliceBy`] from the given `slice` if it was sorted according to
+    /// `cmp`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `slice` was not sorted
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+    /// let sorted = SortedSliceBy::new(&['a', 'B', 'c'], CaseInsensitive)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This will return an error:
+    /// ```should_panic
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+    /// // this is not sorted
+    /// let sorted = SortedSliceBy::new(&['a', 'C', 'b'], CaseInsensitive)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn new(slice: &'s [T], cmp: F) -> Result<Self, SortedError> {
+        if is_sorted_by(slice, &cmp) {
+            Ok(Self { slice, cmp })
+        } else {
+            Err(SortedError::NotSorted)
+        }
+    }
+
+    /// Sorts the given slice according to `cmp` and creates a new [`SortedSliceBy`] from it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+    /// let mut slice = ['c', 'B', 'a'];
+    /// let sorted = SortedSliceBy::new_sorted(&mut slice, CaseInsensitive);
+    /// assert_eq!(sorted.as_slice(), &['a', 'B', 'c']);
+    /// ```
+    #[inline]
+    pub fn new_sorted(slice: &'s mut [T], cmp: F) -> Self {
+        slice.sort_by(|a, b| cmp.compare(a, b));
+        Self { slice, cmp }
+    }
+
+    /// Creates a new [`SortedSliceBy`] from the given `slice`, assuming it was sorted according
+    /// to `cmp`.
+    ///
+    /// # Safety
+    /// The caller must ensure that:
+    /// - `slice` is sorted according to `cmp`
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+    /// let sorted = unsafe { SortedSliceBy::new_unchecked(&['a', 'B', 'c'], CaseInsensitive) };
+    /// ```
+    #[inline]
+    pub const unsafe fn new_unchecked(slice: &'s [T], cmp: F) -> Self {
+        Self { slice, cmp }
+    }
+
+    /// Returns the underlying slice `&[T]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+    /// let sorted = SortedSliceBy::new(&['a', 'B', 'c'], CaseInsensitive)?;
+    /// let slice: &[char] = sorted.as_slice();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn as_slice(&self) -> &'s [T] {
+        self.slice
+    }
+
+    /// Returns a reference to the [`Comparator`] the slice is sorted by.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+    /// let sorted = SortedSliceBy::new(&['a', 'B', 'c'], CaseInsensitive)?;
+    /// let cmp: &CaseInsensitive = sorted.comparator();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn comparator(&self) -> &F {
+        &self.cmp
+    }
+
+    /// Returns true if the slice contains an item equal to `item` according to the
+    /// [`Comparator`], this uses a binary search.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+    /// let sorted = SortedSliceBy::new(&["Alpha", "beta"], CaseInsensitive)?;
+    /// assert!(sorted.contains(&"ALPHA"));
+    /// assert!(!sorted.contains(&"gamma"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool {
+        self.position(item).is_some()
+    }
+
+    /// Returns the index of an item equal to `item` according to the [`Comparator`] if the slice
+    /// contains one. If multiple items are equal to `item`, any of their indices may be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::{CaseInsensitive, SortedSliceBy};
+    /// let sorted = SortedSliceBy::new(&['a', 'B', 'c'], CaseInsensitive)?;
+    /// assert_eq!(sorted.position(&'b'), Some(1));
+    /// assert_eq!(sorted.position(&'d'), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn position(&self, item: &T) -> Option<usize> {
+        position_by(self.slice, &self.cmp, item)
+    }
+}
+
+impl<'s, T: Debug, F> Debug for SortedSliceBy<'s, T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.slice.fmt(f)
+    }
+}
+
+impl<'s, T, F> Deref for SortedSliceBy<'s, T, F> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.slice
+    }
+}
+
+impl<'s, F: Comparator<char>> CharMembership for SortedSliceBy<'s, char, F> {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        self.contains(&ch)
+    }
+}
+
+/// Represents a [`Vec<T>`] that is guaranteed to be sorted by the [`Comparator`] `F`, see
+/// [`SortedSliceBy`] for a borrowed version.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use strtools::{split, util::{CaseInsensitive, SortedBy}};
+///
+/// // case insensitive delimiters
+/// let delims = SortedBy::new_sorted(vec!['x', 'y'], CaseInsensitive);
+/// let parts: Vec<_> = split::non_escaped_with("aXbyc", '\\', &delims)?.collect();
+/// assert_eq!(parts, ["a", "b", "c"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SortedBy<T, F> {
+    vec: Vec<T>,
+    cmp: F,
+}
+
+impl<T, F: Comparator<T>> SortedBy<T, F> {
+    /// Creates a new [`SortedBy`] from the given `vec` if it was sorted according to `cmp`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `vec` was not sorted
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// let sorted = SortedBy::new(vec!['a', 'B', 'c'], CaseInsensitive)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This will return an error:
+    /// ```should_panic
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// // this is not sorted
+    /// let sorted = SortedBy::new(vec!['a', 'C', 'b'], CaseInsensitive)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn new(vec: Vec<T>, cmp: F) -> Result<Self, SortedError> {
+        if is_sorted_by(&vec, &cmp) {
+            Ok(Self { vec, cmp })
+        } else {
+            Err(SortedError::NotSorted)
+        }
+    }
+
+    /// Sorts the given vec according to `cmp` and creates a new [`SortedBy`] from it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedBy;
+    /// // ordered by a key function
+    /// let sorted = SortedBy::new_sorted(vec!["ccc", "a", "bb"], |a: &&str, b: &&str| {
+    ///     a.len().cmp(&b.len())
+    /// });
+    /// assert_eq!(sorted.as_slice(), &["a", "bb", "ccc"]);
+    /// ```
+    #[inline]
+    pub fn new_sorted(mut vec: Vec<T>, cmp: F) -> Self {
+        vec.sort_by(|a, b| cmp.compare(a, b));
+        Self { vec, cmp }
+    }
+
+    /// Creates a new [`SortedBy`] from the given `vec`, assuming it was sorted according to `cmp`.
+    ///
+    /// # Safety
+    /// The caller must ensure that:
+    /// - `vec` is sorted according to `cmp`
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// let sorted = unsafe { SortedBy::new_unchecked(vec!['a', 'B', 'c'], CaseInsensitive) };
+    /// ```
+    #[inline]
+    pub const unsafe fn new_unchecked(vec: Vec<T>, cmp: F) -> Self {
+        Self { vec, cmp }
+    }
+
+    /// Borrows this as a [`SortedSliceBy`], the [`Comparator`] is cloned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedBy, SortedSliceBy};
+    /// let sorted = SortedBy::new_sorted(vec!['c', 'B', 'a'], CaseInsensitive);
+    /// let slice: SortedSliceBy<'_, char, CaseInsensitive> = sorted.as_sorted_slice_by();
+    /// ```
+    #[inline]
+    pub fn as_sorted_slice_by(&self) -> SortedSliceBy<'_, T, F>
+    where
+        F: Clone,
+    {
+        SortedSliceBy {
+            slice: &self.vec,
+            cmp: self.cmp.clone(),
+        }
+    }
+
+    /// Returns the underlying slice `&[T]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// let sorted = SortedBy::new_sorted(vec!['c', 'B', 'a'], CaseInsensitive);
+    /// let slice: &[char] = sorted.as_slice();
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Returns a reference to the [`Comparator`] the vec is sorted by.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// let sorted = SortedBy::new_sorted(vec!['c', 'B', 'a'], CaseInsensitive);
+    /// let cmp: &CaseInsensitive = sorted.comparator();
+    /// ```
+    #[inline]
+    pub const fn comparator(&self) -> &F {
+        &self.cmp
+    }
+
+    /// Returns true if the vec contains an item equal to `item` according to the [`Comparator`],
+    /// see [`SortedSliceBy::contains`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// let sorted = SortedBy::new_sorted(vec!['c', 'B', 'a'], CaseInsensitive);
+    /// assert!(sorted.contains(&'b'));
+    /// ```
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool {
+        self.position(item).is_some()
+    }
+
+    /// Returns the index of an item equal to `item` according to the [`Comparator`] if the vec
+    /// contains one, see [`SortedSliceBy::position`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// let sorted = SortedBy::new_sorted(vec!['c', 'B', 'a'], CaseInsensitive);
+    /// assert_eq!(sorted.position(&'b'), Some(1));
+    /// ```
+    #[inline]
+    pub fn position(&self, item: &T) -> Option<usize> {
+        position_by(&self.vec, &self.cmp, item)
+    }
+
+    /// Inserts `item` after all items that are less than or equal to it according to the
+    /// [`Comparator`] and returns the index it was inserted at.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// let mut sorted = SortedBy::new_sorted(vec!['a', 'C'], CaseInsensitive);
+    /// assert_eq!(sorted.insert('b'), 1);
+    /// assert_eq!(sorted.as_slice(), &['a', 'b', 'C']);
+    /// ```
+    pub fn insert(&mut self, item: T) -> usize {
+        let idx = self
+            .vec
+            .partition_point(|x| self.cmp.compare(x, &item) != Ordering::Greater);
+        self.vec.insert(idx, item);
+        idx
+    }
+
+    /// Returns the inner [`Vec<T>`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{CaseInsensitive, SortedBy};
+    /// let sorted = SortedBy::new_sorted(vec!['c', 'B', 'a'], CaseInsensitive);
+    /// let vec: Vec<char> = sorted.into_vec();
+    /// ```
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+}
+
+impl<T: Debug, F> Debug for SortedBy<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.vec.fmt(f)
+    }
+}
+
+impl<T, F> Deref for SortedBy<T, F> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.vec
+    }
+}
+
+impl<F: Comparator<char>> CharMembership for SortedBy<char, F> {
+    #[inline]
+    fn contains_char(&self, ch: char) -> bool {
+        self.contains(&ch)
+    }
+}
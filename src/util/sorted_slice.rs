@@ -1,5 +1,10 @@
-use super::SortedError;
-use std::{borrow::Borrow, fmt::Debug, ops::Deref};
+use super::{SortedError, SortedVec};
+use std::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::Debug,
+    ops::{Bound, Deref, RangeBounds},
+};
 
 /// Represents a `[T]` that is guaranteed to be sorted by [`T: PartialOrd`][pord]. This is a
 /// [DST][dst], therefore constructors only return references.
@@ -78,6 +83,36 @@ impl<T: PartialOrd> SortedSlice<T> {
         unsafe { Self::new_unchecked(slice) }
     }
 
+    /// Sorts the given slice, moves all duplicates to the end and creates a new [`SortedSlice`]
+    /// from the remaining unique elements at the front. The order of the duplicates at the end of
+    /// `slice` is unspecified.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedSlice;
+    /// let mut slice = ['c', 'a', 'b', 'a', 'c'];
+    /// let sorted: &SortedSlice<_> = SortedSlice::new_dedup(&mut slice);
+    /// assert_eq!(sorted.as_slice(), &['a', 'b', 'c']);
+    /// ```
+    #[inline]
+    pub fn new_dedup(slice: &mut [T]) -> &Self
+    where
+        T: Ord,
+    {
+        slice.sort_unstable();
+
+        let mut len = usize::from(!slice.is_empty());
+        for idx in 1..slice.len() {
+            if slice[idx] != slice[len - 1] {
+                slice.swap(idx, len);
+                len += 1;
+            }
+        }
+
+        // SAFETY: the slice has been sorted and the unique prefix retains the order
+        unsafe { Self::new_unchecked(&slice[..len]) }
+    }
+
     /// Creates a new [`SortedSlice`] from the given `slice`, assuming it was sorted.
     ///
     /// # Safety
@@ -227,6 +262,366 @@ impl<T: PartialOrd> SortedSlice<T> {
     pub const fn as_slice_mut(&mut self) -> &mut [T] {
         &mut self.0
     }
+
+    /// Returns true if the slice contains `item`, this uses a binary search rather than the linear
+    /// search of [`slice::contains`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let sorted: &SortedSlice<_> = ['a', 'b', 'c'][..].try_into()?;
+    /// assert!(sorted.contains(&'b'));
+    /// assert!(!sorted.contains(&'d'));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn contains(&self, item: &T) -> bool
+    where
+        T: Ord,
+    {
+        self.0.binary_search(item).is_ok()
+    }
+
+    /// Returns the index of `item` in the slice if it contains it. If `item` occurs multiple
+    /// times, any of their indices may be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let sorted: &SortedSlice<_> = ['a', 'b', 'c'][..].try_into()?;
+    /// assert_eq!(sorted.position(&'b'), Some(1));
+    /// assert_eq!(sorted.position(&'d'), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn position(&self, item: &T) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.0.binary_search(item).ok()
+    }
+
+    /// Returns the contiguous sub slice of all items that lie within `bounds`, the returned slice
+    /// is empty if no items lie within `bounds` or if the start bound lies after the end bound.
+    ///
+    /// # Complexity
+    /// This function runs in `O(log n)` time, where `n` is the length of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let sorted: &SortedSlice<_> = SortedSlice::new(&[1, 3, 3, 5, 7, 9])?;
+    ///
+    /// assert_eq!(sorted.range(3..7).as_slice(), &[3, 3, 5]);
+    /// assert_eq!(sorted.range(4..=7).as_slice(), &[5, 7]);
+    /// assert_eq!(sorted.range(..2).as_slice(), &[1]);
+    /// assert_eq!(sorted.range(8..).as_slice(), &[9]);
+    /// assert!(sorted.range(10..).is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn range<R: RangeBounds<T>>(&self, bounds: R) -> &Self
+    where
+        T: Ord,
+    {
+        let start = match bounds.start_bound() {
+            Bound::Included(start) => self.0.partition_point(|x| x < start),
+            Bound::Excluded(start) => self.0.partition_point(|x| x <= start),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match bounds.end_bound() {
+            Bound::Included(end) => self.0.partition_point(|x| x <= end),
+            Bound::Excluded(end) => self.0.partition_point(|x| x < end),
+            Bound::Unbounded => self.0.len(),
+        };
+
+        // SAFETY: a sub slice of a sorted slice is sorted
+        unsafe { Self::new_unchecked(&self.0[start..end.max(start)]) }
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size`, like [`slice::windows`]
+    /// but yielding [`SortedSlice`]s.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let sorted: &SortedSlice<_> = SortedSlice::new(&[1, 2, 4, 8])?;
+    /// let windows: Vec<_> = sorted.windows(2).map(SortedSlice::as_slice).collect();
+    ///
+    /// assert_eq!(windows, [&[1, 2], &[2, 4], &[4, 8]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn windows(
+        &self,
+        size: usize,
+    ) -> impl DoubleEndedIterator<Item = &Self> + ExactSizeIterator {
+        self.0.windows(size).map(|window| {
+            // SAFETY: a sub slice of a sorted slice is sorted
+            unsafe { Self::new_unchecked(window) }
+        })
+    }
+
+    /// Returns an iterator over non overlapping chunks of length `size` starting at the beginning
+    /// of the slice, like [`slice::chunks`] but yielding [`SortedSlice`]s. The last chunk is
+    /// shorter if the length of the slice is not divisible by `size`.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let sorted: &SortedSlice<_> = SortedSlice::new(&[1, 2, 4, 8, 16])?;
+    /// let chunks: Vec<_> = sorted.chunks(2).map(SortedSlice::as_slice).collect();
+    ///
+    /// assert_eq!(chunks, [&[1, 2][..], &[4, 8], &[16]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn chunks(
+        &self,
+        size: usize,
+    ) -> impl DoubleEndedIterator<Item = &Self> + ExactSizeIterator {
+        self.0.chunks(size).map(|chunk| {
+            // SAFETY: a sub slice of a sorted slice is sorted
+            unsafe { Self::new_unchecked(chunk) }
+        })
+    }
+
+    /// Returns a [`SortedVec`] containing all items that are in `self`, `other` or both. Items
+    /// contained in both are only taken once from `self`, items that occur multiple times are
+    /// kept as often as the maximum of their occurrences.
+    ///
+    /// # Complexity
+    /// This function runs in `O(n + m)` time, where `n` and `m` are the lengths of `self` and
+    /// `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let delims: &SortedSlice<_> = SortedSlice::new(&[',', ';'])?;
+    /// let whitespace: &SortedSlice<_> = SortedSlice::new(&['\n', ' ', ';'])?;
+    ///
+    /// let union = delims.union(whitespace);
+    /// assert_eq!(union.as_slice(), &['\n', ' ', ',', ';']);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn union(&self, other: &Self) -> SortedVec<T>
+    where
+        T: Ord + Clone,
+    {
+        merge(&self.0, &other.0, true, true, true)
+    }
+
+    /// Returns a [`SortedVec`] containing all items that are in both `self` and `other`, items
+    /// that occur multiple times are kept as often as the minimum of their occurrences.
+    ///
+    /// # Complexity
+    /// This function runs in `O(n + m)` time, where `n` and `m` are the lengths of `self` and
+    /// `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let a: &SortedSlice<_> = SortedSlice::new(&['a', 'b', 'c'])?;
+    /// let b: &SortedSlice<_> = SortedSlice::new(&['b', 'c', 'd'])?;
+    ///
+    /// let intersection = a.intersection(b);
+    /// assert_eq!(intersection.as_slice(), &['b', 'c']);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn intersection(&self, other: &Self) -> SortedVec<T>
+    where
+        T: Ord + Clone,
+    {
+        merge(&self.0, &other.0, false, true, false)
+    }
+
+    /// Returns a [`SortedVec`] containing all items that are in `self` but not in `other`, items
+    /// that occur multiple times are removed as often as they occur in `other`.
+    ///
+    /// # Complexity
+    /// This function runs in `O(n + m)` time, where `n` and `m` are the lengths of `self` and
+    /// `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let a: &SortedSlice<_> = SortedSlice::new(&['a', 'b', 'c'])?;
+    /// let b: &SortedSlice<_> = SortedSlice::new(&['b', 'c', 'd'])?;
+    ///
+    /// let difference = a.difference(b);
+    /// assert_eq!(difference.as_slice(), &['a']);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn difference(&self, other: &Self) -> SortedVec<T>
+    where
+        T: Ord + Clone,
+    {
+        merge(&self.0, &other.0, true, false, false)
+    }
+
+    /// Returns true if all items of `self` are contained in `other`, the amount of occurrences of
+    /// an item is not considered.
+    ///
+    /// # Complexity
+    /// This function runs in `O(n + m)` time, where `n` and `m` are the lengths of `self` and
+    /// `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let delims: &SortedSlice<_> = SortedSlice::new(&[',', ';'])?;
+    /// let charset: &SortedSlice<_> = SortedSlice::new(&[' ', ',', ':', ';'])?;
+    ///
+    /// assert!(delims.is_subset(charset));
+    /// assert!(!charset.is_subset(delims));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_subset(&self, other: &Self) -> bool
+    where
+        T: Ord,
+    {
+        let mut other = other.0.iter().peekable();
+
+        self.0.iter().all(|x| {
+            while other.next_if(|y| *y < x).is_some() {}
+            other.peek() == Some(&x)
+        })
+    }
+
+    /// Returns true if all items of `other` are contained in `self`, see
+    /// [`SortedSlice::is_subset`].
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let delims: &SortedSlice<_> = SortedSlice::new(&[',', ';'])?;
+    /// let charset: &SortedSlice<_> = SortedSlice::new(&[' ', ',', ':', ';'])?;
+    ///
+    /// assert!(charset.is_superset(delims));
+    /// assert!(!delims.is_superset(charset));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_superset(&self, other: &Self) -> bool
+    where
+        T: Ord,
+    {
+        other.is_subset(self)
+    }
+
+    /// Returns true if `self` and `other` have no items in common.
+    ///
+    /// # Complexity
+    /// This function runs in `O(n + m)` time, where `n` and `m` are the lengths of `self` and
+    /// `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedSlice;
+    /// let delims: &SortedSlice<_> = SortedSlice::new(&[',', ';'])?;
+    /// let escapes: &SortedSlice<_> = SortedSlice::new(&['\\', '^'])?;
+    ///
+    /// assert!(delims.is_disjoint(escapes));
+    /// assert!(!delims.is_disjoint(delims));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_disjoint(&self, other: &Self) -> bool
+    where
+        T: Ord,
+    {
+        let (mut a, mut b) = (self.0.iter().peekable(), other.0.iter().peekable());
+
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            match x.cmp(y) {
+                Ordering::Less => {
+                    a.next();
+                }
+                Ordering::Greater => {
+                    b.next();
+                }
+                Ordering::Equal => return false,
+            }
+        }
+
+        true
+    }
+}
+
+/// Linearly merges the two sorted slices, keeping the items only in `a`, in both or only in `b`
+/// as specified.
+fn merge<T: Ord + Clone>(a: &[T], b: &[T], only_a: bool, both: bool, only_b: bool) -> SortedVec<T> {
+    let mut merged = Vec::new();
+    let (mut a, mut b) = (a.iter().peekable(), b.iter().peekable());
+
+    loop {
+        match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) => match x.cmp(y) {
+                Ordering::Less => {
+                    if only_a {
+                        merged.push((*x).clone());
+                    }
+                    a.next();
+                }
+                Ordering::Greater => {
+                    if only_b {
+                        merged.push((*y).clone());
+                    }
+                    b.next();
+                }
+                Ordering::Equal => {
+                    if both {
+                        merged.push((*x).clone());
+                    }
+                    a.next();
+                    b.next();
+                }
+            },
+            (Some(_), None) => {
+                if only_a {
+                    merged.extend(a.cloned());
+                }
+                break;
+            }
+            (None, Some(_)) => {
+                if only_b {
+                    merged.extend(b.cloned());
+                }
+                break;
+            }
+            (None, None) => break,
+        }
+    }
+
+    // SAFETY: the items were merged in order from two sorted slices
+    unsafe { SortedVec::new_unchecked(merged) }
 }
 
 impl<T: PartialOrd + Debug> Debug for SortedSlice<T> {
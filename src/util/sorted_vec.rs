@@ -0,0 +1,281 @@
+use super::{Sorted, SortedError, SortedSlice};
+use std::{borrow::Borrow, fmt::Debug, ops::Deref};
+
+/// Represents a [`Vec<T>`] that is guaranteed to be sorted by [`T: PartialOrd`][pord]. Unlike
+/// [`SortedSlice`] this is owned and can be built at runtime.
+///
+/// # Examples
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use strtools::util::{SortedSlice, SortedVec};
+/// let a: &SortedSlice<_> = SortedSlice::new(&['a', 'b'])?;
+/// let b: &SortedSlice<_> = SortedSlice::new(&['b', 'c'])?;
+///
+/// let union: SortedVec<_> = a.union(b);
+/// assert_eq!(union.as_slice(), &['a', 'b', 'c']);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [pord]: PartialOrd
+#[repr(transparent)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedVec<T: PartialOrd>(Vec<T>);
+
+impl<T: PartialOrd> SortedVec<T> {
+    /// Creates a new [`SortedVec`] from the given `vec` if it was sorted.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `vec` was not sorted
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedVec;
+    /// let sorted: SortedVec<_> = SortedVec::new(vec!['a', 'b', 'c'])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// This will return an error:
+    /// ```should_panic
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # use strtools::util::SortedVec;
+    /// // this is not sorted
+    /// let sorted: SortedVec<_> = SortedVec::new(vec!['a', 'c', 'b'])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn new(vec: Vec<T>) -> Result<Self, SortedError> {
+        if vec.is_sorted() {
+            Ok(Self(vec))
+        } else {
+            Err(SortedError::NotSorted)
+        }
+    }
+
+    /// Sorts the given vec and creates a new [`SortedVec`] from it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedVec;
+    /// let sorted: SortedVec<_> = SortedVec::new_sorted(vec!['a', 'c', 'b']);
+    /// assert_eq!(sorted.as_slice(), &['a', 'b', 'c']);
+    /// ```
+    #[inline]
+    pub fn new_sorted(mut vec: Vec<T>) -> Self
+    where
+        T: Ord,
+    {
+        vec.sort();
+        Self(vec)
+    }
+
+    /// Sorts the given vec, removes all duplicates and creates a new [`SortedVec`] from it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedVec;
+    /// let sorted: SortedVec<_> = SortedVec::new_dedup(vec!['c', 'a', 'b', 'a', 'c']);
+    /// assert_eq!(sorted.as_slice(), &['a', 'b', 'c']);
+    /// ```
+    #[inline]
+    pub fn new_dedup(mut vec: Vec<T>) -> Self
+    where
+        T: Ord,
+    {
+        vec.sort_unstable();
+        vec.dedup();
+        Self(vec)
+    }
+
+    /// Creates a new [`SortedVec`] from the given `vec`, assuming it was sorted.
+    ///
+    /// # Safety
+    /// The caller must ensure that:
+    /// - `vec` is sorted
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedVec;
+    /// let sorted: SortedVec<_> = unsafe { SortedVec::new_unchecked(vec!['a', 'b', 'c']) };
+    /// ```
+    /// Violation of invariants:
+    /// ```
+    /// # use strtools::util::SortedVec;
+    /// // this is not sorted, Sorted invariants are violated
+    /// let sorted: SortedVec<_> = unsafe { SortedVec::new_unchecked(vec!['a', 'c', 'b']) };
+    /// ```
+    #[inline]
+    pub const unsafe fn new_unchecked(vec: Vec<T>) -> Self {
+        Self(vec)
+    }
+
+    /// Borrows this as a [`SortedSlice`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::{SortedSlice, SortedVec};
+    /// let sorted: SortedVec<_> = unsafe { SortedVec::new_unchecked(vec!['a', 'b', 'c']) };
+    /// let slice: &SortedSlice<char> = sorted.as_sorted_slice();
+    /// ```
+    #[inline]
+    pub fn as_sorted_slice(&self) -> &SortedSlice<T> {
+        // SAFETY: the invariants of self are upheld by the vec
+        unsafe { SortedSlice::new_unchecked(&self.0) }
+    }
+
+    /// Inserts `item` after all items that are less than or equal to it and returns the index it
+    /// was inserted at.
+    ///
+    /// # Complexity
+    /// This function runs in `O(n)` time, where `n` is the length of `self`, the position is found
+    /// in `O(log n)` time, but the items after it must be moved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedVec;
+    /// let mut sorted: SortedVec<_> = SortedVec::new_sorted(vec!['a', 'c']);
+    /// assert_eq!(sorted.insert('b'), 1);
+    /// assert_eq!(sorted.insert('c'), 3);
+    /// assert_eq!(sorted.as_slice(), &['a', 'b', 'c', 'c']);
+    /// ```
+    pub fn insert(&mut self, item: T) -> usize
+    where
+        T: Ord,
+    {
+        let idx = self.0.partition_point(|x| x <= &item);
+        self.0.insert(idx, item);
+        idx
+    }
+
+    /// Removes and returns the item at `idx`, this retains the order of the remaining items.
+    ///
+    /// # Panics
+    /// Panics if `idx` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedVec;
+    /// let mut sorted: SortedVec<_> = SortedVec::new_sorted(vec!['a', 'b', 'c']);
+    /// assert_eq!(sorted.remove(1), 'b');
+    /// assert_eq!(sorted.as_slice(), &['a', 'c']);
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> T {
+        self.0.remove(idx)
+    }
+
+    /// Returns the inner [`Vec<T>`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedVec;
+    /// let sorted: SortedVec<_> = unsafe { SortedVec::new_unchecked(vec!['a', 'b', 'c']) };
+    /// let vec: Vec<char> = sorted.into_vec();
+    /// ```
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl SortedVec<char> {
+    /// Creates a new [`SortedVec`] from the chars of `input`, duplicate chars are removed. This is
+    /// useful for specifying sets of delimiters as string literals.
+    ///
+    /// # Complexity
+    /// This function runs in `O(n * log n)` time, where `n` is the length of the input string.
+    ///
+    /// # Examples
+    /// ```
+    /// # use strtools::util::SortedVec;
+    /// let sorted = SortedVec::<char>::from_chars("/:,/");
+    /// assert_eq!(sorted.as_slice(), &[',', '/', ':']);
+    /// ```
+    pub fn from_chars(input: &str) -> Self {
+        let mut chars: Vec<_> = input.chars().collect();
+        chars.sort_unstable();
+        chars.dedup();
+
+        Self(chars)
+    }
+}
+
+impl<T: PartialOrd + Debug> Debug for SortedVec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: PartialOrd> Default for SortedVec<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: PartialOrd> Deref for SortedVec<T> {
+    type Target = SortedSlice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_sorted_slice()
+    }
+}
+
+impl<T: PartialOrd> AsRef<SortedSlice<T>> for SortedVec<T> {
+    fn as_ref(&self) -> &SortedSlice<T> {
+        self.as_sorted_slice()
+    }
+}
+
+impl<T: PartialOrd> Borrow<SortedSlice<T>> for SortedVec<T> {
+    fn borrow(&self) -> &SortedSlice<T> {
+        self.as_sorted_slice()
+    }
+}
+
+impl<T: PartialOrd> AsRef<[T]> for SortedVec<T> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SortedVec<T> {
+    fn from(value: Vec<T>) -> Self {
+        SortedVec::new_sorted(value)
+    }
+}
+
+impl<T: PartialOrd, const N: usize> From<Sorted<T, N>> for SortedVec<T> {
+    fn from(value: Sorted<T, N>) -> Self {
+        // SAFETY: the invariants of value are upheld by the array
+        unsafe { SortedVec::new_unchecked(Vec::from(value.into_array())) }
+    }
+}
+
+impl<T: PartialOrd + Clone> From<&SortedSlice<T>> for SortedVec<T> {
+    fn from(value: &SortedSlice<T>) -> Self {
+        // SAFETY: the invariants of value are upheld by the slice
+        unsafe { SortedVec::new_unchecked(value.to_vec()) }
+    }
+}
+
+impl<T: PartialOrd> From<SortedVec<T>> for Vec<T> {
+    fn from(value: SortedVec<T>) -> Self {
+        value.into_vec()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        SortedVec::new_sorted(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+        self.0.sort();
+    }
+}
@@ -0,0 +1,209 @@
+use std::{
+    fmt::Display,
+    ops::{Index, Range},
+};
+
+/// A region of some input given by its start and end byte offsets, the start is inclusive and the
+/// end exclusive. Unlike [`Range<usize>`] this is [`Copy`], it is used by the crate wherever a
+/// region of the input is referred to, like in [`parse::Located`][0] or [`find::MatchingError`][1].
+///
+/// # Examples
+/// ```
+/// use strtools::util::Span;
+///
+/// let input = "key = value";
+/// let key = Span::new(0, 3);
+/// let value = Span::new(6, 11);
+///
+/// assert_eq!(key.slice(input), "key");
+/// assert_eq!(&input[value], "value");
+/// assert_eq!(key.join(value).slice(input), input);
+/// ```
+///
+/// [0]: crate::parse::Located
+/// [1]: crate::find::MatchingError
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Span {
+    /// The inclusive start byte offset.
+    pub start: usize,
+
+    /// The exclusive end byte offset.
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates a new [`Span`] from `start` to `end`.
+    ///
+    /// # Panics
+    /// Panics if `start > end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// let span = Span::new(2, 5);
+    /// assert_eq!(span.len(), 3);
+    /// ```
+    #[inline]
+    pub const fn new(start: usize, end: usize) -> Self {
+        assert!(
+            start <= end,
+            "the start of a span must not lie after its end"
+        );
+        Self { start, end }
+    }
+
+    /// Creates a new empty [`Span`] at `offset`.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// let span = Span::empty(4);
+    /// assert_eq!(span, Span::new(4, 4));
+    /// assert!(span.is_empty());
+    /// ```
+    #[inline]
+    pub const fn empty(offset: usize) -> Self {
+        Self {
+            start: offset,
+            end: offset,
+        }
+    }
+
+    /// Returns the length of the span in bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// assert_eq!(Span::new(2, 5).len(), 3);
+    /// ```
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns true if the span has a length of zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// assert!(Span::new(3, 3).is_empty());
+    /// assert!(!Span::new(3, 4).is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns true if `offset` lies within the span.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// let span = Span::new(2, 5);
+    /// assert!(span.contains(2));
+    /// assert!(span.contains(4));
+    /// assert!(!span.contains(5));
+    /// ```
+    #[inline]
+    pub const fn contains(self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// Returns the smallest span covering both `self` and `other`, including any gap between
+    /// them.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// assert_eq!(Span::new(2, 4).join(Span::new(7, 9)), Span::new(2, 9));
+    /// assert_eq!(Span::new(2, 8).join(Span::new(3, 5)), Span::new(2, 8));
+    /// ```
+    #[inline]
+    pub fn join(self, other: Self) -> Self {
+        Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Returns the region of `input` this span refers to.
+    ///
+    /// # Panics
+    /// Panics if the span is out of bounds of `input` or does not lie on char boundaries, see
+    /// [`Span::get`] for a non panicking version.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// assert_eq!(Span::new(4, 7).slice("1 2 300 4"), "300");
+    /// ```
+    #[inline]
+    pub fn slice(self, input: &str) -> &str {
+        &input[self.start..self.end]
+    }
+
+    /// Returns the region of `input` this span refers to, or [`None`] if the span is out of
+    /// bounds of `input` or does not lie on char boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// assert_eq!(Span::new(4, 7).get("1 2 300 4"), Some("300"));
+    /// assert_eq!(Span::new(4, 7).get("1 2"), None);
+    /// ```
+    #[inline]
+    pub fn get(self, input: &str) -> Option<&str> {
+        input.get(self.start..self.end)
+    }
+
+    /// Returns the span as a [`Range<usize>`].
+    ///
+    /// # Examples
+    /// ```
+    /// use strtools::util::Span;
+    ///
+    /// assert_eq!(Span::new(2, 5).range(), 2..5);
+    /// ```
+    #[inline]
+    pub const fn range(self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl From<Range<usize>> for Span {
+    /// Creates a new [`Span`] from the given range.
+    ///
+    /// # Panics
+    /// Panics if `value.start > value.end`.
+    fn from(value: Range<usize>) -> Self {
+        Self::new(value.start, value.end)
+    }
+}
+
+impl From<Span> for Range<usize> {
+    fn from(value: Span) -> Self {
+        value.range()
+    }
+}
+
+impl Index<Span> for str {
+    type Output = str;
+
+    fn index(&self, index: Span) -> &Self::Output {
+        index.slice(self)
+    }
+}
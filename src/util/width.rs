@@ -0,0 +1,77 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Returns the width of `input` in columns when displayed in a terminal, as determined by
+/// [UAX #11][0], this is only available with the `unicode-width` feature. Wide chars like CJK
+/// ideographs take two columns, combining and control chars take none.
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// [0]: https://www.unicode.org/reports/tr11/
+///
+/// # Examples
+/// ```
+/// use strtools::util;
+///
+/// assert_eq!(util::display_width("abc"), 3);
+/// assert_eq!(util::display_width("日本"), 4);
+/// assert_eq!(util::display_width("e\u{301}"), 1);
+/// ```
+#[inline]
+pub fn display_width(input: &str) -> usize {
+    UnicodeWidthStr::width(input)
+}
+
+/// Returns the width of `ch` in columns when displayed in a terminal, control chars take no
+/// columns, see [`display_width`] for more info.
+///
+/// # Examples
+/// ```
+/// use strtools::util;
+///
+/// assert_eq!(util::char_width('a'), 1);
+/// assert_eq!(util::char_width('日'), 2);
+/// assert_eq!(util::char_width('\n'), 0);
+/// ```
+#[inline]
+pub fn char_width(ch: char) -> usize {
+    UnicodeWidthChar::width(ch).unwrap_or(0)
+}
+
+/// Returns the longest prefix of `input` whose display width does not exceed `width`, wide chars
+/// that would only partially fit are excluded. The widths of the chars are summed up
+/// individually, see [`char_width`].
+///
+/// # Complexity
+/// This algorithm requires `O(n)` time where `n` is the length of the input string.
+///
+/// # Allocation
+/// No allocations are done.
+///
+/// # Examples
+/// ```
+/// use strtools::util;
+///
+/// assert_eq!(util::width_at_most("abcdef", 4), "abcd");
+/// assert_eq!(util::width_at_most("日本語", 5), "日本");
+/// assert_eq!(util::width_at_most("日本語", 1), "");
+/// assert_eq!(util::width_at_most("abc", 10), "abc");
+///
+/// // the combining char takes no columns and stays attached to its base char
+/// assert_eq!(util::width_at_most("e\u{301}x", 1), "e\u{301}");
+/// ```
+pub fn width_at_most(input: &str, width: usize) -> &str {
+    let mut used = 0;
+
+    for (idx, ch) in input.char_indices() {
+        used += char_width(ch);
+        if used > width {
+            return &input[..idx];
+        }
+    }
+
+    input
+}